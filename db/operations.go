@@ -87,11 +87,30 @@ func (o *Operation) query(typeGetter TypeGetter) (string, error) {
 		return "", fmt.Errorf("preparing column-values: %w", err)
 	}
 	if o.opType == OperationTypeInsert {
-		return fmt.Sprintf("INSERT INTO %s.%s (%s) VALUES (%s)",
+		// Inserts are upserts so that replaying a block range that is
+		// already loaded, when restarting a backfill for example, rewrites
+		// the rows instead of failing on their primary key. Columns that
+		// are not part of the insert keep their current value.
+		var conflictUpdates []string
+		for i := 0; i < len(keys); i++ {
+			if keys[i] == o.primaryKeyColumnName {
+				continue
+			}
+			conflictUpdates = append(conflictUpdates, fmt.Sprintf("%s=EXCLUDED.%s", keys[i], keys[i]))
+		}
+
+		onConflict := "DO NOTHING"
+		if len(conflictUpdates) > 0 {
+			onConflict = fmt.Sprintf("DO UPDATE SET %s", strings.Join(conflictUpdates, ", "))
+		}
+
+		return fmt.Sprintf("INSERT INTO %s.%s (%s) VALUES (%s) ON CONFLICT (%s) %s",
 			o.schemaName,
 			o.tableName,
 			strings.Join(keys, ","),
 			strings.Join(values, ","),
+			o.primaryKeyColumnName,
+			onConflict,
 		), nil
 	}
 
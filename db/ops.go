@@ -21,17 +21,32 @@ func (l *Loader) Insert(tableName string, primaryKey string, data map[string]str
 		l.entries[tableName] = map[string]*Operation{}
 	}
 
-	if _, found := l.entries[tableName][primaryKey]; found {
-		return fmt.Errorf("attempting to insert in table %q a primary key %q, that is already scheduled for insertion, insert should only be called once for a given primary key", tableName, primaryKey)
+	// we need to make sure to add the primary key in the data so that
+	// it gets created
+	data[l.tablePrimaryKeys[tableName]] = primaryKey
+
+	// inserts are upserts, an insert for a primary key already scheduled
+	// is merged into the scheduled operation which becomes an insert
+	if op, found := l.entries[tableName][primaryKey]; found {
+		if op.opType == OperationTypeDelete {
+			return fmt.Errorf("attempting to insert in table %q a primary key %q, that is scheduled to be deleted", tableName, primaryKey)
+		}
+
+		if l.tracer.Enabled() {
+			l.logger.Debug("primary key entry already exist for table, merging fields together", zap.String("primary_key", primaryKey), zap.String("table_name", tableName))
+		}
+
+		if err := op.mergeData(data); err != nil {
+			return fmt.Errorf("merging insert in table %q for primary key %q: %w", tableName, primaryKey, err)
+		}
+		op.opType = OperationTypeInsert
+		return nil
 	}
 
 	if l.tracer.Enabled() {
 		l.logger.Debug("primary key entry never existed for table, adding insert operation", zap.String("primary_key", primaryKey), zap.String("table_name", tableName))
 	}
 
-	// we need to make sure to add the primary key in the data so that
-	// it gets created
-	data[l.tablePrimaryKeys[tableName]] = primaryKey
 	l.entries[tableName][primaryKey] = l.newInsertOperation(tableName, primaryKey, data)
 	l.EntriesCount++
 	return nil
@@ -61,7 +76,9 @@ func (l *Loader) Update(tableName string, primaryKey string, data map[string]str
 			l.logger.Debug("primary key entry already exist for table, merging fields together", zap.String("primary_key", primaryKey), zap.String("table_name", tableName))
 		}
 
-		op.mergeData(data)
+		if err := op.mergeData(data); err != nil {
+			return fmt.Errorf("merging update in table %q for primary key %q: %w", tableName, primaryKey, err)
+		}
 		l.entries[tableName][primaryKey] = op
 		return nil
 	} else {
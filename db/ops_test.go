@@ -0,0 +1,145 @@
+package db
+
+import (
+	"reflect"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"go.uber.org/zap"
+)
+
+type nopTracer struct{}
+
+func (nopTracer) Enabled() bool { return false }
+
+func newTestLoader() *Loader {
+	return &Loader{
+		schema:           "public",
+		entries:          map[string]map[string]*Operation{},
+		tables:           map[string]map[string]reflect.Type{},
+		tablePrimaryKeys: map[string]string{"nfts": "id"},
+		logger:           zap.NewNop(),
+		tracer:           nopTracer{},
+	}
+}
+
+func stringType(tableName string, columnName string) (reflect.Type, error) {
+	return reflect.TypeOf(""), nil
+}
+
+func TestInsertQueryIsAnUpsert(t *testing.T) {
+	l := newTestLoader()
+
+	query, err := l.newInsertOperation("nfts", "01", map[string]string{"id": "01", "owner": "a1"}).query(stringType)
+	require.NoError(t, err)
+
+	assert.True(t, strings.HasPrefix(query, "INSERT INTO public.nfts ("), query)
+	assert.True(t, strings.HasSuffix(query, " ON CONFLICT (id) DO UPDATE SET owner=EXCLUDED.owner"), query)
+
+	query, err = l.newInsertOperation("nfts", "01", map[string]string{"id": "01"}).query(stringType)
+	require.NoError(t, err)
+
+	assert.Equal(t, "INSERT INTO public.nfts (id) VALUES ('01') ON CONFLICT (id) DO NOTHING", query)
+}
+
+func TestOperationsMerge(t *testing.T) {
+	type call struct {
+		opType OperationType
+		data   map[string]string
+	}
+
+	tests := []struct {
+		name         string
+		calls        []call
+		expectError  bool
+		expectOpType OperationType
+		expectData   map[string]string
+	}{
+		{
+			name: "insert then insert",
+			calls: []call{
+				{OperationTypeInsert, map[string]string{"owner": "a1", "locked": "false"}},
+				{OperationTypeInsert, map[string]string{"owner": "b0"}},
+			},
+			expectOpType: OperationTypeInsert,
+			expectData:   map[string]string{"id": "01", "owner": "b0", "locked": "false"},
+		},
+		{
+			name: "update then insert becomes an insert",
+			calls: []call{
+				{OperationTypeUpdate, map[string]string{"locked": "true"}},
+				{OperationTypeInsert, map[string]string{"owner": "a1"}},
+			},
+			expectOpType: OperationTypeInsert,
+			expectData:   map[string]string{"id": "01", "owner": "a1", "locked": "true"},
+		},
+		{
+			name: "insert then update stays an insert",
+			calls: []call{
+				{OperationTypeInsert, map[string]string{"owner": "a1"}},
+				{OperationTypeUpdate, map[string]string{"locked": "true"}},
+			},
+			expectOpType: OperationTypeInsert,
+			expectData:   map[string]string{"id": "01", "owner": "a1", "locked": "true"},
+		},
+		{
+			name: "insert then delete becomes a delete",
+			calls: []call{
+				{OperationTypeInsert, map[string]string{"owner": "a1"}},
+				{OperationTypeDelete, nil},
+			},
+			expectOpType: OperationTypeDelete,
+		},
+		{
+			name: "delete then insert fails",
+			calls: []call{
+				{OperationTypeDelete, nil},
+				{OperationTypeInsert, map[string]string{"owner": "a1"}},
+			},
+			expectError: true,
+		},
+		{
+			name: "delete then update fails",
+			calls: []call{
+				{OperationTypeDelete, nil},
+				{OperationTypeUpdate, map[string]string{"owner": "a1"}},
+			},
+			expectError: true,
+		},
+	}
+
+	for _, test := range tests {
+		t.Run(test.name, func(t *testing.T) {
+			l := newTestLoader()
+
+			var err error
+			for _, c := range test.calls {
+				switch c.opType {
+				case OperationTypeInsert:
+					err = l.Insert("nfts", "01", c.data)
+				case OperationTypeUpdate:
+					err = l.Update("nfts", "01", c.data)
+				case OperationTypeDelete:
+					err = l.Delete("nfts", "01")
+				}
+			}
+
+			if test.expectError {
+				require.Error(t, err)
+				return
+			}
+
+			require.NoError(t, err)
+			require.Len(t, l.entries["nfts"], 1)
+			assert.Equal(t, uint64(1), l.EntriesCount)
+
+			op := l.entries["nfts"]["01"]
+			assert.Equal(t, test.expectOpType, op.opType)
+			if test.expectData != nil {
+				assert.Equal(t, test.expectData, op.data)
+			}
+		})
+	}
+}
@@ -1,4 +1,17 @@
 drop table if exists transfers;
+drop table if exists transfer_ranges;
+drop table if exists transactions;
 drop table if exists nfts;
+drop table if exists balances;
+drop table if exists collections;
+drop table if exists metadata_refresh;
+drop table if exists token_users;
+drop table if exists token_bound_accounts;
 drop table if exists contracts;
+drop table if exists contract_upgrades;
+drop table if exists contract_ownership_transfers;
+drop table if exists sales;
+drop table if exists sale_payouts;
+drop table if exists order_cancellations;
+drop table if exists punk_orders;
 drop table if exists cursors;
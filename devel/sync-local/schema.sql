@@ -13,6 +13,46 @@ create table transfers
     contract_address    text,
     tx_hash             text,
     ordinal             text,
+    timestamp           text,
+    anomaly             text
+);
+
+create table transactions
+(
+    id                  text not null constraint transaction_pk primary key,
+    from_address        text,
+    to_address          text,
+    value               text,
+    gas_used            text,
+    effective_gas_price text,
+    block_number        text,
+    transaction_index   text,
+    timestamp           text
+);
+
+create table transfer_ranges
+(
+    id                  text not null constraint transfer_range_pk primary key,
+    contract_address    text,
+    from_token_id       text,
+    to_token_id         text,
+    from_address        text,
+    to_address          text,
+    block_number        text,
+    tx_hash             text,
+    ordinal             text,
+    timestamp           text
+);
+
+create table metadata_refresh
+(
+    id                  text not null constraint metadata_refresh_pk primary key,
+    contract_address    text,
+    from_token_id       text,
+    to_token_id         text,
+    block_number        text,
+    tx_hash             text,
+    ordinal             text,
     timestamp           text
 );
 
@@ -23,7 +63,53 @@ create table nfts
     token_id    text,
     owner       text,
     tokenUri    text,
-    metadata    text
+    metadata    text,
+    metadata_stale  boolean,
+    locked      boolean
+);
+
+create table balances
+(
+    id          text not null constraint balance_pk primary key,
+    contract    text,
+    token_id    text,
+    holder      text,
+    balance     text
+);
+
+create table collections
+(
+    id              text not null constraint collection_pk primary key,
+    contract        text,
+    transfer_count  text
+);
+
+create table token_users
+(
+    id                  text not null constraint token_user_pk primary key,
+    contract_address    text,
+    token_id            text,
+    user_address        text,
+    expires             text,
+    block_number        text,
+    tx_hash             text,
+    ordinal             text,
+    timestamp           text
+);
+
+create table token_bound_accounts
+(
+    id                  text not null constraint token_bound_account_pk primary key,
+    account             text,
+    implementation      text,
+    salt                text,
+    chain_id            text,
+    contract_address    text,
+    token_id            text,
+    block_number        text,
+    tx_hash             text,
+    ordinal             text,
+    timestamp           text
 );
 
 create table contracts
@@ -31,7 +117,103 @@ create table contracts
     id          text not null constraint contract_pk primary key,
     contract    text,
     base_uri    text,
-    deployer    text
+    deployer    text,
+    standard    text,
+    implementation  text,
+    owner       text
+);
+
+create table sales
+(
+    id                  text not null constraint sale_pk primary key,
+    marketplace         text,
+    marketplace_address text,
+    order_hash          text,
+    contract_address    text,
+    token_id            text,
+    quantity            text,
+    item_count          text,
+    seller              text,
+    buyer               text,
+    payment_token       text,
+    price               text,
+    block_number        text,
+    tx_hash             text,
+    ordinal             text,
+    timestamp           text
+);
+
+create table sale_payouts
+(
+    id                  text not null constraint sale_payout_pk primary key,
+    sale_id             text,
+    role                text,
+    recipient           text,
+    payment_token       text,
+    amount              text,
+    contract_address    text,
+    token_id            text,
+    block_number        text,
+    tx_hash             text,
+    timestamp           text
+);
+
+create table order_cancellations
+(
+    id                  text not null constraint order_cancellation_pk primary key,
+    kind                text,
+    marketplace         text,
+    marketplace_address text,
+    order_hash          text,
+    offerer             text,
+    zone                text,
+    counter             text,
+    block_number        text,
+    tx_hash             text,
+    ordinal             text,
+    timestamp           text
+);
+
+create table punk_orders
+(
+    id                  text not null constraint punk_order_pk primary key,
+    kind                text,
+    contract_address    text,
+    token_id            text,
+    value               text,
+    from_address        text,
+    to_address          text,
+    block_number        text,
+    tx_hash             text,
+    ordinal             text,
+    timestamp           text
+);
+
+create table contract_upgrades
+(
+    id                  text not null constraint contract_upgrade_pk primary key,
+    kind                text,
+    source              text,
+    contract_address    text,
+    implementation      text,
+    previous_admin      text,
+    new_admin           text,
+    block_number        text,
+    tx_hash             text,
+    ordinal             text,
+    timestamp           text
+);
+
+create table contract_ownership_transfers
+(
+    id                  text not null constraint contract_ownership_transfer_pk primary key,
+    contract_address    text,
+    previous_owner      text,
+    new_owner           text,
+    block_number        text,
+    tx_hash             text,
+    ordinal             text,
+    timestamp           text
 );
 
 create table cursors
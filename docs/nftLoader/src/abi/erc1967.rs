@@ -0,0 +1,182 @@
+const INTERNAL_ERR: &'static str = "`ethabi_derive` internal error";
+/// Contract's functions.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod functions {
+    use super::INTERNAL_ERR;
+}
+/// Contract's events.
+#[allow(dead_code, unused_imports, unused_variables)]
+pub mod events {
+    use super::INTERNAL_ERR;
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct AdminChanged {
+        pub previous_admin: Vec<u8>,
+        pub new_admin: Vec<u8>,
+    }
+    impl AdminChanged {
+        const TOPIC_ID: [u8; 32] = [
+            126u8,
+            100u8,
+            77u8,
+            121u8,
+            66u8,
+            47u8,
+            23u8,
+            192u8,
+            30u8,
+            72u8,
+            148u8,
+            181u8,
+            244u8,
+            245u8,
+            136u8,
+            211u8,
+            49u8,
+            235u8,
+            250u8,
+            40u8,
+            101u8,
+            61u8,
+            66u8,
+            174u8,
+            131u8,
+            45u8,
+            197u8,
+            158u8,
+            56u8,
+            201u8,
+            121u8,
+            143u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 1usize {
+                return false;
+            }
+            if log.data.len() != 64usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            let mut values = ethabi::decode(
+                    &[ethabi::ParamType::Address, ethabi::ParamType::Address],
+                    log.data.as_ref(),
+                )
+                .map_err(|e| format!("unable to decode log.data: {:?}", e))?;
+            values.reverse();
+            Ok(Self {
+                previous_admin: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+                new_admin: values
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+            })
+        }
+    }
+    impl substreams_ethereum::Event for AdminChanged {
+        const NAME: &'static str = "AdminChanged";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Upgraded {
+        pub implementation: Vec<u8>,
+    }
+    impl Upgraded {
+        const TOPIC_ID: [u8; 32] = [
+            188u8,
+            124u8,
+            215u8,
+            90u8,
+            32u8,
+            238u8,
+            39u8,
+            253u8,
+            154u8,
+            222u8,
+            186u8,
+            179u8,
+            32u8,
+            65u8,
+            247u8,
+            85u8,
+            33u8,
+            77u8,
+            188u8,
+            107u8,
+            255u8,
+            169u8,
+            12u8,
+            192u8,
+            34u8,
+            91u8,
+            57u8,
+            218u8,
+            46u8,
+            92u8,
+            45u8,
+            59u8,
+        ];
+        pub fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            if log.topics.len() != 2usize {
+                return false;
+            }
+            if log.data.len() != 0usize {
+                return false;
+            }
+            return log.topics.get(0).expect("bounds already checked").as_ref()
+                == Self::TOPIC_ID;
+        }
+        pub fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Ok(Self {
+                implementation: ethabi::decode(
+                        &[ethabi::ParamType::Address],
+                        log.topics[1usize].as_ref(),
+                    )
+                    .map_err(|e| {
+                        format!(
+                            "unable to decode param 'implementation' from topic of type 'address': {:?}",
+                            e
+                        )
+                    })?
+                    .pop()
+                    .expect(INTERNAL_ERR)
+                    .into_address()
+                    .expect(INTERNAL_ERR)
+                    .as_bytes()
+                    .to_vec(),
+            })
+        }
+    }
+    impl substreams_ethereum::Event for Upgraded {
+        const NAME: &'static str = "Upgraded";
+        fn match_log(log: &substreams_ethereum::pb::eth::v2::Log) -> bool {
+            Self::match_log(log)
+        }
+        fn decode(
+            log: &substreams_ethereum::pb::eth::v2::Log,
+        ) -> Result<Self, String> {
+            Self::decode(log)
+        }
+    }
+}
@@ -1,3 +1,13 @@
+pub mod cryptopunks;
 pub mod erc1155;
+pub mod erc1967;
 pub mod erc20;
-pub mod erc721;
\ No newline at end of file
+pub mod erc2309;
+pub mod erc4906;
+pub mod erc4907;
+pub mod erc5192;
+pub mod erc6551;
+pub mod erc721;
+pub mod erc721_legacy;
+pub mod ownable;
+pub mod seaport;
\ No newline at end of file
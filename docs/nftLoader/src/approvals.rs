@@ -0,0 +1,73 @@
+use prost_types::Timestamp;
+use substreams_ethereum::pb as ethpb;
+use substreams_ethereum::Event;
+
+use crate::abi::erc721::events::Approval as ERC721ApprovalEvent;
+use crate::abi::erc721::events::ApprovalForAll as ApprovalForAllEvent;
+use crate::approval_kind_to_string;
+use crate::pb::transfers;
+use crate::pb::transfers::approval::Kind as ApprovalKind;
+
+// ERC20 `Approval` shares the signature of the ERC721 one but doesn't index its amount, it's not
+// matched since the event is decoded with 4 topics.
+pub fn transform_block_to_approvals(blk: &ethpb::eth::v2::Block) -> Vec<transfers::Approval> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts().flat_map(|receipt| {
+        let hash = &receipt.transaction.hash;
+        let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+        receipt.receipt.logs.iter().filter_map(move |log| {
+            if let Some(event) = ERC721ApprovalEvent::match_and_decode(log) {
+                return Some(new_approval(
+                    hash,
+                    log,
+                    ApprovalKind::Approval,
+                    event.owner,
+                    event.approved,
+                    event.token_id.to_bytes_be().1,
+                    true,
+                    blk.number,
+                    timestamp.clone(),
+                ));
+            }
+
+            ApprovalForAllEvent::match_and_decode(log).map(|event| new_approval(
+                hash,
+                log,
+                ApprovalKind::ApprovalForAll,
+                event.owner,
+                event.operator,
+                Vec::new(),
+                event.approved,
+                blk.number,
+                timestamp.clone(),
+            ))
+        })
+    }).collect()
+}
+
+fn new_approval(
+    hash: &[u8],
+    log: &ethpb::eth::v2::Log,
+    kind: ApprovalKind,
+    owner: Vec<u8>,
+    spender: Vec<u8>,
+    token_id: Vec<u8>,
+    approved: bool,
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+) -> transfers::Approval {
+    transfers::Approval {
+        kind: approval_kind_to_string(kind),
+        contract_address: log.address.to_vec(),
+        owner: owner,
+        spender: spender,
+        token_id: token_id,
+        approved: approved,
+        tx_hash: hash.to_vec(),
+        block_number: block_number,
+        ordinal: log.block_index as u64,
+        timestamp: timestamp,
+    }
+}
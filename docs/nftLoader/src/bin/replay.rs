@@ -0,0 +1,118 @@
+// Runs `db_out` natively over blocks read from disk and prints its output, to debug a block
+// without a substreams endpoint:
+//
+//     cargo run --bin replay -- [--format json|sql] [--start <block>] [--stop <block>] [--params <params>] <path>
+//
+// `path` is a Firehose merged-block file (`.dbin` or `.dbin.zst`), a directory with one
+// serialized `Block` per file or a file of length-delimited `Block` messages like the test
+// fixtures. The stores start empty at the first block read, `--start` and `--stop` only select the
+// blocks printed. `--params` are given to every module, like the `params` of `substreams.yaml`.
+use std::env;
+use std::path::PathBuf;
+use std::process;
+
+use anyhow::anyhow;
+use serde_json::json;
+
+use substreams_postgresql_sink_tutorial::blocks::read_blocks;
+use substreams_postgresql_sink_tutorial::output::{database_changes_to_json, database_changes_to_sql};
+use substreams_postgresql_sink_tutorial::params::Params;
+use substreams_postgresql_sink_tutorial::pipeline::Pipeline;
+
+const USAGE: &str = "usage: replay [--format json|sql] [--start <block>] [--stop <block>] [--params <params>] <path>";
+
+#[derive(PartialEq)]
+enum Format {
+    Json,
+    Sql,
+}
+
+struct Args {
+    format: Format,
+    start: u64,
+    stop: u64,
+    params: Params,
+    path: PathBuf,
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{:#}", err);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), anyhow::Error> {
+    let args = parse_args(env::args().skip(1).collect())?;
+    let mut pipeline = Pipeline::with_params(args.params);
+
+    for blk in read_blocks(&args.path)? {
+        if blk.number > args.stop {
+            break;
+        }
+
+        let database_changes = pipeline.db_out(&blk);
+        if blk.number < args.start {
+            continue;
+        }
+
+        match args.format {
+            Format::Json => println!("{}", json!({
+                "block_number": blk.number,
+                "db_out": database_changes_to_json(&database_changes),
+            })),
+            Format::Sql => {
+                println!("-- block {}", blk.number);
+                for statement in database_changes_to_sql(&database_changes) {
+                    println!("{}", statement);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_args(args: Vec<String>) -> Result<Args, anyhow::Error> {
+    let mut format = Format::Json;
+    let mut start = 0;
+    let mut stop = u64::MAX;
+    let mut params = Params::default();
+    let mut path = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                format = match args.next().as_deref() {
+                    Some("json") => Format::Json,
+                    Some("sql") => Format::Sql,
+                    _ => return Err(anyhow!("--format is json or sql\n{}", USAGE)),
+                }
+            }
+            "--start" => start = parse_block_number("--start", args.next())?,
+            "--stop" => stop = parse_block_number("--stop", args.next())?,
+            "--params" => params = Params::parse(&args.next().unwrap_or_default())?,
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            _ if path.is_none() && !arg.starts_with("--") => path = Some(PathBuf::from(arg)),
+            _ => return Err(anyhow!("unexpected argument {}\n{}", arg, USAGE)),
+        }
+    }
+
+    Ok(Args {
+        format: format,
+        start: start,
+        stop: stop,
+        params: params,
+        path: path.ok_or_else(|| anyhow!(USAGE))?,
+    })
+}
+
+fn parse_block_number(flag: &str, value: Option<String>) -> Result<u64, anyhow::Error> {
+    value
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| anyhow!("{} takes a block number\n{}", flag, USAGE))
+}
@@ -0,0 +1,51 @@
+// Converts `eth_getBlockByNumber` and receipts JSON saved from a node into length-delimited
+// `Block` messages, the format of the test fixtures also read by `replay`:
+//
+//     cargo run --bin rpc_to_fixture -- <output> <block.json> <receipts.json> [<block.json> <receipts.json>...]
+//
+// The block must be fetched with full transactions. The receipts file holds the response of
+// `eth_getBlockReceipts`, of `eth_getTransactionReceipt` or an array of `eth_getTransactionReceipt`
+// responses.
+use std::env;
+use std::fs;
+use std::process;
+
+use anyhow::{anyhow, Context};
+use prost::Message;
+use serde_json::Value;
+
+use substreams_postgresql_sink_tutorial::rpc::{rpc_to_block, rpc_to_receipts};
+
+const USAGE: &str = "usage: rpc_to_fixture <output> <block.json> <receipts.json> [<block.json> <receipts.json>...]";
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{:#}", err);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), anyhow::Error> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.len() < 3 || args.len() % 2 == 0 {
+        return Err(anyhow!(USAGE));
+    }
+
+    let mut content = Vec::new();
+
+    for pair in args[1..].chunks(2) {
+        let block = read_json(&pair[0])?;
+        let receipts = rpc_to_receipts(&read_json(&pair[1])?).with_context(|| format!("reading {}", pair[1]))?;
+
+        let blk = rpc_to_block(&block, &receipts).with_context(|| format!("converting {}", pair[0]))?;
+        blk.encode_length_delimited(&mut content)?;
+        eprintln!("block {} with {} transactions", blk.number, blk.transaction_traces.len());
+    }
+
+    fs::write(&args[0], content).with_context(|| format!("writing {}", args[0]))
+}
+
+fn read_json(path: &str) -> Result<Value, anyhow::Error> {
+    let content = fs::read(path).with_context(|| format!("reading {}", path))?;
+    serde_json::from_slice(&content).with_context(|| format!("parsing {}", path))
+}
@@ -0,0 +1,374 @@
+// Blocks as JSON, for fixtures that can be read and reviewed unlike serialized messages. Fields
+// are named like in the proto and only the ones read by the modules are kept: bytes and big
+// integers are lowercase hex without `0x`, enums are the names of their proto values, timestamps
+// are in seconds and fields holding their default value are left out.
+use anyhow::{anyhow, Context};
+use prost_types::Timestamp;
+use serde_json::{json, Map, Value};
+use substreams::Hex;
+use substreams_ethereum::pb as ethpb;
+use substreams_ethereum::pb::eth::v2::{CallType, TransactionTraceStatus};
+
+const CALL_TYPES: [(CallType, &str); 6] = [
+    (CallType::Unspecified, "UNSPECIFIED"),
+    (CallType::Call, "CALL"),
+    (CallType::Callcode, "CALLCODE"),
+    (CallType::Delegate, "DELEGATE"),
+    (CallType::Static, "STATIC"),
+    (CallType::Create, "CREATE"),
+];
+
+const STATUSES: [(TransactionTraceStatus, &str); 4] = [
+    (TransactionTraceStatus::Unknown, "UNKNOWN"),
+    (TransactionTraceStatus::Succeeded, "SUCCEEDED"),
+    (TransactionTraceStatus::Failed, "FAILED"),
+    (TransactionTraceStatus::Reverted, "REVERTED"),
+];
+
+pub fn block_to_json(blk: &ethpb::eth::v2::Block) -> Value {
+    let mut fields = Fields::default();
+    fields.uint("ver", blk.ver as u64);
+    fields.bytes("hash", &blk.hash);
+    fields.uint("number", blk.number);
+    fields.value("header", blk.header.as_ref().map(header_to_json));
+    fields.array("transaction_traces", blk.transaction_traces.iter().map(transaction_to_json));
+    fields.0
+}
+
+pub fn block_from_json(value: &Value) -> Result<ethpb::eth::v2::Block, anyhow::Error> {
+    Ok(ethpb::eth::v2::Block {
+        ver: uint(value, "ver")? as i32,
+        hash: bytes(value, "hash")?,
+        number: uint(value, "number")?,
+        header: optional(value, "header", header_from_json)?,
+        transaction_traces: array(value, "transaction_traces", transaction_from_json)?,
+        ..Default::default()
+    })
+}
+
+fn header_to_json(header: &ethpb::eth::v2::BlockHeader) -> Value {
+    let mut fields = Fields::default();
+    fields.bytes("parent_hash", &header.parent_hash);
+    fields.uint("number", header.number);
+    fields.bytes("hash", &header.hash);
+    fields.uint("timestamp", header.timestamp.as_ref().map_or(0, |timestamp| timestamp.seconds as u64));
+    fields.big_int("base_fee_per_gas", &header.base_fee_per_gas);
+    fields.0
+}
+
+fn header_from_json(value: &Value) -> Result<ethpb::eth::v2::BlockHeader, anyhow::Error> {
+    Ok(ethpb::eth::v2::BlockHeader {
+        parent_hash: bytes(value, "parent_hash")?,
+        number: uint(value, "number")?,
+        hash: bytes(value, "hash")?,
+        timestamp: Some(Timestamp { seconds: uint(value, "timestamp")? as i64, nanos: 0 }),
+        base_fee_per_gas: big_int(value, "base_fee_per_gas")?,
+        ..Default::default()
+    })
+}
+
+fn transaction_to_json(transaction: &ethpb::eth::v2::TransactionTrace) -> Value {
+    let mut fields = Fields::default();
+    fields.bytes("hash", &transaction.hash);
+    fields.uint("index", transaction.index as u64);
+    fields.bytes("from", &transaction.from);
+    fields.bytes("to", &transaction.to);
+    fields.big_int("value", &transaction.value);
+    fields.bytes("input", &transaction.input);
+    fields.big_int("gas_price", &transaction.gas_price);
+    fields.big_int("max_fee_per_gas", &transaction.max_fee_per_gas);
+    fields.big_int("max_priority_fee_per_gas", &transaction.max_priority_fee_per_gas);
+    fields.uint("gas_used", transaction.gas_used);
+    fields.enumeration("status", transaction.status, &STATUSES);
+    fields.uint("begin_ordinal", transaction.begin_ordinal);
+    fields.uint("end_ordinal", transaction.end_ordinal);
+    fields.value("receipt", transaction.receipt.as_ref().map(|receipt| {
+        let mut fields = Fields::default();
+        fields.array("logs", receipt.logs.iter().map(log_to_json));
+        fields.0
+    }));
+    fields.array("calls", transaction.calls.iter().map(call_to_json));
+    fields.0
+}
+
+fn transaction_from_json(value: &Value) -> Result<ethpb::eth::v2::TransactionTrace, anyhow::Error> {
+    Ok(ethpb::eth::v2::TransactionTrace {
+        hash: bytes(value, "hash")?,
+        index: uint(value, "index")? as u32,
+        from: bytes(value, "from")?,
+        to: bytes(value, "to")?,
+        value: big_int(value, "value")?,
+        input: bytes(value, "input")?,
+        gas_price: big_int(value, "gas_price")?,
+        max_fee_per_gas: big_int(value, "max_fee_per_gas")?,
+        max_priority_fee_per_gas: big_int(value, "max_priority_fee_per_gas")?,
+        gas_used: uint(value, "gas_used")?,
+        status: enumeration(value, "status", &STATUSES)?,
+        begin_ordinal: uint(value, "begin_ordinal")?,
+        end_ordinal: uint(value, "end_ordinal")?,
+        receipt: optional(value, "receipt", |receipt| {
+            Ok(ethpb::eth::v2::TransactionReceipt {
+                logs: array(receipt, "logs", log_from_json)?,
+                ..Default::default()
+            })
+        })?,
+        calls: array(value, "calls", call_from_json)?,
+        ..Default::default()
+    })
+}
+
+fn call_to_json(call: &ethpb::eth::v2::Call) -> Value {
+    let mut fields = Fields::default();
+    fields.uint("index", call.index as u64);
+    fields.uint("parent_index", call.parent_index as u64);
+    fields.uint("depth", call.depth as u64);
+    fields.enumeration("call_type", call.call_type, &CALL_TYPES);
+    fields.bytes("caller", &call.caller);
+    fields.bytes("address", &call.address);
+    fields.big_int("value", &call.value);
+    fields.bytes("input", &call.input);
+    fields.boolean("status_failed", call.status_failed);
+    fields.boolean("status_reverted", call.status_reverted);
+    fields.boolean("state_reverted", call.state_reverted);
+    fields.uint("begin_ordinal", call.begin_ordinal);
+    fields.uint("end_ordinal", call.end_ordinal);
+    fields.array("logs", call.logs.iter().map(log_to_json));
+    fields.array("storage_changes", call.storage_changes.iter().map(|change| {
+        let mut fields = Fields::default();
+        fields.bytes("address", &change.address);
+        fields.bytes("key", &change.key);
+        fields.bytes("old_value", &change.old_value);
+        fields.bytes("new_value", &change.new_value);
+        fields.uint("ordinal", change.ordinal);
+        fields.0
+    }));
+    fields.array("code_changes", call.code_changes.iter().map(|change| {
+        let mut fields = Fields::default();
+        fields.bytes("address", &change.address);
+        fields.bytes("old_hash", &change.old_hash);
+        fields.bytes("old_code", &change.old_code);
+        fields.bytes("new_hash", &change.new_hash);
+        fields.bytes("new_code", &change.new_code);
+        fields.uint("ordinal", change.ordinal);
+        fields.0
+    }));
+    fields.0
+}
+
+fn call_from_json(value: &Value) -> Result<ethpb::eth::v2::Call, anyhow::Error> {
+    Ok(ethpb::eth::v2::Call {
+        index: uint(value, "index")? as u32,
+        parent_index: uint(value, "parent_index")? as u32,
+        depth: uint(value, "depth")? as u32,
+        call_type: enumeration(value, "call_type", &CALL_TYPES)?,
+        caller: bytes(value, "caller")?,
+        address: bytes(value, "address")?,
+        value: big_int(value, "value")?,
+        input: bytes(value, "input")?,
+        status_failed: boolean(value, "status_failed")?,
+        status_reverted: boolean(value, "status_reverted")?,
+        state_reverted: boolean(value, "state_reverted")?,
+        begin_ordinal: uint(value, "begin_ordinal")?,
+        end_ordinal: uint(value, "end_ordinal")?,
+        logs: array(value, "logs", log_from_json)?,
+        storage_changes: array(value, "storage_changes", |change| {
+            Ok(ethpb::eth::v2::StorageChange {
+                address: bytes(change, "address")?,
+                key: bytes(change, "key")?,
+                old_value: bytes(change, "old_value")?,
+                new_value: bytes(change, "new_value")?,
+                ordinal: uint(change, "ordinal")?,
+            })
+        })?,
+        code_changes: array(value, "code_changes", |change| {
+            Ok(ethpb::eth::v2::CodeChange {
+                address: bytes(change, "address")?,
+                old_hash: bytes(change, "old_hash")?,
+                old_code: bytes(change, "old_code")?,
+                new_hash: bytes(change, "new_hash")?,
+                new_code: bytes(change, "new_code")?,
+                ordinal: uint(change, "ordinal")?,
+            })
+        })?,
+        ..Default::default()
+    })
+}
+
+fn log_to_json(log: &ethpb::eth::v2::Log) -> Value {
+    let mut fields = Fields::default();
+    fields.bytes("address", &log.address);
+    fields.value("topics", match log.topics.is_empty() {
+        true => None,
+        false => Some(log.topics.iter().map(|topic| Value::String(Hex(topic).to_string())).collect()),
+    });
+    fields.bytes("data", &log.data);
+    fields.uint("index", log.index as u64);
+    fields.uint("block_index", log.block_index as u64);
+    fields.uint("ordinal", log.ordinal);
+    fields.0
+}
+
+fn log_from_json(value: &Value) -> Result<ethpb::eth::v2::Log, anyhow::Error> {
+    Ok(ethpb::eth::v2::Log {
+        address: bytes(value, "address")?,
+        topics: array(value, "topics", |topic| decode_hex(topic.as_str().unwrap_or_default()))?,
+        data: bytes(value, "data")?,
+        index: uint(value, "index")? as u32,
+        block_index: uint(value, "block_index")? as u32,
+        ordinal: uint(value, "ordinal")?,
+    })
+}
+
+// The fields of an object, without the ones holding their default value.
+struct Fields(Value);
+
+impl Default for Fields {
+    fn default() -> Self {
+        Fields(Value::Object(Map::new()))
+    }
+}
+
+impl Fields {
+    fn value(&mut self, name: &str, value: Option<Value>) {
+        if let (Value::Object(fields), Some(value)) = (&mut self.0, value) {
+            fields.insert(name.to_string(), value);
+        }
+    }
+
+    fn uint(&mut self, name: &str, value: u64) {
+        self.value(name, Some(json!(value)).filter(|_| value != 0));
+    }
+
+    fn boolean(&mut self, name: &str, value: bool) {
+        self.value(name, Some(json!(value)).filter(|_| value));
+    }
+
+    fn bytes(&mut self, name: &str, value: &[u8]) {
+        self.value(name, Some(json!(Hex(value).to_string())).filter(|_| !value.is_empty()));
+    }
+
+    fn big_int(&mut self, name: &str, value: &Option<ethpb::eth::v2::BigInt>) {
+        self.value(name, value.as_ref().map(|value| json!(Hex(&value.bytes).to_string())));
+    }
+
+    fn enumeration<E: Copy + Into<i32>>(&mut self, name: &str, value: i32, names: &[(E, &str)]) {
+        let name_of_value = names.iter().find(|(variant, _)| (*variant).into() == value).map(|(_, name)| *name);
+        self.value(name, Some(json!(name_of_value.unwrap_or_default())).filter(|_| value != 0));
+    }
+
+    fn array(&mut self, name: &str, values: impl Iterator<Item = Value>) {
+        let values: Vec<Value> = values.collect();
+        self.value(name, Some(Value::Array(values)).filter(|values| !values.as_array().unwrap().is_empty()));
+    }
+}
+
+// Missing fields are the default value, like absent fields of the proto.
+fn uint(value: &Value, name: &str) -> Result<u64, anyhow::Error> {
+    match value.get(name) {
+        None => Ok(0),
+        Some(field) => field.as_u64().ok_or_else(|| anyhow!("{} is not an unsigned integer: {}", name, field)),
+    }
+}
+
+fn boolean(value: &Value, name: &str) -> Result<bool, anyhow::Error> {
+    match value.get(name) {
+        None => Ok(false),
+        Some(field) => field.as_bool().ok_or_else(|| anyhow!("{} is not a boolean: {}", name, field)),
+    }
+}
+
+fn bytes(value: &Value, name: &str) -> Result<Vec<u8>, anyhow::Error> {
+    match value.get(name) {
+        None => Ok(Vec::new()),
+        Some(field) => field.as_str()
+            .ok_or_else(|| anyhow!("{} is not a string: {}", name, field))
+            .and_then(decode_hex)
+            .with_context(|| format!("decoding {}", name)),
+    }
+}
+
+fn big_int(value: &Value, name: &str) -> Result<Option<ethpb::eth::v2::BigInt>, anyhow::Error> {
+    match value.get(name) {
+        None => Ok(None),
+        Some(_) => Ok(Some(ethpb::eth::v2::BigInt { bytes: bytes(value, name)? })),
+    }
+}
+
+fn enumeration<E: Copy + Into<i32>>(value: &Value, name: &str, names: &[(E, &str)]) -> Result<i32, anyhow::Error> {
+    let field = match value.get(name) {
+        None => return Ok(0),
+        Some(field) => field.as_str().ok_or_else(|| anyhow!("{} is not a string: {}", name, field))?,
+    };
+
+    names.iter()
+        .find(|(_, variant_name)| *variant_name == field)
+        .map(|(variant, _)| (*variant).into())
+        .ok_or_else(|| anyhow!("unknown {} {}", name, field))
+}
+
+fn optional<T>(value: &Value, name: &str, from_json: impl Fn(&Value) -> Result<T, anyhow::Error>) -> Result<Option<T>, anyhow::Error> {
+    value.get(name).map(|field| from_json(field).with_context(|| format!("decoding {}", name))).transpose()
+}
+
+fn array<T>(value: &Value, name: &str, from_json: impl Fn(&Value) -> Result<T, anyhow::Error>) -> Result<Vec<T>, anyhow::Error> {
+    let values = match value.get(name) {
+        None => return Ok(Vec::new()),
+        Some(field) => field.as_array().ok_or_else(|| anyhow!("{} is not an array: {}", name, field))?,
+    };
+
+    values.iter()
+        .enumerate()
+        .map(|(i, value)| from_json(value).with_context(|| format!("decoding {}[{}]", name, i)))
+        .collect()
+}
+
+fn decode_hex(value: &str) -> Result<Vec<u8>, anyhow::Error> {
+    if value.len() % 2 != 0 {
+        return Err(anyhow!("odd number of digits in {}", value));
+    }
+
+    (0..value.len()).step_by(2)
+        .map(|i| u8::from_str_radix(&value[i..i + 2], 16).map_err(|err| anyhow!("invalid hex {}: {}", value, err)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::{
+        erc1155_transfer_single_log, erc721_transfer_log, BlockBuilder, CallBuilder, TransactionBuilder,
+    };
+
+    #[test]
+    fn it_block_to_json_and_back() {
+        let contract = [0x72; 20];
+        let alice = [0xa1; 20];
+        let bob = [0xb0; 20];
+
+        let blk = BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&alice).call(
+                CallBuilder::new(&contract)
+                    .input(&[0x23, 0xb8, 0x72, 0xdd])
+                    .code(&[0x60, 0x80])
+                    .log(erc721_transfer_log(&contract, &alice, &bob, 1)),
+            ))
+            .transaction(TransactionBuilder::new(0x02).from(&alice).status(TransactionTraceStatus::Failed).call(
+                CallBuilder::new(&contract).log(erc1155_transfer_single_log(&contract, &alice, &alice, &bob, 7, 3)),
+            ))
+            .build();
+
+        let value = block_to_json(&blk);
+
+        assert_eq!(value["transaction_traces"][1]["status"], json!("FAILED"));
+        assert_eq!(value["transaction_traces"][0]["calls"][0]["call_type"], json!("CALL"));
+        assert_eq!(block_from_json(&value).unwrap(), blk);
+    }
+
+    #[test]
+    fn it_rejects_invalid_fields() {
+        assert!(block_from_json(&json!({ "number": "12287507" })).is_err());
+        assert!(block_from_json(&json!({ "hash": "0x01" })).is_err());
+        assert!(block_from_json(&json!({ "transaction_traces": [{ "status": "DONE" }] })).is_err());
+    }
+}
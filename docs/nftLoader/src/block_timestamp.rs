@@ -55,7 +55,17 @@ impl BlockTimestamp {
                 )),
                 _ => Err(anyhow!("invalid key")),
             },
-            _ => Err(anyhow!("invalid key")),
+            // Any other key is a timestamp in milliseconds since the epoch, the format the tests of
+            // this module have always used.
+            millis => match millis.parse::<i64>() {
+                Ok(millis) => NaiveDateTime::from_timestamp_opt(
+                    millis.div_euclid(1000),
+                    (millis.rem_euclid(1000) * 1_000_000) as u32,
+                )
+                .map(BlockTimestamp)
+                .ok_or_else(|| anyhow!("invalid key")),
+                Err(_) => Err(anyhow!("invalid key")),
+            },
         }
     }
 
@@ -179,6 +189,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_block_timestamp_try_from_key_with_period_and_millisecond_keys() {
+        assert_eq!(
+            BlockTimestamp::try_from_key("day:last:20210705").unwrap(),
+            timestamp(2021, 7, 5, 23, 59, 59, 999)
+        );
+        assert_eq!(
+            BlockTimestamp::try_from_key("month:first:202107").unwrap(),
+            timestamp(2021, 7, 1, 0, 0, 0, 0)
+        );
+        assert_eq!(
+            BlockTimestamp::try_from_key("1625480514354").unwrap(),
+            timestamp(2021, 7, 5, 10, 21, 54, 354)
+        );
+        assert_eq!(
+            BlockTimestamp::try_from_key("-1").unwrap(),
+            timestamp(1969, 12, 31, 23, 59, 59, 999)
+        );
+
+        assert!(BlockTimestamp::try_from_key("week:first:20210705").is_err());
+        assert!(BlockTimestamp::try_from_key("1625480514.354").is_err());
+    }
+
     #[test]
     fn it_block_timestamp_start_of_day() {
         let input = timestamp(2021, 7, 5, 10, 21, 54, 354);
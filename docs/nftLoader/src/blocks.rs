@@ -0,0 +1,251 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Context};
+use prost::Message;
+use substreams_ethereum::pb as ethpb;
+
+use crate::block_json::block_from_json;
+
+const DBIN_MAGIC: &[u8] = b"dbin";
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+// `sf.bstream.v1.Block`, the envelope of the blocks in merged-block files, numbered like the
+// upstream proto with the fields that aren't needed left out. Older files carry the chain specific
+// block in `payload_buffer`, newer ones in `payload`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+struct BstreamBlock {
+    #[prost(uint64, tag = "1")]
+    number: u64,
+    #[prost(bytes = "vec", tag = "8")]
+    payload_buffer: Vec<u8>,
+    #[prost(uint64, tag = "10")]
+    parent_num: u64,
+    #[prost(message, optional, tag = "11")]
+    payload: Option<::prost_types::Any>,
+}
+
+// Reads the blocks at `path`, in the order of their numbers:
+// - a directory holds one serialized `Block` per file,
+// - a `.dbin` file is a Firehose merged-block bundle,
+// - a `.json` file holds an array of blocks in the format of `block_json.rs`, like the test fixtures,
+// - any other file holds length-delimited `Block` messages, like the output of `rpc_to_fixture`.
+// Files compressed with zstd, like the `.dbin.zst` merged-block files, are decompressed first.
+pub fn read_blocks(path: &Path) -> Result<Vec<ethpb::eth::v2::Block>, anyhow::Error> {
+    let mut blocks = if path.is_dir() {
+        read_block_files(path)?
+    } else {
+        let content = read_file(path)?;
+
+        if path.extension().map_or(false, |extension| extension == "json") {
+            read_json_blocks(&content).with_context(|| format!("reading blocks {}", path.display()))?
+        } else if content.starts_with(DBIN_MAGIC) {
+            read_merged_blocks(&content).with_context(|| format!("reading merged blocks {}", path.display()))?
+        } else {
+            read_length_delimited_blocks(&content).with_context(|| format!("reading blocks {}", path.display()))?
+        }
+    };
+
+    blocks.sort_by_key(|blk| blk.number);
+    Ok(blocks)
+}
+
+pub fn read_length_delimited_blocks(content: &[u8]) -> Result<Vec<ethpb::eth::v2::Block>, anyhow::Error> {
+    let mut buf = content;
+    let mut blocks = Vec::new();
+
+    while !buf.is_empty() {
+        blocks.push(ethpb::eth::v2::Block::decode_length_delimited(&mut buf)?);
+    }
+
+    Ok(blocks)
+}
+
+pub fn read_json_blocks(content: &[u8]) -> Result<Vec<ethpb::eth::v2::Block>, anyhow::Error> {
+    let values: Vec<serde_json::Value> = serde_json::from_slice(content)?;
+
+    values.iter()
+        .enumerate()
+        .map(|(i, value)| block_from_json(value).with_context(|| format!("decoding block {}", i)))
+        .collect()
+}
+
+// The `dbin` header is the magic, a version byte and the content type, 3 bytes in version 0 and a
+// 2 bytes big endian length followed by the type in version 1. Every message after it is prefixed
+// by its length on 4 bytes, big endian.
+pub fn read_merged_blocks(content: &[u8]) -> Result<Vec<ethpb::eth::v2::Block>, anyhow::Error> {
+    let mut buf = content.strip_prefix(DBIN_MAGIC).ok_or_else(|| anyhow!("not a dbin file"))?;
+
+    let version = take(&mut buf, 1)?[0];
+    match version {
+        // Content type, then a content version on 2 bytes.
+        0 => {
+            take(&mut buf, 5)?;
+        }
+        1 => {
+            let length = u16::from_be_bytes(take(&mut buf, 2)?.try_into().unwrap());
+            take(&mut buf, length as usize)?;
+        }
+        _ => return Err(anyhow!("unsupported dbin version {}", version)),
+    }
+
+    let mut blocks = Vec::new();
+
+    while !buf.is_empty() {
+        let length = u32::from_be_bytes(take(&mut buf, 4)?.try_into().unwrap());
+        let message = take(&mut buf, length as usize)?;
+
+        let bstream_block = BstreamBlock::decode(message)?;
+        let payload = match &bstream_block.payload {
+            Some(any) => &any.value,
+            None => &bstream_block.payload_buffer,
+        };
+
+        let blk = ethpb::eth::v2::Block::decode(payload.as_slice())
+            .with_context(|| format!("decoding block {}", bstream_block.number))?;
+        blocks.push(blk);
+    }
+
+    Ok(blocks)
+}
+
+fn read_block_files(dir: &Path) -> Result<Vec<ethpb::eth::v2::Block>, anyhow::Error> {
+    let mut blocks = Vec::new();
+
+    for entry in fs::read_dir(dir).with_context(|| format!("reading {}", dir.display()))? {
+        let path = entry?.path();
+        if !path.is_file() {
+            continue;
+        }
+
+        let content = read_file(&path)?;
+        let blk = ethpb::eth::v2::Block::decode(content.as_slice())
+            .with_context(|| format!("decoding block {}", path.display()))?;
+        blocks.push(blk);
+    }
+
+    Ok(blocks)
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, anyhow::Error> {
+    let content = fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+
+    match content.starts_with(ZSTD_MAGIC) {
+        true => zstd::decode_all(content.as_slice()).with_context(|| format!("decompressing {}", path.display())),
+        false => Ok(content),
+    }
+}
+
+fn take<'a>(buf: &mut &'a [u8], length: usize) -> Result<&'a [u8], anyhow::Error> {
+    if buf.len() < length {
+        return Err(anyhow!("unexpected end of file, {} bytes missing", length - buf.len()));
+    }
+
+    let (taken, rest) = buf.split_at(length);
+    *buf = rest;
+    Ok(taken)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use substreams::Hex;
+
+    use crate::testing::BlockBuilder;
+
+    const CONTENT_TYPE: &[u8] = b"type.googleapis.com/sf.ethereum.type.v2.Block";
+
+    fn varint(mut value: u64) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        while value >= 0x80 {
+            bytes.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        bytes.push(value as u8);
+        bytes
+    }
+
+    fn varint_field(number: u64, value: u64) -> Vec<u8> {
+        let mut field = varint(number << 3);
+        field.extend(varint(value));
+        field
+    }
+
+    fn bytes_field(number: u64, value: &[u8]) -> Vec<u8> {
+        let mut field = varint(number << 3 | 2);
+        field.extend(varint(value.len() as u64));
+        field.extend(value);
+        field
+    }
+
+    // A `sf.bstream.v1.Block` encoded by hand with the field numbers of the upstream proto rather
+    // than with `BstreamBlock`, with the block in `payload` (11) or in the older `payload_buffer`
+    // (8). `id` (2), `parent_id` (3), `lib_num` (5), `payload_kind` (6), `head_num` (9) and
+    // `parent_num` (10) are set like in real files.
+    fn bstream_block(number: u64, in_payload_buffer: bool) -> Vec<u8> {
+        let blk = BlockBuilder::new(number).build();
+
+        let mut message = varint_field(1, number);
+        message.extend(bytes_field(2, Hex(&blk.hash).to_string().as_bytes()));
+        message.extend(bytes_field(3, Hex(&blk.header.as_ref().unwrap().parent_hash).to_string().as_bytes()));
+        message.extend(varint_field(5, number - 1));
+        message.extend(varint_field(6, 1));
+        message.extend(varint_field(9, number + 1));
+        message.extend(varint_field(10, number - 1));
+
+        if in_payload_buffer {
+            message.extend(bytes_field(8, &blk.encode_to_vec()));
+        } else {
+            let mut any = bytes_field(1, CONTENT_TYPE);
+            any.extend(bytes_field(2, &blk.encode_to_vec()));
+            message.extend(bytes_field(11, &any));
+        }
+
+        message
+    }
+
+    fn merged_blocks(messages: Vec<Vec<u8>>) -> Vec<u8> {
+        let mut content = b"dbin\x01".to_vec();
+        content.extend((CONTENT_TYPE.len() as u16).to_be_bytes());
+        content.extend(CONTENT_TYPE);
+
+        for message in messages {
+            content.extend((message.len() as u32).to_be_bytes());
+            content.extend(message);
+        }
+
+        content
+    }
+
+    #[test]
+    fn it_read_merged_blocks() {
+        let content = merged_blocks(vec![bstream_block(12287507, false), bstream_block(12287508, false)]);
+
+        let blocks = read_merged_blocks(&content).unwrap();
+
+        assert_eq!(blocks, vec![BlockBuilder::new(12287507).build(), BlockBuilder::new(12287508).build()]);
+    }
+
+    #[test]
+    fn it_read_merged_blocks_with_the_block_in_the_payload_buffer() {
+        let content = merged_blocks(vec![bstream_block(12287507, true)]);
+
+        let blocks = read_merged_blocks(&content).unwrap();
+
+        assert_eq!(blocks, vec![BlockBuilder::new(12287507).build()]);
+    }
+
+    #[test]
+    fn it_read_compressed_merged_blocks() {
+        let path = std::env::temp_dir().join(format!("blocks-{}.dbin.zst", std::process::id()));
+        let content = zstd::encode_all(merged_blocks(vec![bstream_block(12287508, false), bstream_block(12287507, false)]).as_slice(), 0).unwrap();
+        fs::write(&path, content).unwrap();
+
+        let blocks = read_blocks(&path);
+        fs::remove_file(&path).unwrap();
+
+        let numbers: Vec<u64> = blocks.unwrap().iter().map(|blk| blk.number).collect();
+        assert_eq!(numbers, vec![12287507, 12287508]);
+    }
+}
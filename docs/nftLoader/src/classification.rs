@@ -0,0 +1,216 @@
+use std::collections::HashSet;
+
+use hex_literal::hex;
+use prost_types::Timestamp;
+use substreams_ethereum::pb as ethpb;
+
+use crate::pb::transfers;
+use crate::{block_log_ordinals, log_block_index};
+use crate::pb::transfers::contract_classification::Standard;
+use crate::standard_to_string;
+
+// ERC165 interface ids, found in the code of `supportsInterface`.
+const ERC721_INTERFACE_ID: [u8; 4] = hex!("80ac58cd");
+const ERC1155_INTERFACE_ID: [u8; 4] = hex!("d9b67a26");
+
+// Function selectors, found in the dispatcher of the contract. Only selectors that can't be
+// mistaken for another standard's are used.
+const ERC721_SELECTORS: [[u8; 4]; 2] = [
+    // ownerOf(uint256)
+    hex!("6352211e"),
+    // safeTransferFrom(address,address,uint256)
+    hex!("42842e0e"),
+];
+const ERC1155_SELECTORS: [[u8; 4]; 2] = [
+    // safeTransferFrom(address,address,uint256,uint256,bytes)
+    hex!("f242432a"),
+    // safeBatchTransferFrom(address,address,uint256[],uint256[],bytes)
+    hex!("2eb2c2d6"),
+];
+const ERC20_SELECTORS: [[u8; 4]; 3] = [
+    // transfer(address,uint256)
+    hex!("a9059cbb"),
+    // totalSupply()
+    hex!("18160ddd"),
+    // allowance(address,address)
+    hex!("dd62ed3e"),
+];
+
+const OPCODE_PUSH1: u8 = 0x60;
+const OPCODE_PUSH4: u8 = 0x63;
+const OPCODE_PUSH32: u8 = 0x7f;
+const OPCODE_DELEGATECALL: u8 = 0xf4;
+
+pub fn transform_block_to_contract_classifications(blk: &ethpb::eth::v2::Block) -> Vec<transfers::ContractClassification> {
+    let header = blk.header.as_ref().unwrap();
+    let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+    let log_ordinals = block_log_ordinals(blk);
+    let log_ordinals = &log_ordinals;
+
+    blk.transactions().flat_map(|transaction| {
+        let timestamp = timestamp.clone();
+
+        transaction.calls.iter()
+            .filter(|call| !call.state_reverted)
+            .flat_map(move |call| {
+                let timestamp = timestamp.clone();
+
+                call.code_changes.iter()
+                    .filter(|code_change| !code_change.new_code.is_empty())
+                    .map(move |code_change| new_contract_classification(
+                        &transaction.hash,
+                        call,
+                        code_change,
+                        log_block_index(log_ordinals, code_change.ordinal),
+                        blk.number,
+                        timestamp.clone(),
+                    ))
+            })
+    }).collect()
+}
+
+fn new_contract_classification(
+    hash: &[u8],
+    call: &ethpb::eth::v2::Call,
+    code_change: &ethpb::eth::v2::CodeChange,
+    ordinal: u64,
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+) -> transfers::ContractClassification {
+    transfers::ContractClassification {
+        standard: standard_to_string(classify_code(&code_change.new_code)),
+        contract_address: code_change.address.to_vec(),
+        deployer: call.caller.to_vec(),
+        tx_hash: hash.to_vec(),
+        block_number: block_number,
+        ordinal: ordinal,
+        timestamp: timestamp,
+    }
+}
+
+fn classify_code(code: &[u8]) -> Standard {
+    let (pushed, has_delegatecall) = scan_code(code);
+    let contains_all = |selectors: &[[u8; 4]]| selectors.iter().all(|selector| pushed.contains(selector));
+
+    if pushed.contains(&ERC1155_INTERFACE_ID) || contains_all(&ERC1155_SELECTORS) {
+        return Standard::Erc1155;
+    }
+
+    if pushed.contains(&ERC721_INTERFACE_ID) || contains_all(&ERC721_SELECTORS) {
+        return Standard::Erc721;
+    }
+
+    if contains_all(&ERC20_SELECTORS) {
+        return Standard::Erc20;
+    }
+
+    if has_delegatecall {
+        return Standard::Proxy;
+    }
+
+    Standard::Unknown
+}
+
+// Walks the opcodes of `code`, returning every 4 bytes value pushed on the stack and whether the
+// code can delegate its execution. Push data is skipped so that constants are not mistaken for
+// opcodes.
+fn scan_code(code: &[u8]) -> (HashSet<[u8; 4]>, bool) {
+    let mut pushed = HashSet::new();
+    let mut has_delegatecall = false;
+    let mut i = 0;
+
+    while i < code.len() {
+        let opcode = code[i];
+
+        if (OPCODE_PUSH1..=OPCODE_PUSH32).contains(&opcode) {
+            let size = (opcode - OPCODE_PUSH1 + 1) as usize;
+
+            if opcode == OPCODE_PUSH4 && i + 1 + size <= code.len() {
+                let mut value = [0u8; 4];
+                value.copy_from_slice(&code[i + 1..i + 1 + size]);
+                pushed.insert(value);
+            }
+
+            i += 1 + size;
+            continue;
+        }
+
+        if opcode == OPCODE_DELEGATECALL {
+            has_delegatecall = true;
+        }
+
+        i += 1;
+    }
+
+    (pushed, has_delegatecall)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::{erc721_transfer_log, BlockBuilder, CallBuilder, TransactionBuilder};
+
+    #[test]
+    fn it_classify_code_erc721_interface_id() {
+        // PUSH4 0x80ac58cd, PUSH4 0x01ffc9a7
+        let code = hex!("6380ac58cd6301ffc9a7");
+
+        assert_eq!(classify_code(&code), Standard::Erc721);
+    }
+
+    #[test]
+    fn it_classify_code_erc1155_selectors() {
+        let code = hex!("63f242432a632eb2c2d6");
+
+        assert_eq!(classify_code(&code), Standard::Erc1155);
+    }
+
+    #[test]
+    fn it_classify_code_erc20_selectors() {
+        let code = hex!("63a9059cbb6318160ddd63dd62ed3e");
+
+        assert_eq!(classify_code(&code), Standard::Erc20);
+    }
+
+    #[test]
+    fn it_classify_code_minimal_proxy() {
+        // EIP-1167 minimal proxy
+        let code = hex!("363d3d373d3d3d363d73bebebebebebebebebebebebebebebebebebebebe5af43d82803e903d91602b57fd5bf3");
+
+        assert_eq!(classify_code(&code), Standard::Proxy);
+    }
+
+    #[test]
+    fn it_classify_code_ignores_push_data() {
+        // PUSH5 with the ERC721 interface id in its data, then a DELEGATECALL byte in PUSH1 data
+        let code = hex!("6480ac58cd0060f4");
+
+        assert_eq!(classify_code(&code), Standard::Unknown);
+    }
+
+    #[test]
+    fn it_orders_classifications_by_the_block_index_of_logs() {
+        let contract = [0x72; 20];
+        let alice = [0xa1; 20];
+
+        let blk = BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&alice).call(
+                CallBuilder::new(&[0x11; 20])
+                    .log(erc721_transfer_log(&[0x11; 20], &alice, &alice, 1))
+                    .log(erc721_transfer_log(&[0x11; 20], &alice, &alice, 2)),
+            ))
+            .transaction(TransactionBuilder::new(0x02).from(&alice)
+                .call(CallBuilder::new(&contract).code(&hex!("6380ac58cd")).log(erc721_transfer_log(&contract, &[0; 20], &alice, 1)))
+                .call(CallBuilder::new(&[0x11; 20]).log(erc721_transfer_log(&[0x11; 20], &alice, &alice, 3))))
+            .build();
+
+        let classifications = transform_block_to_contract_classifications(&blk);
+
+        // After the log of the constructor, before the one of the next call.
+        assert_eq!(classifications.len(), 1);
+        assert_eq!(classifications[0].standard, "erc721");
+        assert_eq!(classifications[0].deployer, alice.to_vec());
+        assert_eq!(classifications[0].ordinal, 3);
+    }
+}
@@ -0,0 +1,361 @@
+use hex_literal::hex;
+use prost_types::Timestamp;
+use substreams::scalar::BigInt;
+use substreams::store::StoreGet;
+use substreams::{log, Hex};
+use substreams_ethereum::pb as ethpb;
+use substreams_ethereum::Event;
+
+use crate::abi::cryptopunks::events::Assign as PunkAssignEvent;
+use crate::abi::cryptopunks::events::PunkBidEntered as PunkBidEnteredEvent;
+use crate::abi::cryptopunks::events::PunkBought as PunkBoughtEvent;
+use crate::abi::cryptopunks::events::PunkOffered as PunkOfferedEvent;
+use crate::abi::cryptopunks::events::PunkTransfer as PunkTransferEvent;
+use crate::abi::cryptopunks::events::Transfer as PunkBalanceTransferEvent;
+use crate::pb::transfers;
+use crate::pb::transfers::punk_order::Kind as PunkOrderKind;
+use crate::pb::transfers::sale_payout::Role;
+use crate::pb::transfers::transfer::Schema;
+use crate::{nft_key, punk_order_kind_to_string, role_to_string, schema_to_string};
+
+// The CryptoPunks market contract, punks predate ERC721 and are only ever moved through it.
+const CRYPTOPUNKS_ADDRESS: [u8; 20] = hex!("b47e3cd837ddf8e4c57f05d70ab865de6e193bbb");
+
+const ZERO_ADDRESS: [u8; 20] = [0u8; 20];
+
+// Returns the transfer of a punk if `log` is one of the events that moves a punk, `logs` are all
+// the logs of the receipt `log` belongs to.
+pub fn new_punk_transfer(
+    hash: &[u8],
+    log: &ethpb::eth::v2::Log,
+    logs: &[ethpb::eth::v2::Log],
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+) -> Option<transfers::Transfer> {
+    if log.address != CRYPTOPUNKS_ADDRESS {
+        return None;
+    }
+
+    if let Some(event) = PunkAssignEvent::match_and_decode(log) {
+        return Some(new_cryptopunks_transfer(hash, log, &ZERO_ADDRESS, &event.to, &event.punk_index, block_number, timestamp));
+    }
+
+    if let Some(event) = PunkTransferEvent::match_and_decode(log) {
+        return Some(new_cryptopunks_transfer(hash, log, &event.from, &event.to, &event.punk_index, block_number, timestamp));
+    }
+
+    PunkBoughtEvent::match_and_decode(log).map(|event| {
+        let buyer = punk_buyer(hash, log, logs, &event);
+        new_cryptopunks_transfer(hash, log, &event.from_address, &buyer, &event.punk_index, block_number, timestamp)
+    })
+}
+
+pub fn transform_block_to_sales(blk: &ethpb::eth::v2::Block, punk_bids: &impl StoreGet<String>) -> Vec<transfers::Sale> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts().flat_map(|receipt| {
+        let hash = &receipt.transaction.hash;
+        let logs = &receipt.receipt.logs;
+        let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+        logs.iter()
+            .filter(|log| log.address == CRYPTOPUNKS_ADDRESS)
+            .filter_map(move |log| {
+                PunkBoughtEvent::match_and_decode(log)
+                    .map(|event| new_punk_sale(hash, log, logs, punk_bids, blk.number, timestamp.clone(), event))
+            })
+    }).collect()
+}
+
+pub fn transform_block_to_bids(blk: &ethpb::eth::v2::Block) -> Vec<transfers::PunkOrder> {
+    transform_block_to_orders(blk)
+        .into_iter()
+        .filter(|order| order.kind == punk_order_kind_to_string(PunkOrderKind::BidEntered))
+        .collect()
+}
+
+pub fn transform_block_to_orders(blk: &ethpb::eth::v2::Block) -> Vec<transfers::PunkOrder> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts().flat_map(|receipt| {
+        let hash = &receipt.transaction.hash;
+        let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+        receipt.receipt.logs.iter()
+            .filter(|log| log.address == CRYPTOPUNKS_ADDRESS)
+            .filter_map(move |log| {
+                if let Some(event) = PunkOfferedEvent::match_and_decode(log) {
+                    return Some(new_punk_order(
+                        hash,
+                        log,
+                        PunkOrderKind::Offered,
+                        &event.punk_index,
+                        &event.min_value,
+                        Vec::new(),
+                        event.to_address,
+                        blk.number,
+                        timestamp.clone(),
+                    ));
+                }
+
+                PunkBidEnteredEvent::match_and_decode(log).map(|event| new_punk_order(
+                    hash,
+                    log,
+                    PunkOrderKind::BidEntered,
+                    &event.punk_index,
+                    &event.value,
+                    event.from_address,
+                    Vec::new(),
+                    blk.number,
+                    timestamp.clone(),
+                ))
+            })
+    }).collect()
+}
+
+fn new_cryptopunks_transfer(
+    hash: &[u8],
+    log: &ethpb::eth::v2::Log,
+    from: &[u8],
+    to: &[u8],
+    punk_index: &BigInt,
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+) -> transfers::Transfer {
+    transfers::Transfer {
+        schema: schema_to_string(Schema::Cryptopunks),
+        from_address: from.to_vec(),
+        to_address: to.to_vec(),
+        quantity: "1".to_string(),
+        tx_hash: hash.to_vec(),
+        ordinal: log.block_index as u64,
+        token_id: punk_index.to_bytes_be().1,
+        contract_address: log.address.to_vec(),
+        block_number: block_number,
+        timestamp: timestamp,
+
+        operator: Vec::new(),
+    }
+}
+
+// The market has no fees and punks have no royalties, the seller receives the full price.
+//
+// When a bid is accepted, the contract resets the bid before emitting `PunkBought` so the event
+// carries a zero `value` and a zero `toAddress`. The buyer is recovered from the `Transfer` emitted
+// just before it and the price from the `PunkBidEntered` of the bid, which can be in any earlier
+// block. Bids entered before the start block of the module are unknown, the price of their sale
+// is left empty and it has no payout.
+fn new_punk_sale(
+    hash: &[u8],
+    log: &ethpb::eth::v2::Log,
+    logs: &[ethpb::eth::v2::Log],
+    punk_bids: &impl StoreGet<String>,
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+    event: PunkBoughtEvent,
+) -> transfers::Sale {
+    let buyer = punk_buyer(hash, log, logs, &event);
+    let price = match is_accepted_bid(&event) {
+        true => punk_bids
+            .get_at(log.block_index as u64, nft_key(&log.address, &event.punk_index.to_bytes_be().1))
+            .unwrap_or_else(|| {
+                log::info!(
+                    "Unable to find the bid accepted for punk {} in transaction {} for log at block index {}, leaving the price empty",
+                    event.punk_index,
+                    Hex(&hash).to_string(),
+                    log.block_index,
+                );
+
+                String::new()
+            }),
+        false => event.value.to_string(),
+    };
+
+    let payouts = match price.is_empty() {
+        true => Vec::new(),
+        false => vec![transfers::SalePayout {
+            role: role_to_string(Role::Seller),
+            recipient: event.from_address.clone(),
+            payment_token: ZERO_ADDRESS.to_vec(),
+            amount: price.clone(),
+        }],
+    };
+
+    transfers::Sale {
+        marketplace: "cryptopunks".to_string(),
+        marketplace_address: log.address.to_vec(),
+        contract_address: log.address.to_vec(),
+        token_id: event.punk_index.to_bytes_be().1,
+        quantity: "1".to_string(),
+        item_count: 1,
+        seller: event.from_address,
+        buyer: buyer,
+        payment_token: ZERO_ADDRESS.to_vec(),
+        price: price,
+        payouts: payouts,
+        tx_hash: hash.to_vec(),
+        block_number: block_number,
+        ordinal: log.block_index as u64,
+        timestamp: timestamp,
+
+        order_hash: Vec::new(),
+    }
+}
+
+fn new_punk_order(
+    hash: &[u8],
+    log: &ethpb::eth::v2::Log,
+    kind: PunkOrderKind,
+    punk_index: &BigInt,
+    value: &BigInt,
+    from: Vec<u8>,
+    to: Vec<u8>,
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+) -> transfers::PunkOrder {
+    transfers::PunkOrder {
+        kind: punk_order_kind_to_string(kind),
+        contract_address: log.address.to_vec(),
+        token_id: punk_index.to_bytes_be().1,
+        value: value.to_string(),
+        from_address: from,
+        to_address: to,
+        tx_hash: hash.to_vec(),
+        block_number: block_number,
+        ordinal: log.block_index as u64,
+        timestamp: timestamp,
+    }
+}
+
+fn is_accepted_bid(event: &PunkBoughtEvent) -> bool {
+    event.to_address == ZERO_ADDRESS && event.value == BigInt::zero()
+}
+
+// Returns the `toAddress` of `event`, or for an accepted bid where it's the zero address, the
+// recipient of the `Transfer(seller, bidder, 1)` emitted by the market right before it.
+fn punk_buyer(
+    hash: &[u8],
+    log: &ethpb::eth::v2::Log,
+    logs: &[ethpb::eth::v2::Log],
+    event: &PunkBoughtEvent,
+) -> Vec<u8> {
+    if event.to_address != ZERO_ADDRESS {
+        return event.to_address.clone();
+    }
+
+    let transfer = logs
+        .iter()
+        .rev()
+        .filter(|previous| previous.address == log.address && previous.block_index < log.block_index)
+        .filter_map(PunkBalanceTransferEvent::match_and_decode)
+        .find(|transfer| transfer.from == event.from_address);
+
+    match transfer {
+        Some(transfer) => transfer.to,
+        None => {
+            log::info!(
+                "Unable to find the buyer of punk {} in transaction {} for log at block index {}, keeping the zero address",
+                event.punk_index,
+                Hex(&hash).to_string(),
+                log.block_index,
+            );
+
+            event.to_address.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::memory_store::MemoryStore;
+    use crate::testing::{erc20_transfer_log, punk_bid_entered_log, punk_bought_log, BlockBuilder, CallBuilder, TransactionBuilder};
+
+    const ALICE: [u8; 20] = [0xa1; 20];
+    const BOB: [u8; 20] = [0xb0; 20];
+
+    // `acceptBidForPunk` of `seller` on the punk, the balance `Transfer` comes before `PunkBought`.
+    fn accept_bid(seller: &[u8], bidder: &[u8], punk_index: u64) -> TransactionBuilder {
+        TransactionBuilder::new(0x02).from(seller).call(
+            CallBuilder::new(&CRYPTOPUNKS_ADDRESS)
+                .log(erc20_transfer_log(&CRYPTOPUNKS_ADDRESS, seller, bidder, 1))
+                .log(punk_bought_log(punk_index, 0, seller, &ZERO_ADDRESS)),
+        )
+    }
+
+    #[test]
+    fn it_transform_bought_offers_to_sales() {
+        let blk = BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&BOB).call(
+                CallBuilder::new(&CRYPTOPUNKS_ADDRESS).log(punk_bought_log(42, 1000, &ALICE, &BOB)),
+            ))
+            .build();
+
+        let sales = transform_block_to_sales(&blk, &MemoryStore::default());
+
+        assert_eq!(sales.len(), 1);
+        assert_eq!(sales[0].seller, ALICE.to_vec());
+        assert_eq!(sales[0].buyer, BOB.to_vec());
+        assert_eq!(sales[0].token_id, vec![42]);
+        assert_eq!(sales[0].price, "1000");
+        assert_eq!(sales[0].payouts.len(), 1);
+        assert_eq!(sales[0].payouts[0].recipient, ALICE.to_vec());
+        assert_eq!(sales[0].payouts[0].amount, "1000");
+    }
+
+    #[test]
+    fn it_takes_the_price_of_accepted_bids_from_their_bid() {
+        let blk = BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&BOB).call(
+                CallBuilder::new(&CRYPTOPUNKS_ADDRESS).log(punk_bid_entered_log(42, 700, &BOB)),
+            ))
+            .transaction(accept_bid(&ALICE, &BOB, 42))
+            .build();
+
+        let punk_bids = MemoryStore::default();
+        crate::stores::store_punk_bids(&blk, &punk_bids);
+
+        let sales = transform_block_to_sales(&blk, &punk_bids);
+
+        assert_eq!(sales.len(), 1);
+        assert_eq!(sales[0].seller, ALICE.to_vec());
+        assert_eq!(sales[0].buyer, BOB.to_vec());
+        assert_eq!(sales[0].price, "700");
+        assert_eq!(sales[0].payouts[0].role, "seller");
+        assert_eq!(sales[0].payouts[0].recipient, ALICE.to_vec());
+        assert_eq!(sales[0].payouts[0].amount, "700");
+    }
+
+    #[test]
+    fn it_leaves_the_price_of_unknown_accepted_bids_empty() {
+        let blk = BlockBuilder::new(12287507).transaction(accept_bid(&ALICE, &BOB, 42)).build();
+
+        let sales = transform_block_to_sales(&blk, &MemoryStore::default());
+
+        assert_eq!(sales.len(), 1);
+        assert_eq!(sales[0].buyer, BOB.to_vec());
+        assert_eq!(sales[0].price, "");
+        assert!(sales[0].payouts.is_empty());
+    }
+
+    #[test]
+    fn it_takes_the_buyer_of_accepted_bids_from_the_preceding_transfer() {
+        let carol = [0xc0; 20];
+        let blk = BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x02).from(&ALICE).call(
+                CallBuilder::new(&CRYPTOPUNKS_ADDRESS)
+                    .log(erc20_transfer_log(&CRYPTOPUNKS_ADDRESS, &carol, &ALICE, 1))
+                    .log(erc20_transfer_log(&CRYPTOPUNKS_ADDRESS, &ALICE, &BOB, 1))
+                    .log(punk_bought_log(42, 0, &ALICE, &ZERO_ADDRESS)),
+            ))
+            .build();
+
+        let (_timestamp, transfers) = crate::transform_block_to_transfers(&blk);
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].from_address, ALICE.to_vec());
+        assert_eq!(transfers[0].to_address, BOB.to_vec());
+        assert_eq!(transform_block_to_sales(&blk, &MemoryStore::default())[0].buyer, BOB.to_vec());
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::HashSet;
+
+use hex_literal::hex;
+use prost_types::Timestamp;
+use substreams_ethereum::pb as ethpb;
+use substreams_ethereum::Event;
+
+use crate::abi::erc1967::events::AdminChanged as ERC1967AdminChangedEvent;
+use crate::abi::erc1967::events::Upgraded as ERC1967UpgradedEvent;
+use crate::{block_log_ordinals, contract_upgrade_kind_to_string, log_block_index};
+use crate::pb::transfers;
+use crate::pb::transfers::contract_upgrade::Kind as ContractUpgradeKind;
+
+// bytes32(uint256(keccak256("eip1967.proxy.implementation")) - 1)
+const IMPLEMENTATION_SLOT: [u8; 32] = hex!("360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+
+pub fn transform_block_to_contract_upgrades(blk: &ethpb::eth::v2::Block) -> Vec<transfers::ContractUpgrade> {
+    let header = blk.header.as_ref().unwrap();
+    let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+    let log_ordinals = block_log_ordinals(blk);
+
+    blk.receipts().flat_map(|receipt| {
+        let hash = &receipt.transaction.hash;
+
+        let mut upgrades: Vec<transfers::ContractUpgrade> = receipt.receipt.logs.iter()
+            .filter_map(|log| {
+                if let Some(event) = ERC1967UpgradedEvent::match_and_decode(log) {
+                    return Some(new_contract_upgrade(
+                        hash,
+                        ContractUpgradeKind::Upgraded,
+                        "event",
+                        &log.address,
+                        event.implementation,
+                        Vec::new(),
+                        Vec::new(),
+                        blk.number,
+                        log.block_index as u64,
+                        timestamp.clone(),
+                    ));
+                }
+
+                ERC1967AdminChangedEvent::match_and_decode(log).map(|event| new_contract_upgrade(
+                    hash,
+                    ContractUpgradeKind::AdminChanged,
+                    "event",
+                    &log.address,
+                    Vec::new(),
+                    event.previous_admin,
+                    event.new_admin,
+                    blk.number,
+                    log.block_index as u64,
+                    timestamp.clone(),
+                ))
+            })
+            .collect();
+
+        // Proxies are supposed to emit `Upgraded` when they write the implementation slot, the
+        // write is only used for the ones that don't.
+        let upgraded: HashSet<Vec<u8>> = upgrades.iter()
+            .filter(|upgrade| upgrade.kind == contract_upgrade_kind_to_string(ContractUpgradeKind::Upgraded))
+            .map(|upgrade| upgrade.contract_address.clone())
+            .collect();
+
+        let storage_upgrades: Vec<transfers::ContractUpgrade> = receipt.transaction.calls.iter()
+            .filter(|call| !call.state_reverted)
+            .flat_map(|call| call.storage_changes.iter())
+            .filter(|storage_change| storage_change.key == IMPLEMENTATION_SLOT && !upgraded.contains(&storage_change.address))
+            .map(|storage_change| new_contract_upgrade(
+                hash,
+                ContractUpgradeKind::Upgraded,
+                "storage",
+                &storage_change.address,
+                slot_to_address(&storage_change.new_value),
+                Vec::new(),
+                Vec::new(),
+                blk.number,
+                log_block_index(&log_ordinals, storage_change.ordinal),
+                timestamp.clone(),
+            ))
+            .collect();
+
+        upgrades.extend(storage_upgrades);
+        upgrades
+    }).collect()
+}
+
+fn new_contract_upgrade(
+    hash: &[u8],
+    kind: ContractUpgradeKind,
+    source: &str,
+    contract_address: &[u8],
+    implementation: Vec<u8>,
+    previous_admin: Vec<u8>,
+    new_admin: Vec<u8>,
+    block_number: u64,
+    ordinal: u64,
+    timestamp: Option<Timestamp>,
+) -> transfers::ContractUpgrade {
+    transfers::ContractUpgrade {
+        kind: contract_upgrade_kind_to_string(kind),
+        source: source.to_string(),
+        contract_address: contract_address.to_vec(),
+        implementation: implementation,
+        previous_admin: previous_admin,
+        new_admin: new_admin,
+        tx_hash: hash.to_vec(),
+        block_number: block_number,
+        ordinal: ordinal,
+        timestamp: timestamp,
+    }
+}
+
+// Storage values are 32 bytes words, the address is right aligned in it.
+fn slot_to_address(value: &[u8]) -> Vec<u8> {
+    if value.len() < 20 {
+        return value.to_vec();
+    }
+
+    value[value.len() - 20..].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::{erc721_transfer_log, BlockBuilder, CallBuilder, TransactionBuilder};
+
+    #[test]
+    fn it_orders_implementation_slot_writes_by_the_block_index_of_logs() {
+        let proxy = [0x50; 20];
+        let contract = [0x72; 20];
+        let alice = [0xa1; 20];
+        let mut slot_value = [0u8; 32];
+        slot_value[12..].copy_from_slice(&[0x1a; 20]);
+
+        let blk = BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&alice).call(
+                CallBuilder::new(&contract).log(erc721_transfer_log(&contract, &alice, &alice, 1)),
+            ))
+            .transaction(TransactionBuilder::new(0x02).from(&alice)
+                .call(CallBuilder::new(&proxy).storage_change(&IMPLEMENTATION_SLOT, &slot_value))
+                .call(CallBuilder::new(&contract).log(erc721_transfer_log(&contract, &alice, &alice, 2))))
+            .build();
+
+        let upgrades = transform_block_to_contract_upgrades(&blk);
+
+        // After the log of the first transaction, before the one following the write.
+        assert_eq!(upgrades.len(), 1);
+        assert_eq!(upgrades[0].source, "storage");
+        assert_eq!(upgrades[0].implementation, vec![0x1a; 20]);
+        assert_eq!(upgrades[0].ordinal, 1);
+    }
+}
@@ -0,0 +1,58 @@
+use prost_types::Timestamp;
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb as ethpb;
+use substreams_ethereum::Event;
+
+use crate::abi::erc4906::events::BatchMetadataUpdate as ERC4906BatchMetadataUpdateEvent;
+use crate::abi::erc4906::events::MetadataUpdate as ERC4906MetadataUpdateEvent;
+use crate::pb::transfers;
+
+pub fn transform_block_to_metadata_refreshes(blk: &ethpb::eth::v2::Block) -> Vec<transfers::MetadataRefresh> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts().flat_map(|receipt| {
+        let hash = &receipt.transaction.hash;
+        let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+        receipt.receipt.logs.iter().filter_map(move |log| {
+            if let Some(event) = ERC4906MetadataUpdateEvent::match_and_decode(log) {
+                return Some(new_metadata_refresh(
+                    hash,
+                    log,
+                    &event.token_id,
+                    &event.token_id,
+                    blk.number,
+                    timestamp.clone(),
+                ));
+            }
+
+            ERC4906BatchMetadataUpdateEvent::match_and_decode(log).map(|event| new_metadata_refresh(
+                hash,
+                log,
+                &event.from_token_id,
+                &event.to_token_id,
+                blk.number,
+                timestamp.clone(),
+            ))
+        })
+    }).collect()
+}
+
+fn new_metadata_refresh(
+    hash: &[u8],
+    log: &ethpb::eth::v2::Log,
+    from_token_id: &BigInt,
+    to_token_id: &BigInt,
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+) -> transfers::MetadataRefresh {
+    transfers::MetadataRefresh {
+        contract_address: log.address.to_vec(),
+        from_token_id: from_token_id.to_bytes_be().1,
+        to_token_id: to_token_id.to_bytes_be().1,
+        tx_hash: hash.to_vec(),
+        block_number: block_number,
+        ordinal: log.block_index as u64,
+        timestamp: timestamp,
+    }
+}
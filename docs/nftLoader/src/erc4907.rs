@@ -0,0 +1,44 @@
+use prost_types::Timestamp;
+use substreams_ethereum::pb as ethpb;
+use substreams_ethereum::Event;
+
+use crate::abi::erc4907::events::UpdateUser as ERC4907UpdateUserEvent;
+use crate::pb::transfers;
+
+pub fn transform_block_to_token_users(blk: &ethpb::eth::v2::Block) -> Vec<transfers::TokenUser> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts().flat_map(|receipt| {
+        let hash = &receipt.transaction.hash;
+        let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+        receipt.receipt.logs.iter().filter_map(move |log| {
+            ERC4907UpdateUserEvent::match_and_decode(log).map(|event| new_token_user(
+                hash,
+                log,
+                blk.number,
+                timestamp.clone(),
+                event,
+            ))
+        })
+    }).collect()
+}
+
+fn new_token_user(
+    hash: &[u8],
+    log: &ethpb::eth::v2::Log,
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+    event: ERC4907UpdateUserEvent,
+) -> transfers::TokenUser {
+    transfers::TokenUser {
+        contract_address: log.address.to_vec(),
+        token_id: event.token_id.to_bytes_be().1,
+        user: event.user,
+        expires: event.expires.to_u64(),
+        tx_hash: hash.to_vec(),
+        block_number: block_number,
+        ordinal: log.block_index as u64,
+        timestamp: timestamp,
+    }
+}
@@ -0,0 +1,45 @@
+use prost_types::Timestamp;
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb as ethpb;
+use substreams_ethereum::Event;
+
+use crate::abi::erc5192::events::Locked as ERC5192LockedEvent;
+use crate::abi::erc5192::events::Unlocked as ERC5192UnlockedEvent;
+use crate::pb::transfers;
+
+pub fn transform_block_to_token_locks(blk: &ethpb::eth::v2::Block) -> Vec<transfers::TokenLock> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts().flat_map(|receipt| {
+        let hash = &receipt.transaction.hash;
+        let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+        receipt.receipt.logs.iter().filter_map(move |log| {
+            if let Some(event) = ERC5192LockedEvent::match_and_decode(log) {
+                return Some(new_token_lock(hash, log, &event.token_id, true, blk.number, timestamp.clone()));
+            }
+
+            ERC5192UnlockedEvent::match_and_decode(log)
+                .map(|event| new_token_lock(hash, log, &event.token_id, false, blk.number, timestamp.clone()))
+        })
+    }).collect()
+}
+
+fn new_token_lock(
+    hash: &[u8],
+    log: &ethpb::eth::v2::Log,
+    token_id: &BigInt,
+    locked: bool,
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+) -> transfers::TokenLock {
+    transfers::TokenLock {
+        contract_address: log.address.to_vec(),
+        token_id: token_id.to_bytes_be().1,
+        locked: locked,
+        tx_hash: hash.to_vec(),
+        block_number: block_number,
+        ordinal: log.block_index as u64,
+        timestamp: timestamp,
+    }
+}
@@ -0,0 +1,53 @@
+use hex_literal::hex;
+use prost_types::Timestamp;
+use substreams_ethereum::pb as ethpb;
+use substreams_ethereum::Event;
+
+use crate::abi::erc6551::events::Erc6551AccountCreated as ERC6551AccountCreatedEvent;
+use crate::pb::transfers;
+
+// The canonical ERC6551 registry, deployed at the same address on every chain. Accounts created
+// through any other registry are ignored.
+const ERC6551_REGISTRY_ADDRESS: [u8; 20] = hex!("000000006551c19487814612e58fe06813775758");
+
+pub fn transform_block_to_token_bound_accounts(blk: &ethpb::eth::v2::Block) -> Vec<transfers::TokenBoundAccount> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts().flat_map(|receipt| {
+        let hash = &receipt.transaction.hash;
+        let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+        receipt.receipt.logs.iter()
+            .filter(|log| log.address == ERC6551_REGISTRY_ADDRESS)
+            .filter_map(move |log| {
+                ERC6551AccountCreatedEvent::match_and_decode(log).map(|event| new_token_bound_account(
+                    hash,
+                    log,
+                    blk.number,
+                    timestamp.clone(),
+                    event,
+                ))
+            })
+    }).collect()
+}
+
+fn new_token_bound_account(
+    hash: &[u8],
+    log: &ethpb::eth::v2::Log,
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+    event: ERC6551AccountCreatedEvent,
+) -> transfers::TokenBoundAccount {
+    transfers::TokenBoundAccount {
+        account: event.account,
+        implementation: event.implementation,
+        salt: event.salt.to_vec(),
+        chain_id: event.chain_id.to_string(),
+        contract_address: event.token_contract,
+        token_id: event.token_id.to_bytes_be().1,
+        tx_hash: hash.to_vec(),
+        block_number: block_number,
+        ordinal: log.block_index as u64,
+        timestamp: timestamp,
+    }
+}
@@ -0,0 +1,188 @@
+use hex_literal::hex;
+use prost_types::Timestamp;
+use substreams::{log, Hex};
+use substreams_ethereum::pb as ethpb;
+use substreams_ethereum::Event;
+
+use crate::abi::erc721_legacy::events::Transfer as ERC721LegacyTransferEvent;
+use crate::pb::transfers;
+use crate::pb::transfers::transfer::Schema;
+use crate::{log_operator, schema_to_string};
+
+// NFT contracts deployed before ERC721 was final that emit `Transfer(address,address,uint256)`
+// with every parameter in the data section. The event is the one of early ERC20 tokens, the
+// contracts that are not listed here are recognized from the classification of their code, from
+// the NFT transfers they already emitted or from the function being executed.
+const LEGACY_NFT_ADDRESSES: [[u8; 20]; 1] = [
+    // CryptoKitties
+    hex!("06012c8cf97bead5deae237070f9587f8e7a266d"),
+];
+
+// Selectors of functions only an NFT contract implements, a legacy `Transfer` emitted while
+// executing one of them comes from an NFT contract even if it's not a known one.
+const ERC721_ONLY_SELECTORS: [[u8; 4]; 3] = [
+    // safeTransferFrom(address,address,uint256)
+    hex!("42842e0e"),
+    // safeTransferFrom(address,address,uint256,bytes)
+    hex!("b88d4fde"),
+    // takeOwnership(uint256), from the ERC721 draft
+    hex!("b2e6ceeb"),
+];
+
+pub fn new_erc721_legacy_transfer(
+    hash: &[u8],
+    log: &ethpb::eth::v2::Log,
+    transaction: &ethpb::eth::v2::TransactionTrace,
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+    is_legacy_nft_contract: &dyn Fn(&[u8]) -> bool,
+) -> Option<transfers::Transfer> {
+    let event = ERC721LegacyTransferEvent::match_and_decode(log)?;
+
+    if !is_legacy_nft_address(&log.address)
+        && !is_emitted_by_erc721_only_call(log, transaction)
+        && !is_legacy_nft_contract(&log.address)
+    {
+        log::debug!(
+            "Ignoring non-indexed Transfer from {} in transaction {} for log at block index {}, contract is not known to be an NFT",
+            Hex(&log.address).to_string(),
+            Hex(&hash).to_string(),
+            log.block_index,
+        );
+
+        return None;
+    }
+
+    Some(transfers::Transfer {
+        schema: schema_to_string(Schema::Erc721),
+        from_address: event.from,
+        to_address: event.to,
+        quantity: "1".to_string(),
+        tx_hash: hash.to_vec(),
+        ordinal: log.block_index as u64,
+        token_id: event.token_id.to_bytes_be().1,
+        operator: log_operator(transaction, log),
+        contract_address: log.address.to_vec(),
+        block_number: block_number,
+        timestamp: timestamp,
+    })
+}
+
+fn is_legacy_nft_address(address: &[u8]) -> bool {
+    LEGACY_NFT_ADDRESSES.iter().any(|legacy| legacy == address)
+}
+
+fn is_emitted_by_erc721_only_call(
+    log: &ethpb::eth::v2::Log,
+    transaction: &ethpb::eth::v2::TransactionTrace,
+) -> bool {
+    transaction
+        .calls
+        .iter()
+        .filter(|call| call.address == log.address && call.input.len() >= 4 && !call.state_reverted)
+        .filter(|call| call.logs.iter().any(|call_log| call_log.ordinal == log.ordinal))
+        .any(|call| ERC721_ONLY_SELECTORS.iter().any(|selector| selector == &call.input[0..4]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use substreams::store::StoreSet;
+
+    use crate::memory_store::MemoryStore;
+    use crate::params::Params;
+    use crate::testing::{erc721_legacy_transfer_log, BlockBuilder, CallBuilder, TransactionBuilder};
+    use crate::{transform_block_to_nft_transfers, transform_block_to_transfers};
+
+    const CRYPTOKITTIES: [u8; 20] = LEGACY_NFT_ADDRESSES[0];
+    const CONTRACT: [u8; 20] = [0x72; 20];
+    const ALICE: [u8; 20] = [0xa1; 20];
+    const BOB: [u8; 20] = [0xb0; 20];
+
+    fn block(call: CallBuilder) -> ethpb::eth::v2::Block {
+        BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE).call(call))
+            .build()
+    }
+
+    fn transfer_from(contract: &[u8]) -> CallBuilder {
+        // transfer(address,uint256)
+        CallBuilder::new(contract).input(&hex!("a9059cbb")).log(erc721_legacy_transfer_log(contract, &ALICE, &BOB, 7))
+    }
+
+    #[test]
+    fn it_transform_legacy_transfers_of_known_collections() {
+        let (_timestamp, transfers) = transform_block_to_transfers(&block(transfer_from(&CRYPTOKITTIES)));
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].schema, "erc721");
+        assert_eq!(transfers[0].contract_address, CRYPTOKITTIES.to_vec());
+        assert_eq!(transfers[0].from_address, ALICE.to_vec());
+        assert_eq!(transfers[0].to_address, BOB.to_vec());
+        assert_eq!(transfers[0].token_id, vec![7]);
+    }
+
+    #[test]
+    fn it_ignores_legacy_transfers_of_unknown_contracts() {
+        let (_timestamp, transfers) = transform_block_to_transfers(&block(transfer_from(&CONTRACT)));
+
+        assert!(transfers.is_empty());
+    }
+
+    #[test]
+    fn it_transform_legacy_transfers_emitted_by_erc721_only_functions() {
+        let call = CallBuilder::new(&CONTRACT)
+            .input(&hex!("42842e0e"))
+            .log(erc721_legacy_transfer_log(&CONTRACT, &ALICE, &BOB, 7));
+
+        let (_timestamp, transfers) = transform_block_to_transfers(&block(call));
+
+        assert_eq!(transfers.len(), 1);
+    }
+
+    #[test]
+    fn it_transform_legacy_transfers_of_contracts_classified_as_erc721() {
+        let contract_standards = MemoryStore::default();
+        contract_standards.set(0, Hex(CONTRACT).to_string(), &"erc721".to_string());
+
+        let (_timestamp, transfers) = transform_block_to_nft_transfers(
+            &block(transfer_from(&CONTRACT)),
+            &Params::default(),
+            &contract_standards,
+            &MemoryStore::default(),
+        );
+
+        assert_eq!(transfers.len(), 1);
+    }
+
+    #[test]
+    fn it_transform_legacy_transfers_of_contracts_that_emitted_nft_transfers() {
+        let nft_contracts = MemoryStore::default();
+        nft_contracts.set(0, Hex(CONTRACT).to_string(), &"erc721".to_string());
+
+        let (_timestamp, transfers) = transform_block_to_nft_transfers(
+            &block(transfer_from(&CONTRACT)),
+            &Params::default(),
+            &MemoryStore::default(),
+            &nft_contracts,
+        );
+
+        assert_eq!(transfers.len(), 1);
+    }
+
+    #[test]
+    fn it_ignores_legacy_transfers_of_contracts_classified_as_erc20() {
+        let contract_standards = MemoryStore::default();
+        contract_standards.set(0, Hex(CONTRACT).to_string(), &"erc20".to_string());
+
+        let (_timestamp, transfers) = transform_block_to_nft_transfers(
+            &block(transfer_from(&CONTRACT)),
+            &Params::default(),
+            &contract_standards,
+            &MemoryStore::default(),
+        );
+
+        assert!(transfers.is_empty());
+    }
+}
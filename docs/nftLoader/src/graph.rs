@@ -0,0 +1,176 @@
+use substreams::log;
+use substreams::pb::substreams::store_delta::Operation as StoreOperation;
+use substreams::scalar::BigInt;
+use substreams::store::{DeltaInt64, DeltaString};
+use substreams::Hex;
+use substreams_entity_change::pb::entity::{entity_change::Operation, EntityChanges};
+
+use crate::pb::transfers;
+use crate::{nft_key, transfer_key};
+
+// Entities are named and keyed like in `schema.graphql`. The transfers reference their token
+// and accounts, which are created from the deltas of `store_tokens` and `store_accounts` the
+// first time they're seen.
+pub fn transform_transfers_to_entity_changes(changes: &mut EntityChanges, transfers: &[transfers::Transfer]) {
+    for transfer in transfers {
+        let token_key = nft_key(&transfer.contract_address, &transfer.token_id);
+        push_create_transfer(changes, &token_key, transfer);
+    }
+}
+
+pub fn transform_account_delta_to_entity_changes(changes: &mut EntityChanges, delta: DeltaString) {
+    if delta.operation != StoreOperation::Create {
+        return;
+    }
+
+    changes.push_change("Account", &delta.key, delta.ordinal, Operation::Create);
+}
+
+// Keys are `<contract>-<token id>`, like the ones of `nft_key`.
+pub fn transform_token_delta_to_entity_changes(changes: &mut EntityChanges, delta: DeltaString) {
+    if delta.operation != StoreOperation::Create {
+        return;
+    }
+
+    let (collection, token_id) = match delta.key.split_once('-') {
+        Some(parts) => parts,
+        None => return,
+    };
+
+    changes
+        .push_change("Token", &delta.key, delta.ordinal, Operation::Create)
+        .change("collection", (None, collection.to_string()))
+        .change("tokenId", (None, token_id.to_string()));
+}
+
+fn push_create_transfer(changes: &mut EntityChanges, token_key: &str, transfer: &transfers::Transfer) {
+    let quantity = BigInt::try_from(&transfer.quantity).unwrap_or_else(|_| BigInt::zero());
+    let timestamp = transfer.timestamp.as_ref().map(|timestamp| timestamp.seconds).unwrap_or_default();
+
+    changes
+        .push_change("Transfer", &transfer_key(transfer), transfer.ordinal, Operation::Create)
+        .change("schema", (None, transfer.schema.clone()))
+        .change("collection", (None, Hex(&transfer.contract_address).to_string()))
+        .change("token", (None, token_key.to_string()))
+        .change("from", (None, Hex(&transfer.from_address).to_string()))
+        .change("to", (None, Hex(&transfer.to_address).to_string()))
+        .change("operator", (None, Hex(&transfer.operator).to_string()))
+        .change("quantity", (None, quantity))
+        .change("txHash", (None, Hex(&transfer.tx_hash).to_string()))
+        .change("blockNumber", (None, BigInt::from(transfer.block_number)))
+        .change("ordinal", (None, BigInt::from(transfer.ordinal)))
+        .change("timestamp", (None, BigInt::from(timestamp)));
+}
+
+pub fn transform_collection_delta_to_entity_changes(changes: &mut EntityChanges, delta: DeltaInt64) {
+    let operation = match store_operation_to_entity_operation(delta.operation) {
+        Some(operation) => operation,
+        None => return,
+    };
+
+    changes
+        .push_change("Collection", &delta.key, delta.ordinal, operation)
+        .change("transferCount", (None, BigInt::from(delta.new_value)));
+}
+
+// Tokens are created from the deltas of `store_tokens`, which come first, the owner is only set
+// on the ones that can have a single owner.
+pub fn transform_nft_owner_delta_to_entity_changes(changes: &mut EntityChanges, delta: DeltaString) {
+    if store_operation_to_entity_operation(delta.operation).is_none() {
+        return;
+    }
+
+    log::debug!("Setting the owner of token {} to {}", delta.key, delta.new_value);
+
+    changes
+        .push_change("Token", &delta.key, delta.ordinal, Operation::Update)
+        .change("owner", (None, delta.new_value));
+}
+
+fn store_operation_to_entity_operation(operation: StoreOperation) -> Option<Operation> {
+    match operation {
+        StoreOperation::Create => Some(Operation::Create),
+        StoreOperation::Update => Some(Operation::Update),
+        StoreOperation::Delete => Some(Operation::Delete),
+        StoreOperation::Unset => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use substreams_entity_change::pb::entity::EntityChange;
+    use substreams_ethereum::pb as ethpb;
+
+    use crate::pipeline::Pipeline;
+    use crate::testing::{erc1155_transfer_single_log, erc721_transfer_log, BlockBuilder, CallBuilder, TransactionBuilder};
+
+    const CONTRACT_721: [u8; 20] = [0x72; 20];
+    const CONTRACT_1155: [u8; 20] = [0x11; 20];
+    const ALICE: [u8; 20] = [0xa1; 20];
+    const BOB: [u8; 20] = [0xb0; 20];
+
+    fn block(number: u64, log: ethpb::eth::v2::Log) -> ethpb::eth::v2::Block {
+        BlockBuilder::new(number)
+            .transaction(TransactionBuilder::new(number as u8).from(&ALICE).call(CallBuilder::new(&log.address).log(log)))
+            .build()
+    }
+
+    fn changes_of<'a>(changes: &'a EntityChanges, entity: &str) -> Vec<&'a EntityChange> {
+        changes.entity_changes.iter().filter(|change| change.entity == entity).collect()
+    }
+
+    fn summary(changes: &EntityChanges, entity: &str) -> Vec<(String, Operation)> {
+        changes_of(changes, entity)
+            .into_iter()
+            .map(|change| (change.id.clone(), Operation::from_i32(change.operation).unwrap()))
+            .collect()
+    }
+
+    fn field_names(change: &EntityChange) -> Vec<&str> {
+        change.fields.iter().map(|field| field.name.as_str()).collect()
+    }
+
+    #[test]
+    fn it_creates_accounts_and_tokens_the_first_time_they_are_seen() {
+        let mut pipeline = Pipeline::new();
+        let token = format!("{}-01", Hex(CONTRACT_721));
+
+        let mint = pipeline.graph_out(&block(12287507, erc721_transfer_log(&CONTRACT_721, &[0; 20], &ALICE, 1)));
+
+        assert_eq!(summary(&mint, "Account"), vec![
+            (Hex([0u8; 20]).to_string(), Operation::Create),
+            (Hex(ALICE).to_string(), Operation::Create),
+        ]);
+        assert_eq!(summary(&mint, "Token"), vec![
+            (token.clone(), Operation::Create),
+            (token.clone(), Operation::Update),
+        ]);
+        assert_eq!(field_names(changes_of(&mint, "Token")[0]), vec!["collection", "tokenId"]);
+        assert_eq!(field_names(changes_of(&mint, "Token")[1]), vec!["owner"]);
+        assert_eq!(summary(&mint, "Transfer").len(), 1);
+        assert_eq!(summary(&mint, "Collection"), vec![(Hex(CONTRACT_721).to_string(), Operation::Create)]);
+
+        let transfer = pipeline.graph_out(&block(12287508, erc721_transfer_log(&CONTRACT_721, &ALICE, &BOB, 1)));
+
+        assert_eq!(summary(&transfer, "Account"), vec![(Hex(BOB).to_string(), Operation::Create)]);
+        assert_eq!(summary(&transfer, "Token"), vec![(token, Operation::Update)]);
+        assert_eq!(summary(&transfer, "Transfer").len(), 1);
+        assert_eq!(summary(&transfer, "Collection"), vec![(Hex(CONTRACT_721).to_string(), Operation::Update)]);
+    }
+
+    #[test]
+    fn it_creates_tokens_without_a_single_owner() {
+        let mut pipeline = Pipeline::new();
+        let log = erc1155_transfer_single_log(&CONTRACT_1155, &ALICE, &[0; 20], &ALICE, 7, 10);
+
+        let changes = pipeline.graph_out(&block(12287507, log));
+
+        let tokens = changes_of(&changes, "Token");
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].id, format!("{}-07", Hex(CONTRACT_1155)));
+        assert_eq!(Operation::from_i32(tokens[0].operation), Some(Operation::Create));
+        assert_eq!(field_names(tokens[0]), vec!["collection", "tokenId"]);
+    }
+}
@@ -0,0 +1,188 @@
+// The substreams modules, they only hand their inputs to the library so that its logic can be
+// tested natively.
+use substreams::store::{
+    DeltaBigInt, DeltaInt64, DeltaProto, DeltaString, Deltas, StoreAddBigInt, StoreAddInt64,
+    StoreGetProto, StoreGetString, StoreSetIfNotExistsString, StoreSetProto, StoreSetString,
+};
+use substreams_database_change::pb::database::DatabaseChanges;
+use substreams_entity_change::pb::entity::EntityChanges;
+use substreams_ethereum::pb as ethpb;
+use substreams_sink_kv::pb::sf::substreams::sink::kv::v1::KvOperations;
+
+use crate::params::Params;
+use crate::pb::files::Lines;
+use crate::pb::transfers;
+use crate::stores;
+
+substreams_ethereum::init!();
+
+// Stores can't return an error, an invalid param stops them.
+fn parse_params(params: &str) -> Params {
+    Params::parse(params).unwrap_or_else(|err| panic!("invalid params {:?}: {:#}", params, err))
+}
+
+#[substreams::handlers::store]
+fn store_token_users(blk: ethpb::eth::v2::Block, store: StoreSetProto<transfers::TokenUser>) {
+    stores::store_token_users(&blk, &store)
+}
+
+#[substreams::handlers::store]
+fn store_token_locks(blk: ethpb::eth::v2::Block, store: StoreSetProto<transfers::TokenLock>) {
+    stores::store_token_locks(&blk, &store)
+}
+
+#[substreams::handlers::store]
+fn store_contract_standards(blk: ethpb::eth::v2::Block, store: StoreSetString) {
+    stores::store_contract_standards(&blk, &store)
+}
+
+#[substreams::handlers::store]
+fn store_nft_contracts(
+    params: String,
+    blk: ethpb::eth::v2::Block,
+    contract_standards: StoreGetString,
+    store: StoreSetIfNotExistsString,
+) {
+    stores::store_nft_contracts(&blk, &parse_params(&params), &contract_standards, &store)
+}
+
+#[substreams::handlers::store]
+fn store_contract_owners(
+    blk: ethpb::eth::v2::Block,
+    contract_standards: StoreGetString,
+    nft_contracts: StoreGetString,
+    store: StoreSetProto<transfers::OwnershipTransfer>,
+) {
+    stores::store_contract_owners(&blk, &contract_standards, &nft_contracts, &store)
+}
+
+#[substreams::handlers::store]
+fn store_nft_owners(
+    params: String,
+    blk: ethpb::eth::v2::Block,
+    contract_standards: StoreGetString,
+    nft_contracts: StoreGetString,
+    store: StoreSetString,
+) {
+    stores::store_nft_owners(&blk, &parse_params(&params), &contract_standards, &nft_contracts, &store)
+}
+
+#[substreams::handlers::store]
+fn store_balances(
+    params: String,
+    blk: ethpb::eth::v2::Block,
+    contract_standards: StoreGetString,
+    nft_contracts: StoreGetString,
+    store: StoreAddBigInt,
+) {
+    stores::store_balances(&blk, &parse_params(&params), &contract_standards, &nft_contracts, &store)
+}
+
+#[substreams::handlers::store]
+fn store_collections(
+    params: String,
+    blk: ethpb::eth::v2::Block,
+    contract_standards: StoreGetString,
+    nft_contracts: StoreGetString,
+    store: StoreAddInt64,
+) {
+    stores::store_collections(&blk, &parse_params(&params), &contract_standards, &nft_contracts, &store)
+}
+
+#[substreams::handlers::store]
+fn store_tokens(
+    params: String,
+    blk: ethpb::eth::v2::Block,
+    contract_standards: StoreGetString,
+    nft_contracts: StoreGetString,
+    store: StoreSetIfNotExistsString,
+) {
+    stores::store_tokens(&blk, &parse_params(&params), &contract_standards, &nft_contracts, &store)
+}
+
+#[substreams::handlers::store]
+fn store_accounts(
+    params: String,
+    blk: ethpb::eth::v2::Block,
+    contract_standards: StoreGetString,
+    nft_contracts: StoreGetString,
+    store: StoreSetIfNotExistsString,
+) {
+    stores::store_accounts(&blk, &parse_params(&params), &contract_standards, &nft_contracts, &store)
+}
+
+#[substreams::handlers::store]
+fn store_punk_bids(blk: ethpb::eth::v2::Block, store: StoreSetString) {
+    stores::store_punk_bids(&blk, &store)
+}
+
+#[substreams::handlers::map]
+fn db_out(
+    params: String,
+    blk: ethpb::eth::v2::Block,
+    token_users_deltas: Deltas<DeltaProto<transfers::TokenUser>>,
+    token_locks: StoreGetProto<transfers::TokenLock>,
+    contract_standards: StoreGetString,
+    nft_contracts: StoreGetString,
+    contract_owners_deltas: Deltas<DeltaProto<transfers::OwnershipTransfer>>,
+    nft_owners_deltas: Deltas<DeltaString>,
+    balances_deltas: Deltas<DeltaBigInt>,
+    collections_deltas: Deltas<DeltaInt64>,
+    punk_bids: StoreGetString,
+) -> Result<DatabaseChanges, substreams::errors::Error> {
+    Ok(crate::transform_block_to_database_changes(
+        &blk,
+        &Params::parse(&params)?,
+        token_users_deltas,
+        &token_locks,
+        &contract_standards,
+        &nft_contracts,
+        contract_owners_deltas,
+        nft_owners_deltas,
+        balances_deltas,
+        collections_deltas,
+        &punk_bids,
+    ))
+}
+
+#[substreams::handlers::map]
+fn graph_out(
+    params: String,
+    blk: ethpb::eth::v2::Block,
+    contract_standards: StoreGetString,
+    nft_contracts: StoreGetString,
+    accounts_deltas: Deltas<DeltaString>,
+    tokens_deltas: Deltas<DeltaString>,
+    nft_owners_deltas: Deltas<DeltaString>,
+    collections_deltas: Deltas<DeltaInt64>,
+) -> Result<EntityChanges, substreams::errors::Error> {
+    Ok(crate::transform_block_to_entity_changes(
+        &blk,
+        &Params::parse(&params)?,
+        &contract_standards,
+        &nft_contracts,
+        accounts_deltas,
+        tokens_deltas,
+        nft_owners_deltas,
+        collections_deltas,
+    ))
+}
+
+#[substreams::handlers::map]
+fn kv_out(
+    nft_owners_deltas: Deltas<DeltaString>,
+    balances_deltas: Deltas<DeltaBigInt>,
+) -> Result<KvOperations, substreams::errors::Error> {
+    Ok(crate::transform_deltas_to_kv_operations(nft_owners_deltas, balances_deltas))
+}
+
+#[substreams::handlers::map]
+fn jsonl_out(
+    params: String,
+    blk: ethpb::eth::v2::Block,
+    contract_standards: StoreGetString,
+    nft_contracts: StoreGetString,
+    punk_bids: StoreGetString,
+) -> Result<Lines, substreams::errors::Error> {
+    Ok(crate::transform_block_to_lines(&blk, &Params::parse(&params)?, &contract_standards, &nft_contracts, &punk_bids))
+}
@@ -0,0 +1,117 @@
+use prost_types::Timestamp;
+use serde_json::{json, Value};
+use substreams::Hex;
+
+use crate::pb::transfers;
+
+// Every line is a JSON object with a `type` of `transfer`, `sale` or `approval`, the fields of
+// each type are documented in the README. Fields are never removed or renamed, new ones can be
+// added. Bytes are lowercase hex without `0x` like in the Postgres tables, amounts are decimal
+// strings since they don't fit in a JSON number.
+pub fn transfer_to_line(transfer: &transfers::Transfer) -> String {
+    json!({
+        "type": "transfer",
+        "schema": transfer.schema,
+        "contract_address": Hex(&transfer.contract_address).to_string(),
+        "token_id": Hex(&transfer.token_id).to_string(),
+        "from_address": Hex(&transfer.from_address).to_string(),
+        "to_address": Hex(&transfer.to_address).to_string(),
+        "operator": Hex(&transfer.operator).to_string(),
+        "quantity": transfer.quantity,
+        "tx_hash": Hex(&transfer.tx_hash).to_string(),
+        "block_number": transfer.block_number,
+        "ordinal": transfer.ordinal,
+        "timestamp": timestamp_seconds(&transfer.timestamp),
+    })
+    .to_string()
+}
+
+pub fn sale_to_line(sale: &transfers::Sale) -> String {
+    let payouts: Vec<Value> = sale.payouts.iter().map(|payout| json!({
+        "role": payout.role,
+        "recipient": Hex(&payout.recipient).to_string(),
+        "payment_token": Hex(&payout.payment_token).to_string(),
+        "amount": payout.amount,
+    })).collect();
+
+    json!({
+        "type": "sale",
+        "marketplace": sale.marketplace,
+        "marketplace_address": Hex(&sale.marketplace_address).to_string(),
+        "order_hash": Hex(&sale.order_hash).to_string(),
+        "contract_address": Hex(&sale.contract_address).to_string(),
+        "token_id": Hex(&sale.token_id).to_string(),
+        "quantity": sale.quantity,
+        "item_count": sale.item_count,
+        "seller": Hex(&sale.seller).to_string(),
+        "buyer": Hex(&sale.buyer).to_string(),
+        "payment_token": Hex(&sale.payment_token).to_string(),
+        "price": sale.price,
+        "payouts": payouts,
+        "tx_hash": Hex(&sale.tx_hash).to_string(),
+        "block_number": sale.block_number,
+        "ordinal": sale.ordinal,
+        "timestamp": timestamp_seconds(&sale.timestamp),
+    })
+    .to_string()
+}
+
+pub fn approval_to_line(approval: &transfers::Approval) -> String {
+    json!({
+        "type": "approval",
+        "kind": approval.kind,
+        "contract_address": Hex(&approval.contract_address).to_string(),
+        "owner": Hex(&approval.owner).to_string(),
+        "spender": Hex(&approval.spender).to_string(),
+        "token_id": Hex(&approval.token_id).to_string(),
+        "approved": approval.approved,
+        "tx_hash": Hex(&approval.tx_hash).to_string(),
+        "block_number": approval.block_number,
+        "ordinal": approval.ordinal,
+        "timestamp": timestamp_seconds(&approval.timestamp),
+    })
+    .to_string()
+}
+
+fn timestamp_seconds(timestamp: &Option<Timestamp>) -> i64 {
+    timestamp.as_ref().map(|timestamp| timestamp.seconds).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_transfer_to_line() {
+        let transfer = transfers::Transfer {
+            schema: "erc1155".to_string(),
+            block_number: 12287507,
+            from_address: vec![0x01; 20],
+            to_address: vec![0x02; 20],
+            operator: vec![0x03; 20],
+            quantity: "115792089237316195423570985008687907853269984665640564039457584007913129639935".to_string(),
+            token_id: vec![0x2a],
+            contract_address: vec![0x04; 20],
+            tx_hash: vec![0x05; 32],
+            ordinal: 7,
+            timestamp: Some(Timestamp { seconds: 1618481223, nanos: 0 }),
+        };
+
+        let line: Value = serde_json::from_str(&transfer_to_line(&transfer)).unwrap();
+
+        assert_eq!(line, json!({
+            "type": "transfer",
+            "schema": "erc1155",
+            "contract_address": "0404040404040404040404040404040404040404",
+            "token_id": "2a",
+            "from_address": "0101010101010101010101010101010101010101",
+            "to_address": "0202020202020202020202020202020202020202",
+            "operator": "0303030303030303030303030303030303030303",
+            "quantity": "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+            "tx_hash": "0505050505050505050505050505050505050505050505050505050505050505",
+            "block_number": 12287507,
+            "ordinal": 7,
+            "timestamp": 1618481223,
+        }));
+    }
+}
@@ -0,0 +1,124 @@
+use substreams::log;
+use substreams::pb::substreams::store_delta::Operation as StoreOperation;
+use substreams::store::{DeltaBigInt, DeltaString};
+use substreams::Hex;
+use substreams_sink_kv::pb::sf::substreams::sink::kv::v1::KvOperations;
+
+use crate::ZERO_ADDRESS;
+
+// `owner:<contract>:<token_id>` holds the hex address of the owner of the token, the key is
+// deleted when the token is burnt.
+pub fn transform_nft_owner_delta_to_kv_operations(operations: &mut KvOperations, delta: DeltaString) {
+    if delta.operation == StoreOperation::Unset {
+        return;
+    }
+
+    let key = format!("owner:{}", delta.key.replacen('-', ":", 1));
+
+    if delta.new_value == Hex(ZERO_ADDRESS).to_string() {
+        log::debug!("Deleting {}, the token was burnt", key);
+        operations.push_delete(key, delta.ordinal);
+        return;
+    }
+
+    operations.push_new(key, delta.new_value.into_bytes(), delta.ordinal);
+}
+
+// `balance:<contract>:<token_id>:<holder>` holds the quantity of the token held by the holder as
+// a decimal string, the key is deleted when it drops to zero.
+pub fn transform_balance_delta_to_kv_operations(operations: &mut KvOperations, delta: DeltaBigInt) {
+    if delta.operation == StoreOperation::Unset {
+        return;
+    }
+
+    let key = format!("balance:{}", delta.key.replacen('-', ":", 2));
+
+    if delta.new_value.is_zero() {
+        operations.push_delete(key, delta.ordinal);
+        return;
+    }
+
+    operations.push_new(key, delta.new_value.to_string().into_bytes(), delta.ordinal);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use substreams::scalar::BigInt;
+    use substreams_sink_kv::pb::sf::substreams::sink::kv::v1::kv_operation::Type;
+
+    use crate::memory_store::{new_delta_big_int, new_delta_string};
+
+    const OWNER_KEY: &str = "7272727272727272727272727272727272727272-01";
+    const BALANCE_KEY: &str = "7272727272727272727272727272727272727272-01-a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1";
+    const ALICE: &str = "a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1";
+    const BOB: &str = "b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0";
+
+    fn owner_operations(operation: StoreOperation, old_value: &str, new_value: &str) -> KvOperations {
+        let mut operations = KvOperations::default();
+        let delta = new_delta_string(operation, 3, OWNER_KEY.to_string(), old_value.to_string(), new_value.to_string());
+        transform_nft_owner_delta_to_kv_operations(&mut operations, delta);
+        operations
+    }
+
+    fn balance_operations(operation: StoreOperation, old_value: i64, new_value: i64) -> KvOperations {
+        let mut operations = KvOperations::default();
+        let delta = new_delta_big_int(operation, 3, BALANCE_KEY.to_string(), BigInt::from(old_value), BigInt::from(new_value));
+        transform_balance_delta_to_kv_operations(&mut operations, delta);
+        operations
+    }
+
+    #[test]
+    fn it_sets_the_owner_of_tokens() {
+        for (operation, old_value) in [(StoreOperation::Create, ""), (StoreOperation::Update, BOB)] {
+            let operations = owner_operations(operation, old_value, ALICE);
+
+            assert_eq!(operations.operations.len(), 1);
+            assert_eq!(operations.operations[0].key, "owner:7272727272727272727272727272727272727272:01");
+            assert_eq!(operations.operations[0].value, ALICE.as_bytes().to_vec());
+            assert_eq!(operations.operations[0].ordinal, 3);
+            assert_eq!(operations.operations[0].r#type, Type::Set as i32);
+        }
+    }
+
+    #[test]
+    fn it_deletes_the_owner_of_burnt_tokens() {
+        let operations = owner_operations(StoreOperation::Update, ALICE, &Hex(ZERO_ADDRESS).to_string());
+
+        assert_eq!(operations.operations.len(), 1);
+        assert_eq!(operations.operations[0].key, "owner:7272727272727272727272727272727272727272:01");
+        assert_eq!(operations.operations[0].r#type, Type::Delete as i32);
+    }
+
+    #[test]
+    fn it_sets_the_balance_of_holders() {
+        let operations = balance_operations(StoreOperation::Update, 1, 6);
+
+        assert_eq!(operations.operations.len(), 1);
+        assert_eq!(
+            operations.operations[0].key,
+            format!("balance:7272727272727272727272727272727272727272:01:{}", ALICE),
+        );
+        assert_eq!(operations.operations[0].value, b"6".to_vec());
+        assert_eq!(operations.operations[0].r#type, Type::Set as i32);
+    }
+
+    #[test]
+    fn it_deletes_the_balance_of_holders_dropping_to_zero() {
+        let operations = balance_operations(StoreOperation::Update, 1, 0);
+
+        assert_eq!(operations.operations.len(), 1);
+        assert_eq!(
+            operations.operations[0].key,
+            format!("balance:7272727272727272727272727272727272727272:01:{}", ALICE),
+        );
+        assert_eq!(operations.operations[0].r#type, Type::Delete as i32);
+    }
+
+    #[test]
+    fn it_ignores_unset_deltas() {
+        assert!(owner_operations(StoreOperation::Unset, "", ALICE).operations.is_empty());
+        assert!(balance_operations(StoreOperation::Unset, 0, 1).operations.is_empty());
+    }
+}
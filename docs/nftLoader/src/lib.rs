@@ -1,18 +1,61 @@
 // Import the required modules
 mod abi;
-mod pb;
-mod block_timestamp;
+pub mod pb;
+mod approvals;
+pub mod block_timestamp;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod block_json;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod blocks;
+mod classification;
+mod cryptopunks;
+mod erc1967;
+mod erc4906;
+mod erc4907;
+mod erc5192;
+mod erc6551;
+mod erc721_legacy;
+mod graph;
+mod jsonl;
+mod kv;
+pub mod memory_store;
+mod ownable;
+pub mod output;
+pub mod params;
+pub mod pipeline;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod rpc;
+mod seaport;
+pub mod stores;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+mod transactions;
+
+#[cfg(target_arch = "wasm32")]
+mod handlers;
 
 use std::collections::HashSet;
 use std::fmt::Write;
 use sha2::{Digest, Sha256};
 use pb::transfers;
 use pb::transfers::transfer::Schema;
+use pb::transfers::sale_payout::Role;
+use pb::transfers::order_cancellation::Kind;
+use pb::transfers::punk_order::Kind as PunkOrderKind;
+use pb::transfers::contract_classification::Standard;
+use pb::transfers::contract_upgrade::Kind as ContractUpgradeKind;
+use pb::transfers::approval::Kind as ApprovalKind;
+use pb::files::Lines;
 use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
+use substreams_entity_change::pb::entity::EntityChanges;
+use substreams_sink_kv::pb::sf::substreams::sink::kv::v1::KvOperations;
+use substreams::pb::substreams::store_delta::Operation as StoreOperation;
+use substreams::store::{DeltaBigInt, DeltaInt64, DeltaProto, DeltaString, Deltas, StoreGet};
 use substreams_ethereum::pb as ethpb;
 
 use prost_types::Timestamp;
 use self::block_timestamp::BlockTimestamp;
+use self::params::Params;
 use substreams::{log, Hex};
 
 use substreams::scalar::BigInt;
@@ -21,35 +64,275 @@ use substreams_ethereum::Event;
 use abi::erc1155::events::TransferBatch as ERC1155TransferBatchEvent;
 use abi::erc1155::events::TransferSingle as ERC1155TransferSingleEvent;
 use abi::erc20::events::Transfer as ERC20TransferEvent;
+use abi::erc2309::events::ConsecutiveTransfer as ERC2309ConsecutiveTransferEvent;
 use abi::erc721::events::Transfer as ERC721TransferEvent;
 
-substreams_ethereum::init!();
+// ERC4906 batches covering more tokens than this only get a `metadata_refresh` row, the `nfts`
+// rows of the collection are not flagged one by one. Batches are commonly emitted with a
+// `_toTokenId` of `type(uint256).max` to refresh a whole collection.
+const MAX_METADATA_REFRESH_RANGE: u64 = 10_000;
+
+// Mints come from it and burns go to it, it's not a holder.
+const ZERO_ADDRESS: [u8; 20] = [0u8; 20];
 
-#[substreams::handlers::map]
-fn db_out(
-    blk: ethpb::eth::v2::Block
-) -> Result<DatabaseChanges, substreams::errors::Error> {
-    let (_timestamp, transfers) = transform_block_to_transfers(blk);
+// The changes of `db_out`.
+pub fn transform_block_to_database_changes(
+    blk: &ethpb::eth::v2::Block,
+    params: &Params,
+    token_users_deltas: Deltas<DeltaProto<transfers::TokenUser>>,
+    token_locks: &impl StoreGet<transfers::TokenLock>,
+    contract_standards: &impl StoreGet<String>,
+    nft_contracts: &impl StoreGet<String>,
+    contract_owners_deltas: Deltas<DeltaProto<transfers::OwnershipTransfer>>,
+    nft_owners_deltas: Deltas<DeltaString>,
+    balances_deltas: Deltas<DeltaBigInt>,
+    collections_deltas: Deltas<DeltaInt64>,
+    punk_bids: &impl StoreGet<String>,
+) -> DatabaseChanges {
+    let sales: Vec<transfers::Sale> = seaport::transform_block_to_sales(blk)
+        .into_iter()
+        .chain(cryptopunks::transform_block_to_sales(blk, punk_bids))
+        .collect();
+    let order_cancellations = seaport::transform_block_to_order_cancellations(blk);
+    let punk_orders = cryptopunks::transform_block_to_orders(blk);
+    let transfer_ranges = transform_block_to_transfer_ranges(blk, params);
+    let metadata_refreshes = erc4906::transform_block_to_metadata_refreshes(blk);
+    let token_lock_events = erc5192::transform_block_to_token_locks(blk);
+    let token_bound_accounts = erc6551::transform_block_to_token_bound_accounts(blk);
+    let contract_classifications = classification::transform_block_to_contract_classifications(blk);
+    let contract_upgrades = erc1967::transform_block_to_contract_upgrades(blk);
+    let block_transactions = transactions::transform_block_to_transactions(blk);
+    let (_timestamp, transfers) = transform_block_to_nft_transfers(blk, params, contract_standards, nft_contracts);
 
     let mut database_changes: DatabaseChanges = Default::default();
+    let mut transaction_hashes: HashSet<Vec<u8>> = HashSet::new();
 
     // for loop over transfers
     for transfer in transfers {
-        transform_transfers_to_database_changes(&mut database_changes, transfer);
+        if !is_transfer_from_expected_standard(contract_standards, &transfer) {
+            continue;
+        }
+
+        transaction_hashes.insert(transfer.tx_hash.clone());
+        let anomaly = transfer_anomaly(token_locks, &transfer);
+        transform_transfers_to_database_changes(&mut database_changes, transfer, anomaly);
+    }
+
+    // Must come before the metadata refreshes and the token locks, they update the `nfts` rows
+    // created here.
+    for delta in nft_owners_deltas.deltas {
+        transform_nft_owner_delta_to_database_changes(&mut database_changes, delta);
+    }
+
+    for delta in balances_deltas.deltas {
+        transform_balance_delta_to_database_changes(&mut database_changes, delta);
+    }
+
+    for delta in collections_deltas.deltas {
+        transform_collection_delta_to_database_changes(&mut database_changes, delta);
+    }
+
+    for sale in sales {
+        transaction_hashes.insert(sale.tx_hash.clone());
+        transform_sales_to_database_changes(&mut database_changes, sale);
+    }
+
+    // Only the transactions that moved or sold a token are kept, the table would otherwise hold
+    // every transaction of the chain.
+    for transaction in block_transactions {
+        if transaction_hashes.contains(&transaction.hash) {
+            transform_transactions_to_database_changes(&mut database_changes, transaction);
+        }
+    }
+
+    for order_cancellation in order_cancellations {
+        transform_order_cancellations_to_database_changes(&mut database_changes, order_cancellation);
+    }
+
+    for punk_order in punk_orders {
+        transform_punk_orders_to_database_changes(&mut database_changes, punk_order);
+    }
+
+    for transfer_range in transfer_ranges {
+        transform_transfer_ranges_to_database_changes(&mut database_changes, transfer_range);
+    }
+
+    for metadata_refresh in metadata_refreshes {
+        transform_metadata_refreshes_to_database_changes(&mut database_changes, metadata_refresh);
+    }
+
+    for token_lock in token_lock_events {
+        push_update_nft_locked(&mut database_changes, &token_lock);
+    }
+
+    for token_bound_account in token_bound_accounts {
+        transform_token_bound_accounts_to_database_changes(&mut database_changes, token_bound_account);
+    }
+
+    for contract_classification in contract_classifications {
+        transform_contract_classifications_to_database_changes(&mut database_changes, contract_standards, contract_classification);
+    }
+
+    // Must come after the classifications, the `contracts` row of a proxy deployed and upgraded in
+    // the same block is created by its classification. Every proxy of the chain emits upgrades, only
+    // the ones of NFT contracts are kept.
+    for contract_upgrade in contract_upgrades {
+        let key = Hex(&contract_upgrade.contract_address).to_string();
+        if !is_nft_contract(contract_standards, nft_contracts, &key) {
+            continue;
+        }
+
+        transform_contract_upgrades_to_database_changes(&mut database_changes, contract_standards, contract_upgrade);
+    }
+
+    // Same as the upgrades, must come after the classifications.
+    for delta in contract_owners_deltas.deltas {
+        transform_contract_owner_delta_to_database_changes(&mut database_changes, contract_standards, delta);
+    }
+
+    for delta in token_users_deltas.deltas {
+        transform_token_user_delta_to_database_changes(&mut database_changes, delta);
+    }
+
+    database_changes
+}
+
+// The changes of `graph_out`, same entities as the `transfers`, `nfts` and `collections` tables
+// for graph-node, from the same transfers and stores as `db_out`.
+pub fn transform_block_to_entity_changes(
+    blk: &ethpb::eth::v2::Block,
+    params: &Params,
+    contract_standards: &impl StoreGet<String>,
+    nft_contracts: &impl StoreGet<String>,
+    accounts_deltas: Deltas<DeltaString>,
+    tokens_deltas: Deltas<DeltaString>,
+    nft_owners_deltas: Deltas<DeltaString>,
+    collections_deltas: Deltas<DeltaInt64>,
+) -> EntityChanges {
+    let (_timestamp, transfers) = transform_block_to_nft_transfers(blk, params, contract_standards, nft_contracts);
+
+    let mut entity_changes: EntityChanges = Default::default();
+
+    for delta in accounts_deltas.deltas {
+        graph::transform_account_delta_to_entity_changes(&mut entity_changes, delta);
+    }
+
+    // Must come before the owners, they update the tokens created here.
+    for delta in tokens_deltas.deltas {
+        graph::transform_token_delta_to_entity_changes(&mut entity_changes, delta);
+    }
+
+    for delta in nft_owners_deltas.deltas {
+        graph::transform_nft_owner_delta_to_entity_changes(&mut entity_changes, delta);
+    }
+
+    let transfers: Vec<transfers::Transfer> = transfers
+        .into_iter()
+        .filter(|transfer| is_transfer_from_expected_standard(contract_standards, transfer) && is_nft_schema(&transfer.schema))
+        .collect();
+    graph::transform_transfers_to_entity_changes(&mut entity_changes, &transfers);
+
+    for delta in collections_deltas.deltas {
+        graph::transform_collection_delta_to_entity_changes(&mut entity_changes, delta);
+    }
+
+    entity_changes
+}
+
+// The operations of `kv_out`, owners and balances for key-value lookups, from the same stores as
+// the `nfts` and `balances` tables.
+pub fn transform_deltas_to_kv_operations(
+    nft_owners_deltas: Deltas<DeltaString>,
+    balances_deltas: Deltas<DeltaBigInt>,
+) -> KvOperations {
+    let mut kv_operations: KvOperations = Default::default();
+
+    for delta in nft_owners_deltas.deltas {
+        kv::transform_nft_owner_delta_to_kv_operations(&mut kv_operations, delta);
+    }
+
+    for delta in balances_deltas.deltas {
+        kv::transform_balance_delta_to_kv_operations(&mut kv_operations, delta);
+    }
+
+    kv_operations
+}
+
+// The lines of `jsonl_out`, one JSON object per line for file sinks, see `jsonl.rs` for the
+// format.
+pub fn transform_block_to_lines(
+    blk: &ethpb::eth::v2::Block,
+    params: &Params,
+    contract_standards: &impl StoreGet<String>,
+    nft_contracts: &impl StoreGet<String>,
+    punk_bids: &impl StoreGet<String>,
+) -> Lines {
+    let sales: Vec<transfers::Sale> = seaport::transform_block_to_sales(blk)
+        .into_iter()
+        .chain(cryptopunks::transform_block_to_sales(blk, punk_bids))
+        .collect();
+    let approvals = approvals::transform_block_to_approvals(blk);
+    let (_timestamp, transfers) = transform_block_to_nft_transfers(blk, params, contract_standards, nft_contracts);
+
+    let mut lines: Lines = Default::default();
+
+    for transfer in transfers {
+        if !is_transfer_from_expected_standard(contract_standards, &transfer) {
+            continue;
+        }
+
+        lines.lines.push(jsonl::transfer_to_line(&transfer));
+    }
+
+    for sale in sales {
+        lines.lines.push(jsonl::sale_to_line(&sale));
     }
 
-    Ok(database_changes)
+    for approval in approvals {
+        lines.lines.push(jsonl::approval_to_line(&approval));
+    }
+
+    lines
+}
+
+// The transfers of `blk`. Legacy ERC721 transfers, which share their event with ERC20 ones, are
+// only returned for known collections and when emitted while executing an NFT only function, see
+// `transform_block_to_nft_transfers` to also take the stores into account. ERC2309 ranges are
+// expanded up to the default `max_consecutive_transfer_range`.
+pub fn transform_block_to_transfers(blk: &ethpb::eth::v2::Block) -> (BlockTimestamp, Vec<transfers::Transfer>) {
+    transform_block_to_transfers_with_legacy_contracts(blk, &Params::default(), &|_| false)
 }
 
-fn transform_block_to_transfers(blk: ethpb::eth::v2::Block) -> (BlockTimestamp, Vec<transfers::Transfer>) {
+// Same as `transform_block_to_transfers`, legacy ERC721 transfers are also returned for contracts
+// classified as ERC721 when deployed and for contracts that already emitted NFT transfers.
+pub fn transform_block_to_nft_transfers(
+    blk: &ethpb::eth::v2::Block,
+    params: &Params,
+    contract_standards: &impl StoreGet<String>,
+    nft_contracts: &impl StoreGet<String>,
+) -> (BlockTimestamp, Vec<transfers::Transfer>) {
+    transform_block_to_transfers_with_legacy_contracts(blk, params, &|address| {
+        is_nft_contract(contract_standards, nft_contracts, &Hex(address).to_string())
+    })
+}
+
+// `is_legacy_nft_contract` tells whether the legacy ERC721 transfers of a contract that is not
+// otherwise recognized as an NFT contract are returned.
+pub fn transform_block_to_transfers_with_legacy_contracts(
+    blk: &ethpb::eth::v2::Block,
+    params: &Params,
+    is_legacy_nft_contract: &dyn Fn(&[u8]) -> bool,
+) -> (BlockTimestamp, Vec<transfers::Transfer>) {
     let header = blk.header.as_ref().unwrap();
-    let timestamp = BlockTimestamp::from_block(&blk);
+    let timestamp = BlockTimestamp::from_block(blk);
 
     let transfers: Vec<transfers::Transfer> = blk.receipts().flat_map(|receipt| {
-        let hash = &receipt.transaction.hash;
+        let transaction = receipt.transaction;
+        let hash = &transaction.hash;
+        let logs = &receipt.receipt.logs;
         let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
 
-        receipt.receipt.logs.iter().flat_map(move |log| {
+        logs.iter().flat_map(move |log| {
             let erc20_transfers = Vec::new();
 
             // TODO: commented out as we don't want to get all erc20s right now
@@ -66,11 +349,21 @@ fn transform_block_to_transfers(blk: ethpb::eth::v2::Block) -> (BlockTimestamp,
                 hash,
                 log.block_index,
                 log.address.to_vec(),
+                log_operator(transaction, log),
                 blk.number,
                 timestamp.clone(),
                 event
             ));
 
+            let erc721_legacy_transfers = erc721_legacy::new_erc721_legacy_transfer(
+                hash,
+                log,
+                transaction,
+                blk.number,
+                timestamp.clone(),
+                is_legacy_nft_contract,
+            );
+
             let erc1155_single_transfers = ERC1155TransferSingleEvent::match_and_decode(log).map(|event| new_erc1155_single_transfer(
                 hash,
                 log.block_index,
@@ -89,11 +382,33 @@ fn transform_block_to_transfers(blk: ethpb::eth::v2::Block) -> (BlockTimestamp,
                 event
             )).into_iter().flatten();
 
+            let erc2309_transfers = ERC2309ConsecutiveTransferEvent::match_and_decode(log).map(|event| new_erc2309_transfers(
+                hash,
+                log.block_index,
+                log.address.to_vec(),
+                log_operator(transaction, log),
+                blk.number,
+                timestamp.clone(),
+                params.max_consecutive_transfer_range,
+                event
+            )).into_iter().flatten();
+
+            let cryptopunks_transfers = cryptopunks::new_punk_transfer(
+                hash,
+                log,
+                logs,
+                blk.number,
+                timestamp.clone(),
+            );
+
             erc20_transfers
                 .into_iter()
                 .chain(erc721_transfers.into_iter())
+                .chain(erc721_legacy_transfers.into_iter())
                 .chain(erc1155_single_transfers.into_iter())
                 .chain(erc1155_batch_transfers)
+                .chain(erc2309_transfers)
+                .chain(cryptopunks_transfers.into_iter())
         })
     }).collect();
 
@@ -129,6 +444,7 @@ fn new_erc721_transfer(
     hash: &[u8],
     ordinal: u32,
     contract_address: Vec<u8>,
+    operator: Vec<u8>,
     block_number: u64,
     timestamp: Option<Timestamp>,
     event: ERC721TransferEvent
@@ -141,12 +457,50 @@ fn new_erc721_transfer(
         tx_hash: hash.to_vec(),
         ordinal: ordinal as u64,
         token_id: event.token_id.to_bytes_be().1,
+        operator: operator,
         contract_address: contract_address,
         block_number: block_number,
         timestamp: timestamp,
+    }
+}
 
-        operator: Vec::new(),
+// Returns the `msg.sender` of the call that emitted `log`. A delegate call runs in the context of
+// its parent and keeps its `msg.sender`, so the caller is taken from the first non-delegate call
+// up the tree. Logs are matched on their ordinal, the block index of the logs of reverted calls is
+// not set and can be the one of a receipt log.
+fn log_operator(transaction: &ethpb::eth::v2::TransactionTrace, log: &ethpb::eth::v2::Log) -> Vec<u8> {
+    let mut call = match transaction
+        .calls
+        .iter()
+        .filter(|call| !call.state_reverted)
+        .find(|call| call.logs.iter().any(|call_log| call_log.ordinal == log.ordinal))
+    {
+        Some(call) => call,
+        None => return Vec::new(),
+    };
+
+    while call.call_type == ethpb::eth::v2::CallType::Delegate as i32 {
+        call = match transaction.calls.iter().find(|parent| parent.index == call.parent_index) {
+            Some(parent) if parent.index != call.index => parent,
+            _ => break,
+        };
     }
+
+    call.caller.to_vec()
+}
+
+// The ordinals of the logs of the receipts of `blk`, in order, for `log_block_index`.
+fn block_log_ordinals(blk: &ethpb::eth::v2::Block) -> Vec<u64> {
+    blk.receipts()
+        .flat_map(|receipt| receipt.receipt.logs.iter().map(|log| log.ordinal))
+        .collect()
+}
+
+// Code and storage changes have an ordinal over the whole block while the rest of the crate orders
+// by the block index of the logs. A change is placed at the block index of the first log emitted
+// after it, `log_ordinals` are the ordinals of the logs of the receipts of the block, in order.
+fn log_block_index(log_ordinals: &[u64], ordinal: u64) -> u64 {
+    log_ordinals.partition_point(|log_ordinal| *log_ordinal < ordinal) as u64
 }
 
 fn new_erc1155_single_transfer(
@@ -229,6 +583,95 @@ fn new_erc1155_batch_transfer(
         .collect()
 }
 
+fn new_erc2309_transfers(
+    hash: &[u8],
+    ordinal: u32,
+    contract_address: Vec<u8>,
+    operator: Vec<u8>,
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+    max_range: u64,
+    event: ERC2309ConsecutiveTransferEvent,
+) -> Vec<transfers::Transfer> {
+    let size = match token_range_size(&event.from_token_id, &event.to_token_id) {
+        Some(size) if size <= max_range => size,
+        _ => return vec![],
+    };
+
+    (0..size)
+        .map(|i| transfers::Transfer {
+            schema: schema_to_string(Schema::Erc721),
+            from_address: event.from_address.clone(),
+            to_address: event.to_address.clone(),
+            quantity: "1".to_string(),
+            tx_hash: hash.to_vec(),
+            ordinal: ordinal as u64,
+            token_id: (event.from_token_id.clone() + BigInt::from(i)).to_bytes_be().1,
+            contract_address: contract_address.clone(),
+            block_number: block_number,
+            timestamp: timestamp.clone(),
+            operator: operator.clone(),
+        })
+        .collect()
+}
+
+// ERC2309 ranges covering more than `max_consecutive_transfer_range` tokens, they are written as a
+// single row instead of being expanded into one transfer per token.
+fn transform_block_to_transfer_ranges(blk: &ethpb::eth::v2::Block, params: &Params) -> Vec<transfers::TransferRange> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts().flat_map(|receipt| {
+        let hash = &receipt.transaction.hash;
+        let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+        receipt.receipt.logs.iter().filter_map(move |log| {
+            let event = ERC2309ConsecutiveTransferEvent::match_and_decode(log)?;
+
+            match token_range_size(&event.from_token_id, &event.to_token_id) {
+                Some(size) if size > params.max_consecutive_transfer_range => {
+                    log::info!(
+                        "Range of {} tokens in transaction {} for log at block index {} is over the limit of {}, writing it as a range",
+                        size,
+                        Hex(&hash).to_string(),
+                        log.block_index,
+                        params.max_consecutive_transfer_range,
+                    );
+
+                    Some(transfers::TransferRange {
+                        contract_address: log.address.to_vec(),
+                        from_token_id: event.from_token_id.to_bytes_be().1,
+                        to_token_id: event.to_token_id.to_bytes_be().1,
+                        from_address: event.from_address,
+                        to_address: event.to_address,
+                        tx_hash: hash.to_vec(),
+                        block_number: blk.number,
+                        ordinal: log.block_index as u64,
+                        timestamp: timestamp.clone(),
+                    })
+                }
+                _ => None,
+            }
+        })
+    }).collect()
+}
+
+// Returns the number of tokens from `from` to `to` inclusive, `None` when the range is reversed,
+// ranges that don't fit in a `u64` are capped to `u64::MAX`.
+fn token_range_size(from: &BigInt, to: &BigInt) -> Option<u64> {
+    if to < from {
+        log::info!("Ignoring reversed token range {} to {}", from, to);
+
+        return None;
+    }
+
+    let size = to.clone() - from.clone() + BigInt::one();
+    if size > BigInt::from(u64::MAX) {
+        return Some(u64::MAX);
+    }
+
+    Some(size.to_u64())
+}
+
 fn new_erc1155_transfer(
     hash: &[u8],
     ordinal: u32,
@@ -261,44 +704,188 @@ fn schema_to_string(schema: Schema) -> String {
         Schema::Erc20 => "erc20",
         Schema::Erc721 => "erc721",
         Schema::Erc1155 => "erc1155",
+        Schema::Cryptopunks => "cryptopunks",
+    }
+    .to_string()
+}
+
+fn role_to_string(role: Role) -> String {
+    match role {
+        Role::Seller => "seller",
+        Role::MarketplaceFee => "marketplace_fee",
+        Role::Royalty => "royalty",
+        Role::Other => "other",
+    }
+    .to_string()
+}
+
+fn standard_to_string(standard: Standard) -> String {
+    match standard {
+        Standard::Unknown => "unknown",
+        Standard::Erc20 => "erc20",
+        Standard::Erc721 => "erc721",
+        Standard::Erc1155 => "erc1155",
+        Standard::Proxy => "proxy",
+    }
+    .to_string()
+}
+
+fn contract_upgrade_kind_to_string(kind: ContractUpgradeKind) -> String {
+    match kind {
+        ContractUpgradeKind::Upgraded => "upgraded",
+        ContractUpgradeKind::AdminChanged => "admin_changed",
+    }
+    .to_string()
+}
+
+fn kind_to_string(kind: Kind) -> String {
+    match kind {
+        Kind::OrderCancelled => "order_cancelled",
+        Kind::CounterIncremented => "counter_incremented",
+    }
+    .to_string()
+}
+
+fn approval_kind_to_string(kind: ApprovalKind) -> String {
+    match kind {
+        ApprovalKind::Approval => "approval",
+        ApprovalKind::ApprovalForAll => "approval_for_all",
+    }
+    .to_string()
+}
+
+fn punk_order_kind_to_string(kind: PunkOrderKind) -> String {
+    match kind {
+        PunkOrderKind::Offered => "offered",
+        PunkOrderKind::BidEntered => "bid_entered",
     }
     .to_string()
 }
 
+// Contracts are classified from their code when they are deployed, a transfer is dropped when it
+// comes from a contract that doesn't implement the standard of the event, like an ERC20 or a
+// spam contract emitting look-alike events. Contracts deployed before the start block of the
+// module have no classification and are trusted, so are proxies since their logic lives in
+// another contract. A contract deployed in range whose code matched no NFT standard only emits
+// look-alikes.
+fn is_transfer_from_expected_standard(contract_standards: &impl StoreGet<String>, transfer: &transfers::Transfer) -> bool {
+    let expected = match transfer.schema.as_str() {
+        "erc721" => schema_to_string(Schema::Erc721),
+        "erc1155" => schema_to_string(Schema::Erc1155),
+        _ => return true,
+    };
+
+    let standard = match contract_standards.get_last(Hex(&transfer.contract_address).to_string()) {
+        Some(standard) => standard,
+        None => return true,
+    };
+
+    if standard == expected || is_proxy_standard(&standard) {
+        return true;
+    }
+
+    log::info!(
+        "Dropping {} transfer from contract {} classified as {} in transaction {}",
+        transfer.schema,
+        Hex(&transfer.contract_address).to_string(),
+        standard,
+        Hex(&transfer.tx_hash).to_string(),
+    );
+
+    false
+}
+
+// A contract is an NFT contract when its code was classified as one when it was deployed, or
+// when it has no classification (deployed before the start block) or is a proxy and it emitted
+// NFT transfers.
+fn is_nft_contract(contract_standards: &impl StoreGet<String>, nft_contracts: &impl StoreGet<String>, key: &str) -> bool {
+    match contract_standards.get_last(key) {
+        Some(standard) if standard == standard_to_string(Standard::Erc721) => true,
+        Some(standard) if standard == standard_to_string(Standard::Erc1155) => true,
+        Some(standard) if !is_proxy_standard(&standard) => false,
+        _ => nft_contracts.get_last(key).is_some(),
+    }
+}
+
+// The classification of a proxy doesn't tell which events it emits, its logic is in another
+// contract.
+fn is_proxy_standard(standard: &str) -> bool {
+    standard == standard_to_string(Standard::Proxy)
+}
+
+fn is_nft_schema(schema: &str) -> bool {
+    schema != schema_to_string(Schema::Erc20)
+}
+
+fn is_single_owner_schema(schema: &str) -> bool {
+    schema == schema_to_string(Schema::Erc721) || schema == schema_to_string(Schema::Cryptopunks)
+}
+
+// Returns why `transfer` is suspicious, empty when it's not. A token locked through ERC5192 is
+// not transferable, the lock is looked up at the ordinal of the transfer so a token locked right
+// after being minted in the same transaction doesn't flag its mint.
+fn transfer_anomaly(token_locks: &impl StoreGet<transfers::TokenLock>, transfer: &transfers::Transfer) -> String {
+    let key = nft_key(&transfer.contract_address, &transfer.token_id);
+
+    match token_locks.get_at(transfer.ordinal, &key) {
+        Some(token_lock) if token_lock.locked => {
+            log::info!(
+                "Token {} is locked but was transferred in transaction {}",
+                key,
+                Hex(&transfer.tx_hash).to_string(),
+            );
+
+            "locked_token".to_string()
+        }
+        _ => String::new(),
+    }
+}
+
 fn transform_transfers_to_database_changes(
     changes: &mut DatabaseChanges,
     transfer: transfers::Transfer,
+    anomaly: String,
 ) {
-    let unique_key = |transfer: &transfers::Transfer| {
-        format!(
-            "{}-{}-{}-{}-{}-{}-{}-{}-{}",
-            transfer.schema,
-            Hex(&transfer.contract_address),
-            Hex(&transfer.token_id),
-            Hex(&transfer.from_address),
-            Hex(&transfer.to_address),
-            Hex(&transfer.operator),
-            Hex(&transfer.quantity),
-            Hex(&transfer.tx_hash),
-            &transfer.ordinal
-        )
-    };
-
     let mut hasher = Sha256::new();
-    hasher.update(unique_key(&transfer).as_bytes());
+    hasher.update(transfer_key(&transfer).as_bytes());
     let hash_bytes = hasher.finalize();
     let mut hash_string = String::new();
     for byte in hash_bytes {
         write!(&mut hash_string, "{:02x}", byte).expect("Error writing hash string");
     }
 
-    log::info!("Found a transfer {}", unique_key(&transfer));
+    log::info!("Found a transfer {}", transfer_key(&transfer));
 
     push_create(
         changes,
-        &unique_key(&transfer),
+        &transfer_key(&transfer),
         transfer.ordinal,
         transfer,
+        anomaly,
+    )
+}
+
+// A log can hold several transfers, like ERC1155 batches, so the key is made of every field of
+// the transfer. Only the operator of ERC1155 transfers, which is part of their events, is in it:
+// the one of the other schemas is taken from the calls and was added later, it's left out so
+// their keys don't change.
+fn transfer_key(transfer: &transfers::Transfer) -> String {
+    let operator = match transfer.schema == schema_to_string(Schema::Erc1155) {
+        true => transfer.operator.as_slice(),
+        false => &[],
+    };
+
+    format!(
+        "{}-{}-{}-{}-{}-{}-{}-{}-{}",
+        transfer.schema,
+        Hex(&transfer.contract_address),
+        Hex(&transfer.token_id),
+        Hex(&transfer.from_address),
+        Hex(&transfer.to_address),
+        Hex(operator),
+        Hex(&transfer.quantity),
+        Hex(&transfer.tx_hash),
+        &transfer.ordinal
     )
 }
 
@@ -307,6 +894,7 @@ fn push_create(
     key: &str,
     ordinal: u64,
     value: transfers::Transfer,
+    anomaly: String,
 ) {
     changes
         .push_change("transfers", key, ordinal, Operation::Create)
@@ -320,5 +908,846 @@ fn push_create(
         .change("contract_address", (None, Hex(value.contract_address)))
         .change("tx_hash", (None, Hex(value.tx_hash)))
         .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()))
+        .change("anomaly", (None, anomaly));
+}
+
+fn transform_sales_to_database_changes(
+    changes: &mut DatabaseChanges,
+    sale: transfers::Sale,
+) {
+    let sale_key = format!("{}-{}", Hex(&sale.tx_hash), &sale.ordinal);
+
+    log::info!("Found a {} sale {}", sale.marketplace, sale_key);
+
+    for (i, payout) in sale.payouts.iter().enumerate() {
+        push_create_sale_payout(
+            changes,
+            &format!("{}-{}", sale_key, i),
+            &sale_key,
+            &sale,
+            payout,
+        );
+    }
+
+    push_create_sale(changes, &sale_key, sale.ordinal, sale)
+}
+
+fn push_create_sale(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+    value: transfers::Sale,
+) {
+    changes
+        .push_change("sales", key, ordinal, Operation::Create)
+        .change("marketplace", (None, value.marketplace))
+        .change("marketplace_address", (None, Hex(value.marketplace_address)))
+        .change("order_hash", (None, Hex(value.order_hash)))
+        .change("contract_address", (None, Hex(value.contract_address)))
+        .change("token_id", (None, Hex(value.token_id)))
+        .change("quantity", (None, value.quantity))
+        .change("item_count", (None, value.item_count))
+        .change("seller", (None, Hex(value.seller)))
+        .change("buyer", (None, Hex(value.buyer)))
+        .change("payment_token", (None, Hex(value.payment_token)))
+        .change("price", (None, value.price))
+        .change("block_number", (None, value.block_number))
+        .change("tx_hash", (None, Hex(value.tx_hash)))
+        .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()));
+}
+
+fn push_create_sale_payout(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    sale_key: &str,
+    sale: &transfers::Sale,
+    value: &transfers::SalePayout,
+) {
+    changes
+        .push_change("sale_payouts", key, sale.ordinal, Operation::Create)
+        .change("sale_id", (None, sale_key.to_string()))
+        .change("role", (None, value.role.clone()))
+        .change("recipient", (None, Hex(&value.recipient)))
+        .change("payment_token", (None, Hex(&value.payment_token)))
+        .change("amount", (None, value.amount.clone()))
+        .change("contract_address", (None, Hex(&sale.contract_address)))
+        .change("token_id", (None, Hex(&sale.token_id)))
+        .change("block_number", (None, sale.block_number))
+        .change("tx_hash", (None, Hex(&sale.tx_hash)))
+        .change("timestamp", (None, sale.timestamp.clone().unwrap()));
+}
+
+fn transform_order_cancellations_to_database_changes(
+    changes: &mut DatabaseChanges,
+    order_cancellation: transfers::OrderCancellation,
+) {
+    let key = format!("{}-{}", Hex(&order_cancellation.tx_hash), &order_cancellation.ordinal);
+
+    log::info!("Found a {} {} {}", order_cancellation.marketplace, order_cancellation.kind, key);
+
+    push_create_order_cancellation(changes, &key, order_cancellation.ordinal, order_cancellation)
+}
+
+fn push_create_order_cancellation(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+    value: transfers::OrderCancellation,
+) {
+    changes
+        .push_change("order_cancellations", key, ordinal, Operation::Create)
+        .change("kind", (None, value.kind))
+        .change("marketplace", (None, value.marketplace))
+        .change("marketplace_address", (None, Hex(value.marketplace_address)))
+        .change("order_hash", (None, Hex(value.order_hash)))
+        .change("offerer", (None, Hex(value.offerer)))
+        .change("zone", (None, Hex(value.zone)))
+        .change("counter", (None, value.counter))
+        .change("block_number", (None, value.block_number))
+        .change("tx_hash", (None, Hex(value.tx_hash)))
+        .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()));
+}
+
+fn transform_punk_orders_to_database_changes(
+    changes: &mut DatabaseChanges,
+    punk_order: transfers::PunkOrder,
+) {
+    let key = format!("{}-{}", Hex(&punk_order.tx_hash), &punk_order.ordinal);
+
+    log::info!("Found a punk {} {}", punk_order.kind, key);
+
+    push_create_punk_order(changes, &key, punk_order.ordinal, punk_order)
+}
+
+fn push_create_punk_order(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+    value: transfers::PunkOrder,
+) {
+    changes
+        .push_change("punk_orders", key, ordinal, Operation::Create)
+        .change("kind", (None, value.kind))
+        .change("contract_address", (None, Hex(value.contract_address)))
+        .change("token_id", (None, Hex(value.token_id)))
+        .change("value", (None, value.value))
+        .change("from_address", (None, Hex(value.from_address)))
+        .change("to_address", (None, Hex(value.to_address)))
+        .change("block_number", (None, value.block_number))
+        .change("tx_hash", (None, Hex(value.tx_hash)))
+        .change("ordinal", (None, value.ordinal))
         .change("timestamp", (None, value.timestamp.unwrap()));
-}
\ No newline at end of file
+}
+
+fn transform_transfer_ranges_to_database_changes(
+    changes: &mut DatabaseChanges,
+    transfer_range: transfers::TransferRange,
+) {
+    let key = format!("{}-{}", Hex(&transfer_range.tx_hash), &transfer_range.ordinal);
+
+    log::info!("Found a transfer range {}", key);
+
+    push_create_transfer_range(changes, &key, transfer_range.ordinal, transfer_range)
+}
+
+fn push_create_transfer_range(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+    value: transfers::TransferRange,
+) {
+    changes
+        .push_change("transfer_ranges", key, ordinal, Operation::Create)
+        .change("contract_address", (None, Hex(value.contract_address)))
+        .change("from_token_id", (None, Hex(value.from_token_id)))
+        .change("to_token_id", (None, Hex(value.to_token_id)))
+        .change("from_address", (None, Hex(value.from_address)))
+        .change("to_address", (None, Hex(value.to_address)))
+        .change("block_number", (None, value.block_number))
+        .change("tx_hash", (None, Hex(value.tx_hash)))
+        .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()));
+}
+
+fn transform_metadata_refreshes_to_database_changes(
+    changes: &mut DatabaseChanges,
+    metadata_refresh: transfers::MetadataRefresh,
+) {
+    let key = format!("{}-{}", Hex(&metadata_refresh.tx_hash), &metadata_refresh.ordinal);
+
+    log::info!("Found a metadata refresh {}", key);
+
+    let from_token_id = BigInt::from_unsigned_bytes_be(&metadata_refresh.from_token_id);
+    let to_token_id = BigInt::from_unsigned_bytes_be(&metadata_refresh.to_token_id);
+
+    match token_range_size(&from_token_id, &to_token_id) {
+        Some(size) if size <= MAX_METADATA_REFRESH_RANGE => {
+            for i in 0..size {
+                let token_id = (from_token_id.clone() + BigInt::from(i)).to_bytes_be().1;
+
+                push_update_nft_metadata_stale(
+                    changes,
+                    &nft_key(&metadata_refresh.contract_address, &token_id),
+                    metadata_refresh.ordinal,
+                );
+            }
+        }
+        _ => log::info!(
+            "Metadata refresh {} is over the limit of {} tokens, not flagging nfts individually",
+            key,
+            MAX_METADATA_REFRESH_RANGE,
+        ),
+    }
+
+    push_create_metadata_refresh(changes, &key, metadata_refresh.ordinal, metadata_refresh)
+}
+
+fn nft_key(contract_address: &[u8], token_id: &[u8]) -> String {
+    format!("{}-{}", Hex(contract_address), Hex(token_id))
+}
+
+fn balance_key(contract_address: &[u8], token_id: &[u8], holder: &[u8]) -> String {
+    format!("{}-{}-{}", Hex(contract_address), Hex(token_id), Hex(holder))
+}
+
+fn push_create_metadata_refresh(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+    value: transfers::MetadataRefresh,
+) {
+    changes
+        .push_change("metadata_refresh", key, ordinal, Operation::Create)
+        .change("contract_address", (None, Hex(value.contract_address)))
+        .change("from_token_id", (None, Hex(value.from_token_id)))
+        .change("to_token_id", (None, Hex(value.to_token_id)))
+        .change("block_number", (None, value.block_number))
+        .change("tx_hash", (None, Hex(value.tx_hash)))
+        .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()));
+}
+
+// The sink turns an update into an `UPDATE ... WHERE id = ...`, tokens that don't have a row in
+// `nfts` yet are left untouched.
+fn push_update_nft_metadata_stale(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+) {
+    changes
+        .push_change("nfts", key, ordinal, Operation::Update)
+        .change("metadata_stale", (None, true));
+}
+
+fn push_update_nft_locked(
+    changes: &mut DatabaseChanges,
+    token_lock: &transfers::TokenLock,
+) {
+    changes
+        .push_change(
+            "nfts",
+            &nft_key(&token_lock.contract_address, &token_lock.token_id),
+            token_lock.ordinal,
+            Operation::Update,
+        )
+        .change("locked", (None, token_lock.locked));
+}
+
+// The store is keyed like the `token_users` table, its deltas map one to one onto database
+// operations: the first user ever set on a token creates the row, the next ones update it.
+fn transform_token_user_delta_to_database_changes(
+    changes: &mut DatabaseChanges,
+    delta: DeltaProto<transfers::TokenUser>,
+) {
+    let operation = match store_operation_to_database_operation(delta.operation) {
+        Some(operation) => operation,
+        None => return,
+    };
+
+    log::info!("Found a token user {}", delta.key);
+
+    push_token_user(changes, &delta.key, delta.ordinal, operation, delta.new_value)
+}
+
+// The value a store held before `operation`, only updates have one. It's written as the old value
+// of the field so that consumers of the changes see what the update replaced.
+fn delta_old_value<T>(operation: StoreOperation, old_value: T) -> Option<T> {
+    match operation {
+        StoreOperation::Update => Some(old_value),
+        _ => None,
+    }
+}
+
+fn store_operation_to_database_operation(operation: StoreOperation) -> Option<Operation> {
+    match operation {
+        StoreOperation::Create => Some(Operation::Create),
+        StoreOperation::Update => Some(Operation::Update),
+        StoreOperation::Delete => Some(Operation::Delete),
+        StoreOperation::Unset => None,
+    }
+}
+
+fn push_token_user(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+    operation: Operation,
+    value: transfers::TokenUser,
+) {
+    changes
+        .push_change("token_users", key, ordinal, operation)
+        .change("contract_address", (None, Hex(value.contract_address)))
+        .change("token_id", (None, Hex(value.token_id)))
+        .change("user_address", (None, Hex(value.user)))
+        .change("expires", (None, value.expires))
+        .change("block_number", (None, value.block_number))
+        .change("tx_hash", (None, Hex(value.tx_hash)))
+        .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()));
+}
+
+// Accounts are deterministic and the registry only emits the event when it deploys the account,
+// so the account address is used as the key.
+fn transform_token_bound_accounts_to_database_changes(
+    changes: &mut DatabaseChanges,
+    token_bound_account: transfers::TokenBoundAccount,
+) {
+    let key = Hex(&token_bound_account.account).to_string();
+
+    log::info!(
+        "Found a token bound account {} for token {}",
+        key,
+        nft_key(&token_bound_account.contract_address, &token_bound_account.token_id),
+    );
+
+    push_create_token_bound_account(changes, &key, token_bound_account.ordinal, token_bound_account)
+}
+
+fn push_create_token_bound_account(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+    value: transfers::TokenBoundAccount,
+) {
+    changes
+        .push_change("token_bound_accounts", key, ordinal, Operation::Create)
+        .change("account", (None, Hex(value.account)))
+        .change("implementation", (None, Hex(value.implementation)))
+        .change("salt", (None, Hex(value.salt)))
+        .change("chain_id", (None, value.chain_id))
+        .change("contract_address", (None, Hex(value.contract_address)))
+        .change("token_id", (None, Hex(value.token_id)))
+        .change("block_number", (None, value.block_number))
+        .change("tx_hash", (None, Hex(value.tx_hash)))
+        .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()));
+}
+
+fn transform_contract_classifications_to_database_changes(
+    changes: &mut DatabaseChanges,
+    contract_standards: &impl StoreGet<String>,
+    contract_classification: transfers::ContractClassification,
+) {
+    let key = Hex(&contract_classification.contract_address).to_string();
+
+    log::info!("Found a {} contract {}", contract_classification.standard, key);
+
+    // A contract can be deployed again at the same address after it self-destructed, the row
+    // already exists in that case.
+    let existed = match contract_classification.ordinal.checked_sub(1) {
+        Some(previous) => contract_standards.has_at(previous, &key),
+        None => contract_standards.has_first(&key),
+    };
+    let operation = match existed {
+        true => Operation::Update,
+        false => Operation::Create,
+    };
+
+    push_contract(changes, &key, contract_classification.ordinal, operation, contract_classification)
+}
+
+fn push_contract(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+    operation: Operation,
+    value: transfers::ContractClassification,
+) {
+    changes
+        .push_change("contracts", key, ordinal, operation)
+        .change("contract", (None, Hex(value.contract_address)))
+        .change("deployer", (None, Hex(value.deployer)))
+        .change("standard", (None, value.standard));
+}
+
+fn transform_contract_upgrades_to_database_changes(
+    changes: &mut DatabaseChanges,
+    contract_standards: &impl StoreGet<String>,
+    contract_upgrade: transfers::ContractUpgrade,
+) {
+    // Slot writes without a log between them share their ordinal, the ones of different proxies
+    // are told apart by the contract and the last one of a proxy is kept.
+    let key = format!(
+        "{}-{}-{}-{}",
+        Hex(&contract_upgrade.tx_hash),
+        &contract_upgrade.source,
+        &contract_upgrade.ordinal,
+        Hex(&contract_upgrade.contract_address),
+    );
+
+    log::info!("Found a contract upgrade {} of {}", key, Hex(&contract_upgrade.contract_address).to_string());
+
+    if contract_upgrade.kind == contract_upgrade_kind_to_string(ContractUpgradeKind::Upgraded) {
+        push_contract_implementation(changes, contract_standards, &contract_upgrade);
+    }
+
+    push_create_contract_upgrade(changes, &key, contract_upgrade.ordinal, contract_upgrade)
+}
+
+fn push_create_contract_upgrade(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+    value: transfers::ContractUpgrade,
+) {
+    changes
+        .push_change("contract_upgrades", key, ordinal, Operation::Create)
+        .change("kind", (None, value.kind))
+        .change("source", (None, value.source))
+        .change("contract_address", (None, Hex(value.contract_address)))
+        .change("implementation", (None, Hex(value.implementation)))
+        .change("previous_admin", (None, Hex(value.previous_admin)))
+        .change("new_admin", (None, Hex(value.new_admin)))
+        .change("block_number", (None, value.block_number))
+        .change("tx_hash", (None, Hex(value.tx_hash)))
+        .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()));
+}
+
+// Same as the owners, proxies deployed after the start block already have a row created from their
+// classification, for the others the upgrade creates the row. The sink turns creates into upserts,
+// so a row created by an earlier upgrade or owner is only updated.
+fn push_contract_implementation(
+    changes: &mut DatabaseChanges,
+    contract_standards: &impl StoreGet<String>,
+    contract_upgrade: &transfers::ContractUpgrade,
+) {
+    let key = Hex(&contract_upgrade.contract_address).to_string();
+    let operation = match contract_standards.get_last(&key) {
+        Some(_) => Operation::Update,
+        None => Operation::Create,
+    };
+
+    changes
+        .push_change("contracts", &key, contract_upgrade.ordinal, operation)
+        .change("contract", (None, Hex(&contract_upgrade.contract_address)))
+        .change("implementation", (None, Hex(&contract_upgrade.implementation)));
+}
+
+// The store only holds NFT contracts and is keyed like the `contracts` table. Contracts deployed
+// after the start block already have a row created from their classification, for the others the
+// first owner seen creates the row.
+fn transform_contract_owner_delta_to_database_changes(
+    changes: &mut DatabaseChanges,
+    contract_standards: &impl StoreGet<String>,
+    delta: DeltaProto<transfers::OwnershipTransfer>,
+) {
+    let operation = match store_operation_to_database_operation(delta.operation) {
+        Some(Operation::Create) if contract_standards.get_last(&delta.key).is_some() => Operation::Update,
+        Some(operation) => operation,
+        None => return,
+    };
+
+    let ownership_transfer = delta.new_value;
+    let history_key = format!("{}-{}", Hex(&ownership_transfer.tx_hash), &ownership_transfer.ordinal);
+
+    log::info!(
+        "Found an ownership transfer of {} to {}",
+        delta.key,
+        Hex(&ownership_transfer.new_owner).to_string(),
+    );
+
+    let old_owner = delta_old_value(delta.operation, delta.old_value).map(|old| Hex(old.new_owner).to_string());
+    push_contract_owner(changes, &delta.key, delta.ordinal, operation, old_owner, &ownership_transfer);
+    push_create_ownership_transfer(changes, &history_key, ownership_transfer.ordinal, ownership_transfer)
+}
+
+fn push_contract_owner(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+    operation: Operation,
+    old_owner: Option<String>,
+    value: &transfers::OwnershipTransfer,
+) {
+    changes
+        .push_change("contracts", key, ordinal, operation)
+        .change("contract", (None, Hex(&value.contract_address)))
+        .change("owner", (old_owner, Hex(&value.new_owner).to_string()));
+}
+
+fn push_create_ownership_transfer(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+    value: transfers::OwnershipTransfer,
+) {
+    changes
+        .push_change("contract_ownership_transfers", key, ordinal, Operation::Create)
+        .change("contract_address", (None, Hex(value.contract_address)))
+        .change("previous_owner", (None, Hex(value.previous_owner)))
+        .change("new_owner", (None, Hex(value.new_owner)))
+        .change("block_number", (None, value.block_number))
+        .change("tx_hash", (None, Hex(value.tx_hash)))
+        .change("ordinal", (None, value.ordinal))
+        .change("timestamp", (None, value.timestamp.unwrap()));
+}
+
+fn transform_transactions_to_database_changes(
+    changes: &mut DatabaseChanges,
+    transaction: transfers::Transaction,
+) {
+    let key = Hex(&transaction.hash).to_string();
+
+    log::info!("Found a transaction {}", key);
+
+    push_create_transaction(changes, &key, transaction.index as u64, transaction)
+}
+
+fn push_create_transaction(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+    value: transfers::Transaction,
+) {
+    changes
+        .push_change("transactions", key, ordinal, Operation::Create)
+        .change("from_address", (None, Hex(value.from_address)))
+        .change("to_address", (None, Hex(value.to_address)))
+        .change("value", (None, value.value))
+        .change("gas_used", (None, value.gas_used))
+        .change("effective_gas_price", (None, value.effective_gas_price))
+        .change("block_number", (None, value.block_number))
+        .change("transaction_index", (None, value.index))
+        .change("timestamp", (None, value.timestamp.unwrap()));
+}
+
+// The stores below are keyed like their table and their deltas map one to one onto database
+// operations, the first value set creates the row and the next ones update it. Replaying blocks
+// already loaded creates rows that exist, the sink turns creates into upserts.
+fn transform_nft_owner_delta_to_database_changes(
+    changes: &mut DatabaseChanges,
+    delta: DeltaString,
+) {
+    let operation = match store_operation_to_database_operation(delta.operation) {
+        Some(operation) => operation,
+        None => return,
+    };
+
+    log::info!("Found an owner {} for nft {}", delta.new_value, delta.key);
+
+    let old_owner = delta_old_value(delta.operation, delta.old_value);
+    push_nft_owner(changes, &delta.key, delta.ordinal, operation, old_owner, delta.new_value)
+}
+
+fn push_nft_owner(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+    operation: Operation,
+    old_owner: Option<String>,
+    owner: String,
+) {
+    let change = changes.push_change("nfts", key, ordinal, operation);
+
+    if operation == Operation::Create {
+        let (contract, token_id) = key.split_once('-').unwrap_or((key, ""));
+
+        change
+            .change("contract", (None, contract.to_string()))
+            .change("token_id", (None, token_id.to_string()));
+    }
+
+    change.change("owner", (old_owner, owner));
+}
+
+fn transform_balance_delta_to_database_changes(
+    changes: &mut DatabaseChanges,
+    delta: DeltaBigInt,
+) {
+    let operation = match store_operation_to_database_operation(delta.operation) {
+        Some(operation) => operation,
+        None => return,
+    };
+
+    log::info!("Found a balance {} for {}", delta.new_value, delta.key);
+
+    let old_balance = delta_old_value(delta.operation, delta.old_value).map(|balance| balance.to_string());
+    push_balance(changes, &delta.key, delta.ordinal, operation, old_balance, delta.new_value)
+}
+
+fn push_balance(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+    operation: Operation,
+    old_balance: Option<String>,
+    balance: BigInt,
+) {
+    let change = changes.push_change("balances", key, ordinal, operation);
+
+    if operation == Operation::Create {
+        let parts: Vec<&str> = key.splitn(3, '-').collect();
+
+        change
+            .change("contract", (None, parts[0].to_string()))
+            .change("token_id", (None, parts.get(1).unwrap_or(&"").to_string()))
+            .change("holder", (None, parts.get(2).unwrap_or(&"").to_string()));
+    }
+
+    change.change("balance", (old_balance, balance.to_string()));
+}
+
+fn transform_collection_delta_to_database_changes(
+    changes: &mut DatabaseChanges,
+    delta: DeltaInt64,
+) {
+    let operation = match store_operation_to_database_operation(delta.operation) {
+        Some(operation) => operation,
+        None => return,
+    };
+
+    log::info!("Found {} transfers for collection {}", delta.new_value, delta.key);
+
+    push_collection(changes, &delta.key, delta.ordinal, operation, delta.new_value)
+}
+
+fn push_collection(
+    changes: &mut DatabaseChanges,
+    key: &str,
+    ordinal: u64,
+    operation: Operation,
+    transfer_count: i64,
+) {
+    let change = changes.push_change("collections", key, ordinal, operation);
+
+    if operation == Operation::Create {
+        change.change("contract", (None, key.to_string()));
+    }
+
+    change.change("transfer_count", (None, transfer_count));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::pipeline::Pipeline;
+    use crate::testing::{
+        erc1155_transfer_single_log, erc2309_consecutive_transfer_log, erc721_transfer_log, BlockBuilder, CallBuilder,
+        TransactionBuilder,
+    };
+
+    const CONTRACT: [u8; 20] = [0x72; 20];
+    const ROUTER: [u8; 20] = [0x70; 20];
+    const ALICE: [u8; 20] = [0xa1; 20];
+    const BOB: [u8; 20] = [0xb0; 20];
+
+    #[test]
+    fn it_takes_the_operator_from_the_call_emitting_the_log() {
+        // The log of the reverted call has the same block index as the one of the receipt.
+        let blk = BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE)
+                .call(CallBuilder::new(&ROUTER))
+                .call(CallBuilder::new(&CONTRACT).caller(&BOB).reverted().log(erc721_transfer_log(&CONTRACT, &ALICE, &BOB, 1)))
+                .call(CallBuilder::new(&CONTRACT).log(erc721_transfer_log(&CONTRACT, &ALICE, &BOB, 1))))
+            .build();
+
+        let (_timestamp, transfers) = transform_block_to_transfers(&blk);
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].operator, ROUTER.to_vec());
+    }
+
+    #[test]
+    fn it_keeps_the_operator_of_erc721_transfers_out_of_their_key() {
+        let blk = BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE).call(
+                CallBuilder::new(&CONTRACT)
+                    .log(erc721_transfer_log(&CONTRACT, &ALICE, &BOB, 1))
+                    .log(erc1155_transfer_single_log(&CONTRACT, &ALICE, &ALICE, &BOB, 1, 1)),
+            ))
+            .build();
+
+        let (_timestamp, transfers) = transform_block_to_transfers(&blk);
+        let tx_hash = Hex([0x01; 32]);
+
+        assert_eq!(transfers[0].operator, ALICE.to_vec());
+        assert_eq!(
+            transfer_key(&transfers[0]),
+            format!("erc721-{}-01-{}-{}--31-{}-0", Hex(CONTRACT), Hex(ALICE), Hex(BOB), tx_hash),
+        );
+        assert_eq!(
+            transfer_key(&transfers[1]),
+            format!("erc1155-{}-01-{}-{}-{}-31-{}-1", Hex(CONTRACT), Hex(ALICE), Hex(BOB), Hex(ALICE), tx_hash),
+        );
+    }
+
+    #[test]
+    fn it_drops_look_alike_transfers_of_contracts_deployed_in_range() {
+        let look_alike = [0x73; 20];
+        let erc20 = [0x20; 20];
+        let proxy = [0x50; 20];
+        let undeployed = [0x74; 20];
+
+        let blk = BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE)
+                .call(CallBuilder::new(&look_alike).code(&[0x00]))
+                .call(CallBuilder::new(&erc20).code(&hex_literal::hex!("63a9059cbb6318160ddd63dd62ed3e")))
+                .call(CallBuilder::new(&proxy)
+                    .code(&hex_literal::hex!("363d3d373d3d3d363d73bebebebebebebebebebebebebebebebebebebebe5af43d82803e903d91602b57fd5bf3"))))
+            .transaction(TransactionBuilder::new(0x02).from(&ALICE)
+                .call(CallBuilder::new(&look_alike)
+                    .log(erc721_transfer_log(&look_alike, &ALICE, &BOB, 1))
+                    .log(erc1155_transfer_single_log(&look_alike, &ALICE, &ALICE, &BOB, 1, 1)))
+                .call(CallBuilder::new(&erc20).log(erc721_transfer_log(&erc20, &ALICE, &BOB, 1)))
+                .call(CallBuilder::new(&proxy).log(erc721_transfer_log(&proxy, &ALICE, &BOB, 1)))
+                .call(CallBuilder::new(&undeployed).log(erc721_transfer_log(&undeployed, &ALICE, &BOB, 1))))
+            .build();
+
+        let changes = Pipeline::new().db_out(&blk);
+        let contracts: Vec<String> = table_pks(&changes, "transfers").iter()
+            .map(|pk| pk.split('-').nth(1).unwrap().to_string())
+            .collect();
+
+        assert_eq!(contracts, vec![Hex(proxy).to_string(), Hex(undeployed).to_string()]);
+        assert!(table_pks(&changes, "nfts").iter().all(|pk| !pk.starts_with(&Hex(look_alike).to_string())));
+        assert!(table_pks(&changes, "collections").iter().all(|pk| *pk != Hex(look_alike).to_string()));
+    }
+
+    fn consecutive_transfer_block(from_token_id: u64, to_token_id: u64) -> ethpb::eth::v2::Block {
+        BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE).call(
+                CallBuilder::new(&CONTRACT).log(erc2309_consecutive_transfer_log(&CONTRACT, from_token_id, to_token_id, &ZERO_ADDRESS, &BOB)),
+            ))
+            .build()
+    }
+
+    fn table_pks(changes: &DatabaseChanges, table: &str) -> Vec<String> {
+        changes.table_changes.iter()
+            .filter(|change| change.table == table)
+            .map(|change| change.pk.clone())
+            .collect()
+    }
+
+    #[test]
+    fn it_expands_consecutive_transfers_into_one_transfer_per_token() {
+        let (_timestamp, transfers) = transform_block_to_transfers(&consecutive_transfer_block(1, 3));
+
+        let token_ids: Vec<Vec<u8>> = transfers.iter().map(|transfer| transfer.token_id.clone()).collect();
+        assert_eq!(token_ids, vec![vec![1], vec![2], vec![3]]);
+
+        for transfer in &transfers {
+            assert_eq!(transfer.schema, "erc721");
+            assert_eq!(transfer.from_address, ZERO_ADDRESS.to_vec());
+            assert_eq!(transfer.to_address, BOB.to_vec());
+            assert_eq!(transfer.quantity, "1");
+            assert_eq!(transfer.ordinal, 0);
+        }
+    }
+
+    #[test]
+    fn it_expands_consecutive_transfers_up_to_the_max_range() {
+        let blk = consecutive_transfer_block(1, 3);
+        let params = Params { max_consecutive_transfer_range: 3 };
+
+        let changes = Pipeline::with_params(params).db_out(&blk);
+
+        assert_eq!(table_pks(&changes, "transfers").len(), 3);
+        assert_eq!(table_pks(&changes, "nfts").len(), 3);
+        assert!(table_pks(&changes, "transfer_ranges").is_empty());
+    }
+
+    #[test]
+    fn it_writes_consecutive_transfers_over_the_max_range_as_a_range() {
+        let blk = consecutive_transfer_block(1, 3);
+        let params = Params { max_consecutive_transfer_range: 2 };
+
+        let (_timestamp, transfers) = transform_block_to_transfers_with_legacy_contracts(&blk, &params, &|_| false);
+        assert!(transfers.is_empty());
+
+        let changes = Pipeline::with_params(params).db_out(&blk);
+
+        assert!(table_pks(&changes, "transfers").is_empty());
+        assert!(table_pks(&changes, "nfts").is_empty());
+        assert_eq!(table_pks(&changes, "transfer_ranges").len(), 1);
+    }
+
+    // The operation and the `(old, new)` values of the fields of the change of `pk` in `table`.
+    fn table_change(changes: &DatabaseChanges, table: &str, pk: &str) -> (Operation, Vec<(String, String, String)>) {
+        let change = changes.table_changes.iter()
+            .find(|change| change.table == table && change.pk == pk)
+            .unwrap_or_else(|| panic!("no change of {} in {}", pk, table));
+        let fields = change.fields.iter()
+            .map(|field| (field.name.clone(), field.old_value.clone(), field.new_value.clone()))
+            .collect();
+
+        (Operation::from_i32(change.operation).unwrap(), fields)
+    }
+
+    fn field(name: &str, old_value: &str, new_value: &str) -> (String, String, String) {
+        (name.to_string(), old_value.to_string(), new_value.to_string())
+    }
+
+    #[test]
+    fn it_writes_owners_balances_and_collections_from_the_store_deltas() {
+        let mut pipeline = Pipeline::new();
+        let block = |number: u64, from: &[u8], to: &[u8]| {
+            BlockBuilder::new(number)
+                .transaction(TransactionBuilder::new(0x01).from(&ALICE).call(
+                    CallBuilder::new(&CONTRACT).log(erc721_transfer_log(&CONTRACT, from, to, 1)),
+                ))
+                .build()
+        };
+
+        let nft = format!("{}-01", Hex(CONTRACT));
+        let alice = format!("{}-01-{}", Hex(CONTRACT), Hex(ALICE));
+        let bob = format!("{}-01-{}", Hex(CONTRACT), Hex(BOB));
+        let collection = Hex(CONTRACT).to_string();
+
+        let mint = pipeline.db_out(&block(12287507, &ZERO_ADDRESS, &ALICE));
+
+        assert_eq!(table_change(&mint, "nfts", &nft), (Operation::Create, vec![
+            field("contract", "", &collection),
+            field("token_id", "", "01"),
+            field("owner", "", &Hex(ALICE).to_string()),
+        ]));
+        assert_eq!(table_change(&mint, "balances", &alice), (Operation::Create, vec![
+            field("contract", "", &collection),
+            field("token_id", "", "01"),
+            field("holder", "", &Hex(ALICE).to_string()),
+            field("balance", "", "1"),
+        ]));
+        assert_eq!(table_pks(&mint, "balances"), vec![alice.clone()]);
+        assert_eq!(table_change(&mint, "collections", &collection), (Operation::Create, vec![
+            field("contract", "", &collection),
+            field("transfer_count", "", "1"),
+        ]));
+
+        let transfer = pipeline.db_out(&block(12287508, &ALICE, &BOB));
+
+        assert_eq!(table_change(&transfer, "nfts", &nft), (Operation::Update, vec![
+            field("owner", &Hex(ALICE).to_string(), &Hex(BOB).to_string()),
+        ]));
+        assert_eq!(table_change(&transfer, "balances", &alice), (Operation::Update, vec![
+            field("balance", "1", "0"),
+        ]));
+        assert_eq!(table_change(&transfer, "balances", &bob).0, Operation::Create);
+        assert_eq!(table_change(&transfer, "collections", &collection), (Operation::Update, vec![
+            field("transfer_count", "", "2"),
+        ]));
+    }
+}
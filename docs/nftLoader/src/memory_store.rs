@@ -0,0 +1,260 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use substreams::pb::substreams::store_delta::Operation as StoreOperation;
+use substreams::scalar::BigInt;
+use substreams::store::{
+    DeltaBigInt, DeltaInt64, DeltaProto, DeltaString, Deltas, StoreAdd, StoreDelete, StoreGet,
+    StoreNew, StoreSet, StoreSetIfNotExists,
+};
+
+// A store kept in memory, standing in for the ones of the substreams runtime when the modules run
+// natively. Like in the runtime, reads see the writes of the current block and every write of the
+// block is a delta until `commit` is called at the end of the block.
+pub struct MemoryStore<V> {
+    committed: RefCell<HashMap<String, V>>,
+    deltas: RefCell<Vec<MemoryDelta<V>>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MemoryDelta<V> {
+    pub operation: StoreOperation,
+    pub ordinal: u64,
+    pub key: String,
+    pub old_value: Option<V>,
+    pub new_value: Option<V>,
+}
+
+impl<V: Clone> MemoryStore<V> {
+    pub fn new() -> Self {
+        MemoryStore {
+            committed: RefCell::new(HashMap::new()),
+            deltas: RefCell::new(Vec::new()),
+        }
+    }
+
+    // Deltas written since the last commit, in the order they were written.
+    pub fn deltas(&self) -> Vec<MemoryDelta<V>> {
+        self.deltas.borrow().clone()
+    }
+
+    pub fn commit(&self) {
+        let mut committed = self.committed.borrow_mut();
+
+        for delta in self.deltas.borrow_mut().drain(..) {
+            match delta.new_value {
+                Some(value) => committed.insert(delta.key, value),
+                None => committed.remove(&delta.key),
+            };
+        }
+    }
+
+    fn value_at(&self, ordinal: Option<u64>, key: &str) -> Option<V> {
+        let mut value = self.committed.borrow().get(key).cloned();
+
+        for delta in self.deltas.borrow().iter() {
+            if delta.key != key {
+                continue;
+            }
+
+            if let Some(ordinal) = ordinal {
+                if delta.ordinal > ordinal {
+                    break;
+                }
+            }
+
+            value = delta.new_value.clone();
+        }
+
+        value
+    }
+
+    fn write(&self, ordinal: u64, key: &str, value: Option<V>) {
+        let old_value = self.value_at(None, key);
+
+        let operation = match (&old_value, &value) {
+            (_, None) => StoreOperation::Delete,
+            (None, Some(_)) => StoreOperation::Create,
+            (Some(_), Some(_)) => StoreOperation::Update,
+        };
+
+        self.deltas.borrow_mut().push(MemoryDelta {
+            operation: operation,
+            ordinal: ordinal,
+            key: key.to_string(),
+            old_value: old_value,
+            new_value: value,
+        });
+    }
+}
+
+impl<V: Clone> Default for MemoryStore<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Clone + Default> MemoryStore<V> {
+    // The deltas written since the last commit in the shape the runtime hands them to modules,
+    // missing values are the default value like in the runtime.
+    pub fn to_deltas<D>(&self, new_delta: fn(StoreOperation, u64, String, V, V) -> D) -> Deltas<D>
+    where
+        D: substreams::store::Delta,
+    {
+        Deltas {
+            deltas: self
+                .deltas()
+                .into_iter()
+                .map(|delta| new_delta(
+                    delta.operation,
+                    delta.ordinal,
+                    delta.key,
+                    delta.old_value.unwrap_or_default(),
+                    delta.new_value.unwrap_or_default(),
+                ))
+                .collect(),
+        }
+    }
+}
+
+pub fn new_delta_string(operation: StoreOperation, ordinal: u64, key: String, old_value: String, new_value: String) -> DeltaString {
+    DeltaString { operation, ordinal, key, old_value, new_value }
+}
+
+pub fn new_delta_big_int(operation: StoreOperation, ordinal: u64, key: String, old_value: BigInt, new_value: BigInt) -> DeltaBigInt {
+    DeltaBigInt { operation, ordinal, key, old_value, new_value }
+}
+
+pub fn new_delta_int64(operation: StoreOperation, ordinal: u64, key: String, old_value: i64, new_value: i64) -> DeltaInt64 {
+    DeltaInt64 { operation, ordinal, key, old_value, new_value }
+}
+
+pub fn new_delta_proto<T: prost::Message + Default + PartialEq>(operation: StoreOperation, ordinal: u64, key: String, old_value: T, new_value: T) -> DeltaProto<T> {
+    DeltaProto { operation, ordinal, key, old_value, new_value }
+}
+
+impl<V: Clone> StoreNew for MemoryStore<V> {
+    fn new() -> Self {
+        MemoryStore::new()
+    }
+}
+
+impl<V: Clone> StoreDelete for MemoryStore<V> {
+    fn delete_prefix(&self, ordinal: i64, prefix: &String) {
+        let mut keys: Vec<String> = self.committed.borrow().keys()
+            .chain(self.deltas.borrow().iter().map(|delta| &delta.key))
+            .filter(|key| key.starts_with(prefix.as_str()))
+            .cloned()
+            .collect();
+        keys.sort();
+        keys.dedup();
+
+        for key in keys {
+            if self.value_at(None, &key).is_some() {
+                self.write(ordinal as u64, &key, None);
+            }
+        }
+    }
+}
+
+impl<V: Clone> StoreSet<V> for MemoryStore<V> {
+    fn set<K: AsRef<str>>(&self, ordinal: u64, key: K, value: &V) {
+        self.write(ordinal, key.as_ref(), Some(value.clone()));
+    }
+
+    fn set_many<K: AsRef<str>>(&self, ordinal: u64, keys: &Vec<K>, value: &V) {
+        for key in keys {
+            self.set(ordinal, key, value);
+        }
+    }
+}
+
+impl<V: Clone> StoreSetIfNotExists<V> for MemoryStore<V> {
+    fn set_if_not_exists<K: AsRef<str>>(&self, ordinal: u64, key: K, value: &V) {
+        if self.value_at(None, key.as_ref()).is_none() {
+            self.write(ordinal, key.as_ref(), Some(value.clone()));
+        }
+    }
+}
+
+impl StoreAdd<BigInt> for MemoryStore<BigInt> {
+    fn add<K: AsRef<str>>(&self, ordinal: u64, key: K, value: BigInt) {
+        let current = self.value_at(None, key.as_ref()).unwrap_or_else(BigInt::zero);
+        self.write(ordinal, key.as_ref(), Some(current + value));
+    }
+
+    fn add_many<K: AsRef<str>>(&self, ordinal: u64, keys: &Vec<K>, value: BigInt) {
+        for key in keys {
+            self.add(ordinal, key, value.clone());
+        }
+    }
+}
+
+impl StoreAdd<i64> for MemoryStore<i64> {
+    fn add<K: AsRef<str>>(&self, ordinal: u64, key: K, value: i64) {
+        let current = self.value_at(None, key.as_ref()).unwrap_or_default();
+        self.write(ordinal, key.as_ref(), Some(current + value));
+    }
+
+    fn add_many<K: AsRef<str>>(&self, ordinal: u64, keys: &Vec<K>, value: i64) {
+        for key in keys {
+            self.add(ordinal, key, value);
+        }
+    }
+}
+
+impl<V: Clone> StoreGet<V> for MemoryStore<V> {
+    fn new(_idx: u32) -> Self {
+        MemoryStore::new()
+    }
+
+    fn get_at<K: AsRef<str>>(&self, ordinal: u64, key: K) -> Option<V> {
+        self.value_at(Some(ordinal), key.as_ref())
+    }
+
+    fn get_last<K: AsRef<str>>(&self, key: K) -> Option<V> {
+        self.value_at(None, key.as_ref())
+    }
+
+    fn get_first<K: AsRef<str>>(&self, key: K) -> Option<V> {
+        self.committed.borrow().get(key.as_ref()).cloned()
+    }
+
+    fn has_at<K: AsRef<str>>(&self, ordinal: u64, key: K) -> bool {
+        self.get_at(ordinal, key).is_some()
+    }
+
+    fn has_last<K: AsRef<str>>(&self, key: K) -> bool {
+        self.get_last(key).is_some()
+    }
+
+    fn has_first<K: AsRef<str>>(&self, key: K) -> bool {
+        self.get_first(key).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_memory_store_deltas() {
+        let store: MemoryStore<i64> = MemoryStore::new();
+
+        store.add(1, "a", 2);
+        store.add(3, "a", 5);
+        store.commit();
+        store.add(2, "a", -1);
+
+        assert_eq!(store.deltas(), vec![MemoryDelta {
+            operation: StoreOperation::Update,
+            ordinal: 2,
+            key: "a".to_string(),
+            old_value: Some(7),
+            new_value: Some(6),
+        }]);
+        assert_eq!(store.get_first("a"), Some(7));
+        assert_eq!(store.get_at(1, "a"), Some(7));
+        assert_eq!(store.get_last("a"), Some(6));
+    }
+}
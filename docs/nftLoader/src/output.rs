@@ -0,0 +1,84 @@
+use serde_json::{json, Value};
+use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
+
+// `changes` as JSON, in the order of the changes and with the field names of the proto. Used to
+// print and snapshot the output of `db_out` natively.
+pub fn database_changes_to_json(changes: &DatabaseChanges) -> Value {
+    let table_changes: Vec<Value> = changes.table_changes.iter().map(|change| {
+        let fields: Vec<Value> = change.fields.iter().map(|field| json!({
+            "name": field.name,
+            "new_value": field.new_value,
+            "old_value": field.old_value,
+        })).collect();
+
+        json!({
+            "table": change.table,
+            "pk": change.pk,
+            "ordinal": change.ordinal,
+            "operation": operation_to_string(change.operation),
+            "fields": fields,
+        })
+    }).collect();
+
+    json!({ "table_changes": table_changes })
+}
+
+// `changes` as the statements the Postgres sink runs for them, one per change. Every table has an
+// `id` primary key and the values are quoted, Postgres casts them to the type of their column.
+// Inserts are upserts and unset changes are skipped, like in the sink.
+pub fn database_changes_to_sql(changes: &DatabaseChanges) -> Vec<String> {
+    changes.table_changes.iter().filter_map(|change| {
+        let pk = quote(&change.pk);
+
+        let statement = match Operation::from_i32(change.operation) {
+            Some(Operation::Delete) => format!("DELETE FROM {} WHERE id = {};", change.table, pk),
+            Some(Operation::Update) => {
+                let updates: Vec<String> = change.fields.iter()
+                    .map(|field| format!("{}={}", field.name, quote(&field.new_value)))
+                    .collect();
+
+                format!("UPDATE {} SET {} WHERE id = {};", change.table, updates.join(", "), pk)
+            }
+            Some(Operation::Create) => {
+                let mut columns = vec!["id".to_string()];
+                let mut values = vec![pk];
+                let mut conflict_updates = Vec::new();
+
+                for field in change.fields.iter().filter(|field| field.name != "id") {
+                    columns.push(field.name.clone());
+                    values.push(quote(&field.new_value));
+                    conflict_updates.push(format!("{}=EXCLUDED.{}", field.name, field.name));
+                }
+
+                let on_conflict = match conflict_updates.is_empty() {
+                    true => "DO NOTHING".to_string(),
+                    false => format!("DO UPDATE SET {}", conflict_updates.join(", ")),
+                };
+
+                format!(
+                    "INSERT INTO {} ({}) VALUES ({}) ON CONFLICT (id) {};",
+                    change.table,
+                    columns.join(","),
+                    values.join(","),
+                    on_conflict,
+                )
+            }
+            _ => return None,
+        };
+
+        Some(statement)
+    }).collect()
+}
+
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+fn operation_to_string(operation: i32) -> &'static str {
+    match Operation::from_i32(operation) {
+        Some(Operation::Create) => "create",
+        Some(Operation::Update) => "update",
+        Some(Operation::Delete) => "delete",
+        _ => "unset",
+    }
+}
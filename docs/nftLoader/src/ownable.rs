@@ -0,0 +1,45 @@
+use prost_types::Timestamp;
+use substreams_ethereum::pb as ethpb;
+use substreams_ethereum::Event;
+
+use crate::abi::ownable::events::OwnershipTransferred as OwnershipTransferredEvent;
+use crate::pb::transfers;
+
+// Every `OwnershipTransferred` of the block, whether the contract is an NFT contract is decided
+// by the caller since it depends on the stores.
+pub fn transform_block_to_ownership_transfers(blk: &ethpb::eth::v2::Block) -> Vec<transfers::OwnershipTransfer> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts().flat_map(|receipt| {
+        let hash = &receipt.transaction.hash;
+        let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+        receipt.receipt.logs.iter().filter_map(move |log| {
+            OwnershipTransferredEvent::match_and_decode(log).map(|event| new_ownership_transfer(
+                hash,
+                log,
+                blk.number,
+                timestamp.clone(),
+                event,
+            ))
+        })
+    }).collect()
+}
+
+fn new_ownership_transfer(
+    hash: &[u8],
+    log: &ethpb::eth::v2::Log,
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+    event: OwnershipTransferredEvent,
+) -> transfers::OwnershipTransfer {
+    transfers::OwnershipTransfer {
+        contract_address: log.address.to_vec(),
+        previous_owner: event.previous_owner,
+        new_owner: event.new_owner,
+        tx_hash: hash.to_vec(),
+        block_number: block_number,
+        ordinal: log.block_index as u64,
+        timestamp: timestamp,
+    }
+}
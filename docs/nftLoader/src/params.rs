@@ -0,0 +1,72 @@
+use anyhow::anyhow;
+
+// ERC2309 ranges covering more tokens than this are written as a single `transfer_ranges` row
+// instead of being expanded into one transfer per token.
+const DEFAULT_MAX_CONSECUTIVE_TRANSFER_RANGE: u64 = 10_000;
+
+// The params of the modules, set in the `params` section of `substreams.yaml` or with `-p` as
+// `key=value` pairs separated by `&`. Every module reading transfers must be given the same
+// params, the stores and the outputs would otherwise disagree on the transfers of a block.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Params {
+    pub max_consecutive_transfer_range: u64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            max_consecutive_transfer_range: DEFAULT_MAX_CONSECUTIVE_TRANSFER_RANGE,
+        }
+    }
+}
+
+impl Params {
+    // Missing keys keep their default value, unknown ones are rejected so that a typo doesn't go
+    // unnoticed.
+    pub fn parse(input: &str) -> Result<Self, anyhow::Error> {
+        let mut params = Params::default();
+
+        for pair in input.split('&').map(str::trim).filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| anyhow!("param {} is not a key=value pair", pair))?;
+
+            match key.trim() {
+                "max_consecutive_transfer_range" => {
+                    params.max_consecutive_transfer_range = value
+                        .trim()
+                        .parse()
+                        .map_err(|err| anyhow!("invalid max_consecutive_transfer_range {}: {}", value, err))?;
+                }
+                _ => return Err(anyhow!("unknown param {}", key)),
+            }
+        }
+
+        Ok(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_defaults_missing_params() {
+        assert_eq!(Params::parse("").unwrap(), Params::default());
+        assert_eq!(Params::default().max_consecutive_transfer_range, 10_000);
+    }
+
+    #[test]
+    fn it_parses_the_max_consecutive_transfer_range() {
+        let params = Params::parse("max_consecutive_transfer_range=25").unwrap();
+
+        assert_eq!(params.max_consecutive_transfer_range, 25);
+    }
+
+    #[test]
+    fn it_rejects_invalid_params() {
+        assert!(Params::parse("max_consecutive_transfer_range=-1").is_err());
+        assert!(Params::parse("max_consecutive_transfer_range").is_err());
+        assert!(Params::parse("max_consecutive_transfers=25").is_err());
+    }
+}
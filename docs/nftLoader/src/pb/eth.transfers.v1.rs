@@ -22,8 +22,9 @@ pub struct Transfer {
     pub to_address: ::prost::alloc::vec::Vec<u8>,
     /// Operator is the "sender" of the actual transaction that initiated the transfer, it's equal to
     /// `msg.sender` within the smart contract. This will be different than `from` in case of `Approval`
-    /// for example. Only available if `schema = ERC1155`, for `ERC20` and `ERC721`, this will be the empty
-    /// string "".
+    /// for example. For `ERC1155`, it comes from the event. For `ERC721`, which has no operator in its
+    /// event, it's the caller of the call that emitted the event (delegate calls are skipped as they keep
+    /// the `msg.sender` of their parent). For `ERC20` and `cryptopunks`, this will be the empty string "".
     #[prost(bytes="vec", tag="5")]
     pub operator: ::prost::alloc::vec::Vec<u8>,
     /// How many token were transferred in this transfer, will always be 1 in the case of ERC721.
@@ -55,182 +56,2316 @@ pub mod transfer {
         Erc20 = 0,
         Erc721 = 1,
         Erc1155 = 2,
+        Cryptopunks = 3,
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Sales {
+    #[prost(message, repeated, tag="1")]
+    pub sales: ::prost::alloc::vec::Vec<Sale>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Sale {
+    /// Marketplace is the name of the protocol that settled the sale, for example "seaport".
+    #[prost(string, tag="1")]
+    pub marketplace: ::prost::alloc::string::String,
+    /// MarketplaceAddress is the address of the marketplace contract that emitted the sale event.
+    #[prost(bytes="vec", tag="2")]
+    pub marketplace_address: ::prost::alloc::vec::Vec<u8>,
+    /// OrderHash is the hash of the order that was fulfilled, empty when the marketplace has no
+    /// notion of orders.
+    #[prost(bytes="vec", tag="3")]
+    pub order_hash: ::prost::alloc::vec::Vec<u8>,
+    /// ContractAddress is the address of the NFT contract of the token that was sold.
+    #[prost(bytes="vec", tag="4")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// TokenID is the identifier of the token that was sold. When an order contains more than one
+    /// token (a bundle), this is the first token of the order and `item_count` holds the number of
+    /// tokens in the order.
+    #[prost(bytes="vec", tag="5")]
+    pub token_id: ::prost::alloc::vec::Vec<u8>,
+    /// How many tokens of `token_id` were sold, always 1 in the case of ERC721.
+    #[prost(string, tag="6")]
+    pub quantity: ::prost::alloc::string::String,
+    /// ItemCount is the number of NFT items that were traded in the order.
+    #[prost(uint64, tag="7")]
+    pub item_count: u64,
+    /// The account that gave the token away.
+    #[prost(bytes="vec", tag="8")]
+    pub seller: ::prost::alloc::vec::Vec<u8>,
+    /// The account that received the token.
+    #[prost(bytes="vec", tag="9")]
+    pub buyer: ::prost::alloc::vec::Vec<u8>,
+    /// PaymentToken is the address of the ERC20 used to pay for the sale, the zero address when
+    /// the sale was paid in ETH.
+    #[prost(bytes="vec", tag="10")]
+    pub payment_token: ::prost::alloc::vec::Vec<u8>,
+    /// Price is the total amount paid by the buyer, fees and royalties included, in the smallest
+    /// unit of `payment_token`. Empty when unknown, like a CryptoPunks bid entered before the start
+    /// block of the modules and accepted after it.
+    #[prost(string, tag="11")]
+    pub price: ::prost::alloc::string::String,
+    /// Payouts are the individual payment legs of the sale.
+    #[prost(message, repeated, tag="12")]
+    pub payouts: ::prost::alloc::vec::Vec<SalePayout>,
+    /// TxHash is the hash of the transaction
+    #[prost(bytes="vec", tag="13")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="14")]
+    pub block_number: u64,
+    /// Ordinal is the order of the sale within the block.
+    #[prost(uint64, tag="15")]
+    pub ordinal: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="16")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SalePayout {
+    /// Role is the string representation of one of the enum defined in Role, see `Transfer.schema`
+    /// for why it's a string.
+    #[prost(string, tag="1")]
+    pub role: ::prost::alloc::string::String,
+    /// The account that received this leg of the payment.
+    #[prost(bytes="vec", tag="2")]
+    pub recipient: ::prost::alloc::vec::Vec<u8>,
+    /// PaymentToken is the address of the ERC20 paid out, the zero address for ETH.
+    #[prost(bytes="vec", tag="3")]
+    pub payment_token: ::prost::alloc::vec::Vec<u8>,
+    /// Amount paid to `recipient`, in the smallest unit of `payment_token`.
+    #[prost(string, tag="4")]
+    pub amount: ::prost::alloc::string::String,
+}
+/// Nested message and enum types in `SalePayout`.
+pub mod sale_payout {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Role {
+        Seller = 0,
+        MarketplaceFee = 1,
+        Royalty = 2,
+        /// A recipient that is neither the seller nor a known marketplace fee collector, like a
+        /// creator, a third-party marketplace or a referrer, they can't be told apart from the event.
+        Other = 3,
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderCancellations {
+    #[prost(message, repeated, tag="1")]
+    pub order_cancellations: ::prost::alloc::vec::Vec<OrderCancellation>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderCancellation {
+    /// Kind is the string representation of one of the enum defined in Kind, see `Transfer.schema`
+    /// for why it's a string.
+    #[prost(string, tag="1")]
+    pub kind: ::prost::alloc::string::String,
+    /// Marketplace is the name of the protocol the orders belong to, for example "seaport".
+    #[prost(string, tag="2")]
+    pub marketplace: ::prost::alloc::string::String,
+    /// MarketplaceAddress is the address of the marketplace contract that emitted the event.
+    #[prost(bytes="vec", tag="3")]
+    pub marketplace_address: ::prost::alloc::vec::Vec<u8>,
+    /// OrderHash is the hash of the cancelled order, only available for `order_cancelled`.
+    #[prost(bytes="vec", tag="4")]
+    pub order_hash: ::prost::alloc::vec::Vec<u8>,
+    /// The account that signed the cancelled order(s).
+    #[prost(bytes="vec", tag="5")]
+    pub offerer: ::prost::alloc::vec::Vec<u8>,
+    /// Zone is the zone of the cancelled order, only available for `order_cancelled`.
+    #[prost(bytes="vec", tag="6")]
+    pub zone: ::prost::alloc::vec::Vec<u8>,
+    /// Counter is the new counter of `offerer`, only available for `counter_incremented`.
+    #[prost(string, tag="7")]
+    pub counter: ::prost::alloc::string::String,
+    /// TxHash is the hash of the transaction
+    #[prost(bytes="vec", tag="8")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="9")]
+    pub block_number: u64,
+    /// Ordinal is the order of the cancellation within the block.
+    #[prost(uint64, tag="10")]
+    pub ordinal: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="11")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Nested message and enum types in `OrderCancellation`.
+pub mod order_cancellation {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        /// A single order was cancelled, `order_hash` holds the hash of the order.
+        OrderCancelled = 0,
+        /// Every order of `offerer` signed with a counter lower than `counter` was cancelled.
+        CounterIncremented = 1,
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PunkOrders {
+    #[prost(message, repeated, tag="1")]
+    pub punk_orders: ::prost::alloc::vec::Vec<PunkOrder>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PunkOrder {
+    /// Kind is the string representation of one of the enum defined in Kind, see `Transfer.schema`
+    /// for why it's a string.
+    #[prost(string, tag="1")]
+    pub kind: ::prost::alloc::string::String,
+    /// ContractAddress is the address of the CryptoPunks market contract.
+    #[prost(bytes="vec", tag="2")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// TokenID is the index of the punk.
+    #[prost(bytes="vec", tag="3")]
+    pub token_id: ::prost::alloc::vec::Vec<u8>,
+    /// Value is the minimum price of an offer or the amount of a bid, in wei.
+    #[prost(string, tag="4")]
+    pub value: ::prost::alloc::string::String,
+    /// The account that entered the bid, only available for `bid_entered`.
+    #[prost(bytes="vec", tag="5")]
+    pub from_address: ::prost::alloc::vec::Vec<u8>,
+    /// The only account allowed to buy the punk, empty or the zero address when the offer is public,
+    /// only available for `offered`.
+    #[prost(bytes="vec", tag="6")]
+    pub to_address: ::prost::alloc::vec::Vec<u8>,
+    /// TxHash is the hash of the transaction
+    #[prost(bytes="vec", tag="7")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub block_number: u64,
+    /// Ordinal is the order of the event within the block.
+    #[prost(uint64, tag="9")]
+    pub ordinal: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="10")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Nested message and enum types in `PunkOrder`.
+pub mod punk_order {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        /// The owner of the punk offered it for sale for at least `value`, to anyone or only to `to_address`.
+        Offered = 0,
+        /// `from_address` entered a bid of `value` on the punk.
+        BidEntered = 1,
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferRanges {
+    #[prost(message, repeated, tag="1")]
+    pub transfer_ranges: ::prost::alloc::vec::Vec<TransferRange>,
+}
+/// TransferRange is an ERC2309 `ConsecutiveTransfer` covering too many tokens to be expanded into
+/// individual transfers.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferRange {
+    #[prost(bytes="vec", tag="1")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// The first token of the range, inclusive.
+    #[prost(bytes="vec", tag="2")]
+    pub from_token_id: ::prost::alloc::vec::Vec<u8>,
+    /// The last token of the range, inclusive.
+    #[prost(bytes="vec", tag="3")]
+    pub to_token_id: ::prost::alloc::vec::Vec<u8>,
+    /// The account that sent every token of the range, the zero address for mints.
+    #[prost(bytes="vec", tag="4")]
+    pub from_address: ::prost::alloc::vec::Vec<u8>,
+    /// The account that received every token of the range.
+    #[prost(bytes="vec", tag="5")]
+    pub to_address: ::prost::alloc::vec::Vec<u8>,
+    /// TxHash is the hash of the transaction
+    #[prost(bytes="vec", tag="6")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="7")]
+    pub block_number: u64,
+    /// Ordinal is the order of the event within the block.
+    #[prost(uint64, tag="8")]
+    pub ordinal: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="9")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MetadataRefreshes {
+    #[prost(message, repeated, tag="1")]
+    pub metadata_refreshes: ::prost::alloc::vec::Vec<MetadataRefresh>,
+}
+/// MetadataRefresh is an ERC4906 `MetadataUpdate` or `BatchMetadataUpdate`, the metadata of every
+/// token from `from_token_id` to `to_token_id` changed and should be fetched again.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MetadataRefresh {
+    #[prost(bytes="vec", tag="1")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// The first token to refresh, inclusive.
+    #[prost(bytes="vec", tag="2")]
+    pub from_token_id: ::prost::alloc::vec::Vec<u8>,
+    /// The last token to refresh, inclusive, same as `from_token_id` for a `MetadataUpdate`.
+    #[prost(bytes="vec", tag="3")]
+    pub to_token_id: ::prost::alloc::vec::Vec<u8>,
+    /// TxHash is the hash of the transaction
+    #[prost(bytes="vec", tag="4")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="5")]
+    pub block_number: u64,
+    /// Ordinal is the order of the event within the block.
+    #[prost(uint64, tag="6")]
+    pub ordinal: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="7")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenUsers {
+    #[prost(message, repeated, tag="1")]
+    pub token_users: ::prost::alloc::vec::Vec<TokenUser>,
+}
+/// TokenUser is the ERC4907 user of a token, the account allowed to use it without owning it until
+/// `expires`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenUser {
+    #[prost(bytes="vec", tag="1")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub token_id: ::prost::alloc::vec::Vec<u8>,
+    /// The account using the token, the zero address once the user was removed.
+    #[prost(bytes="vec", tag="3")]
+    pub user: ::prost::alloc::vec::Vec<u8>,
+    /// Expires is the unix timestamp at which `user` loses the right to use the token.
+    #[prost(uint64, tag="4")]
+    pub expires: u64,
+    /// TxHash is the hash of the transaction that last updated the user.
+    #[prost(bytes="vec", tag="5")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="6")]
+    pub block_number: u64,
+    /// Ordinal is the order of the event within the block.
+    #[prost(uint64, tag="7")]
+    pub ordinal: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="8")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenLocks {
+    #[prost(message, repeated, tag="1")]
+    pub token_locks: ::prost::alloc::vec::Vec<TokenLock>,
+}
+/// TokenLock is an ERC5192 `Locked` or `Unlocked`, a locked token can't be transferred.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenLock {
+    #[prost(bytes="vec", tag="1")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="2")]
+    pub token_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bool, tag="3")]
+    pub locked: bool,
+    /// TxHash is the hash of the transaction that locked or unlocked the token.
+    #[prost(bytes="vec", tag="4")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="5")]
+    pub block_number: u64,
+    /// Ordinal is the order of the event within the block.
+    #[prost(uint64, tag="6")]
+    pub ordinal: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="7")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenBoundAccounts {
+    #[prost(message, repeated, tag="1")]
+    pub token_bound_accounts: ::prost::alloc::vec::Vec<TokenBoundAccount>,
+}
+/// TokenBoundAccount is an ERC6551 account created by the canonical registry, the account is owned
+/// by whoever owns the token it's bound to.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TokenBoundAccount {
+    /// Account is the address of the account that was created.
+    #[prost(bytes="vec", tag="1")]
+    pub account: ::prost::alloc::vec::Vec<u8>,
+    /// Implementation is the address of the contract the account delegates its logic to.
+    #[prost(bytes="vec", tag="2")]
+    pub implementation: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="3")]
+    pub salt: ::prost::alloc::vec::Vec<u8>,
+    /// ChainID is the chain of the token the account is bound to, which can be another chain than
+    /// the one the account lives on.
+    #[prost(string, tag="4")]
+    pub chain_id: ::prost::alloc::string::String,
+    /// ContractAddress is the address of the NFT contract of the token the account is bound to.
+    #[prost(bytes="vec", tag="5")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes="vec", tag="6")]
+    pub token_id: ::prost::alloc::vec::Vec<u8>,
+    /// TxHash is the hash of the transaction
+    #[prost(bytes="vec", tag="7")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub block_number: u64,
+    /// Ordinal is the order of the event within the block.
+    #[prost(uint64, tag="9")]
+    pub ordinal: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="10")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContractClassifications {
+    #[prost(message, repeated, tag="1")]
+    pub contract_classifications: ::prost::alloc::vec::Vec<ContractClassification>,
+}
+/// ContractClassification is the standard detected in the code of a contract when it's deployed.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContractClassification {
+    /// Standard is the string representation of one of the enum defined in Standard, see
+    /// `Transfer.schema` for why it's a string.
+    #[prost(string, tag="1")]
+    pub standard: ::prost::alloc::string::String,
+    #[prost(bytes="vec", tag="2")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// Deployer is the account or contract that created the contract.
+    #[prost(bytes="vec", tag="3")]
+    pub deployer: ::prost::alloc::vec::Vec<u8>,
+    /// TxHash is the hash of the transaction
+    #[prost(bytes="vec", tag="4")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="5")]
+    pub block_number: u64,
+    /// Ordinal is the ordinal of the code change within the block.
+    #[prost(uint64, tag="6")]
+    pub ordinal: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="7")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Nested message and enum types in `ContractClassification`.
+pub mod contract_classification {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Standard {
+        /// No standard was recognized in the code, the contract can't be trusted to be a token.
+        Unknown = 0,
+        Erc20 = 1,
+        Erc721 = 2,
+        Erc1155 = 3,
+        /// The code delegates its logic to another contract and has no standard of its own.
+        Proxy = 4,
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContractUpgrades {
+    #[prost(message, repeated, tag="1")]
+    pub contract_upgrades: ::prost::alloc::vec::Vec<ContractUpgrade>,
+}
+/// ContractUpgrade is a change of the logic or of the admin of an EIP-1967 proxy.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ContractUpgrade {
+    /// Kind is the string representation of one of the enum defined in Kind, see `Transfer.schema`
+    /// for why it's a string.
+    #[prost(string, tag="1")]
+    pub kind: ::prost::alloc::string::String,
+    /// Source is "event" when the change comes from an `Upgraded` or `AdminChanged` event, "storage"
+    /// when it comes from a write to the EIP-1967 implementation slot without the matching event.
+    #[prost(string, tag="2")]
+    pub source: ::prost::alloc::string::String,
+    /// ContractAddress is the address of the proxy.
+    #[prost(bytes="vec", tag="3")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// Implementation is the new logic contract, only available for `upgraded`.
+    #[prost(bytes="vec", tag="4")]
+    pub implementation: ::prost::alloc::vec::Vec<u8>,
+    /// Only available for `admin_changed`.
+    #[prost(bytes="vec", tag="5")]
+    pub previous_admin: ::prost::alloc::vec::Vec<u8>,
+    /// Only available for `admin_changed`.
+    #[prost(bytes="vec", tag="6")]
+    pub new_admin: ::prost::alloc::vec::Vec<u8>,
+    /// TxHash is the hash of the transaction
+    #[prost(bytes="vec", tag="7")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub block_number: u64,
+    /// Ordinal is the block index of the event, or the ordinal of the storage change.
+    #[prost(uint64, tag="9")]
+    pub ordinal: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="10")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Nested message and enum types in `ContractUpgrade`.
+pub mod contract_upgrade {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        /// The proxy now delegates to `implementation`.
+        Upgraded = 0,
+        /// The admin allowed to upgrade the proxy changed from `previous_admin` to `new_admin`.
+        AdminChanged = 1,
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OwnershipTransfers {
+    #[prost(message, repeated, tag="1")]
+    pub ownership_transfers: ::prost::alloc::vec::Vec<OwnershipTransfer>,
+}
+/// OwnershipTransfer is an Ownable `OwnershipTransferred` emitted by an NFT contract, the owner is
+/// the account marketplaces trust to configure the collection.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OwnershipTransfer {
+    #[prost(bytes="vec", tag="1")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// The owner before the transfer, the zero address when the contract was just deployed.
+    #[prost(bytes="vec", tag="2")]
+    pub previous_owner: ::prost::alloc::vec::Vec<u8>,
+    /// The owner after the transfer, the zero address when ownership was renounced.
+    #[prost(bytes="vec", tag="3")]
+    pub new_owner: ::prost::alloc::vec::Vec<u8>,
+    /// TxHash is the hash of the transaction
+    #[prost(bytes="vec", tag="4")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="5")]
+    pub block_number: u64,
+    /// Ordinal is the order of the event within the block.
+    #[prost(uint64, tag="6")]
+    pub ordinal: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="7")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transactions {
+    #[prost(message, repeated, tag="1")]
+    pub transactions: ::prost::alloc::vec::Vec<Transaction>,
+}
+/// Transaction is a transaction that contained at least one transfer or sale.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Transaction {
+    /// Hash is the hash of the transaction
+    #[prost(bytes="vec", tag="1")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    /// The externally owned account that signed and sent the transaction.
+    #[prost(bytes="vec", tag="2")]
+    pub from_address: ::prost::alloc::vec::Vec<u8>,
+    /// The account or contract the transaction was sent to, empty when it deployed a contract.
+    #[prost(bytes="vec", tag="3")]
+    pub to_address: ::prost::alloc::vec::Vec<u8>,
+    /// Value is the amount of ETH sent with the transaction, in wei.
+    #[prost(string, tag="4")]
+    pub value: ::prost::alloc::string::String,
+    #[prost(uint64, tag="5")]
+    pub gas_used: u64,
+    /// EffectiveGasPrice is the price paid per unit of gas, in wei, base fee included.
+    #[prost(string, tag="6")]
+    pub effective_gas_price: ::prost::alloc::string::String,
+    #[prost(uint64, tag="7")]
+    pub block_number: u64,
+    /// Index is the position of the transaction within the block.
+    #[prost(uint32, tag="8")]
+    pub index: u32,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="9")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Approvals {
+    #[prost(message, repeated, tag="1")]
+    pub approvals: ::prost::alloc::vec::Vec<Approval>,
+}
+/// Approval is an ERC721 `Approval` or an ERC721/ERC1155 `ApprovalForAll`, the two standards
+/// share the signature of the latter.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Approval {
+    /// Kind is the string representation of one of the enum defined in Kind, see `Transfer.schema`
+    /// for why it's a string.
+    #[prost(string, tag="1")]
+    pub kind: ::prost::alloc::string::String,
+    /// ContractAddress is the address of the NFT contract that emitted the event.
+    #[prost(bytes="vec", tag="2")]
+    pub contract_address: ::prost::alloc::vec::Vec<u8>,
+    /// The account that owns the token or the tokens.
+    #[prost(bytes="vec", tag="3")]
+    pub owner: ::prost::alloc::vec::Vec<u8>,
+    /// Spender is the approved account for `approval`, the operator for `approval_for_all`.
+    #[prost(bytes="vec", tag="4")]
+    pub spender: ::prost::alloc::vec::Vec<u8>,
+    /// TokenID is the identifier of the approved token, only available for `approval`.
+    #[prost(bytes="vec", tag="5")]
+    pub token_id: ::prost::alloc::vec::Vec<u8>,
+    /// Approved is false when an `approval_for_all` revokes the operator, always true for `approval`.
+    #[prost(bool, tag="6")]
+    pub approved: bool,
+    /// TxHash is the hash of the transaction
+    #[prost(bytes="vec", tag="7")]
+    pub tx_hash: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag="8")]
+    pub block_number: u64,
+    /// Ordinal is the order of the event within the block.
+    #[prost(uint64, tag="9")]
+    pub ordinal: u64,
+    /// Timestamp is the timestamp of the block.
+    #[prost(message, optional, tag="10")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Nested message and enum types in `Approval`.
+pub mod approval {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+    #[repr(i32)]
+    pub enum Kind {
+        /// `owner` approved `spender` to transfer `token_id`, a zero `spender` clears the approval.
+        Approval = 0,
+        /// `owner` approved or revoked `spender` as an operator of all its tokens of the contract.
+        ApprovalForAll = 1,
     }
 }
 /// Encoded file descriptor set for the `eth.transfers.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0xc7, 0x15, 0x0a, 0x0f, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e, 0x70,
-    0x72, 0x6f, 0x74, 0x6f, 0x12, 0x10, 0x65, 0x74, 0x68, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
-    0x65, 0x72, 0x73, 0x2e, 0x76, 0x31, 0x1a, 0x1f, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70,
-    0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2f, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
-    0x70, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x45, 0x0a, 0x09, 0x54, 0x72, 0x61, 0x6e, 0x73,
-    0x66, 0x65, 0x72, 0x73, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
-    0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x74, 0x72,
-    0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73,
-    0x66, 0x65, 0x72, 0x52, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x22, 0xa0,
-    0x03, 0x0a, 0x08, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x12, 0x16, 0x0a, 0x06, 0x73,
-    0x63, 0x68, 0x65, 0x6d, 0x61, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x73, 0x63, 0x68,
-    0x65, 0x6d, 0x61, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d,
-    0x62, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
-    0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x21, 0x0a, 0x0c, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x61,
-    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0b, 0x66, 0x72,
-    0x6f, 0x6d, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x74, 0x6f, 0x5f,
-    0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x74,
-    0x6f, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x1a, 0x0a, 0x08, 0x6f, 0x70, 0x65, 0x72,
-    0x61, 0x74, 0x6f, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x6f, 0x70, 0x65, 0x72,
-    0x61, 0x74, 0x6f, 0x72, 0x12, 0x1a, 0x0a, 0x08, 0x71, 0x75, 0x61, 0x6e, 0x74, 0x69, 0x74, 0x79,
-    0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x71, 0x75, 0x61, 0x6e, 0x74, 0x69, 0x74, 0x79,
-    0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x07, 0x20, 0x01,
-    0x28, 0x0c, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x29, 0x0a, 0x10, 0x63,
-    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18,
-    0x08, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41,
-    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68, 0x61, 0x73,
-    0x68, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x74, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12,
-    0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x04,
-    0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d,
-    0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67,
-    0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54,
-    0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74,
-    0x61, 0x6d, 0x70, 0x22, 0x2c, 0x0a, 0x06, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x12, 0x09, 0x0a,
-    0x05, 0x65, 0x72, 0x63, 0x32, 0x30, 0x10, 0x00, 0x12, 0x0a, 0x0a, 0x06, 0x65, 0x72, 0x63, 0x37,
-    0x32, 0x31, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x65, 0x72, 0x63, 0x31, 0x31, 0x35, 0x35, 0x10,
-    0x02, 0x4a, 0x8e, 0x11, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x38, 0x01, 0x0a, 0x08, 0x0a, 0x01,
-    0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x19,
-    0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x29, 0x0a, 0x0a, 0x0a, 0x02, 0x04,
-    0x00, 0x12, 0x04, 0x06, 0x00, 0x08, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03,
-    0x06, 0x08, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x07, 0x02, 0x22,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03, 0x07, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x07, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x00, 0x03, 0x12, 0x03, 0x07, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04,
-    0x0a, 0x00, 0x38, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x10,
-    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x04, 0x00, 0x12, 0x04, 0x0b, 0x02, 0x0f, 0x03, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x04, 0x00, 0x01, 0x12, 0x03, 0x0b, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06,
-    0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0c, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
-    0x01, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0c, 0x04, 0x09, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
-    0x01, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x0c, 0x0c, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
-    0x01, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01,
-    0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01,
-    0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x0d, 0x0d, 0x0e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01,
-    0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x0e, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04,
-    0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0e, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04,
-    0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x0e, 0x0e, 0x0f, 0x0a, 0xed, 0x01, 0x0a, 0x04, 0x04, 0x01,
-    0x02, 0x00, 0x12, 0x03, 0x14, 0x02, 0x14, 0x1a, 0xdf, 0x01, 0x20, 0x53, 0x63, 0x68, 0x65, 0x6d,
-    0x61, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20,
+    0x0a, 0xa7, 0xda, 0x01, 0x0a, 0x0f, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e,
+    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x10, 0x65, 0x74, 0x68, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x73, 0x2e, 0x76, 0x31, 0x1a, 0x1f, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f,
+    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2f, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x22, 0x45, 0x0a, 0x09, 0x54, 0x72, 0x61, 0x6e,
+    0x73, 0x66, 0x65, 0x72, 0x73, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65,
+    0x72, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x74,
+    0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x72, 0x61, 0x6e,
+    0x73, 0x66, 0x65, 0x72, 0x52, 0x09, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x22,
+    0xb1, 0x03, 0x0a, 0x08, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x12, 0x16, 0x0a, 0x06,
+    0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x73, 0x63,
+    0x68, 0x65, 0x6d, 0x61, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75,
+    0x6d, 0x62, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63,
+    0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x21, 0x0a, 0x0c, 0x66, 0x72, 0x6f, 0x6d, 0x5f,
+    0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0b, 0x66,
+    0x72, 0x6f, 0x6d, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x74, 0x6f,
+    0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09,
+    0x74, 0x6f, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x1a, 0x0a, 0x08, 0x6f, 0x70, 0x65,
+    0x72, 0x61, 0x74, 0x6f, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x6f, 0x70, 0x65,
+    0x72, 0x61, 0x74, 0x6f, 0x72, 0x12, 0x1a, 0x0a, 0x08, 0x71, 0x75, 0x61, 0x6e, 0x74, 0x69, 0x74,
+    0x79, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x71, 0x75, 0x61, 0x6e, 0x74, 0x69, 0x74,
+    0x79, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x07, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x29, 0x0a, 0x10,
+    0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x18, 0x08, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
+    0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68, 0x61,
+    0x73, 0x68, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x74, 0x78, 0x48, 0x61, 0x73, 0x68,
+    0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x0a, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69,
+    0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e,
+    0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e,
+    0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73,
+    0x74, 0x61, 0x6d, 0x70, 0x22, 0x3d, 0x0a, 0x06, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x12, 0x09,
+    0x0a, 0x05, 0x65, 0x72, 0x63, 0x32, 0x30, 0x10, 0x00, 0x12, 0x0a, 0x0a, 0x06, 0x65, 0x72, 0x63,
+    0x37, 0x32, 0x31, 0x10, 0x01, 0x12, 0x0b, 0x0a, 0x07, 0x65, 0x72, 0x63, 0x31, 0x31, 0x35, 0x35,
+    0x10, 0x02, 0x12, 0x0f, 0x0a, 0x0b, 0x63, 0x72, 0x79, 0x70, 0x74, 0x6f, 0x70, 0x75, 0x6e, 0x6b,
+    0x73, 0x10, 0x03, 0x22, 0x35, 0x0a, 0x05, 0x53, 0x61, 0x6c, 0x65, 0x73, 0x12, 0x2c, 0x0a, 0x05,
+    0x73, 0x61, 0x6c, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x16, 0x2e, 0x65, 0x74,
+    0x68, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x53,
+    0x61, 0x6c, 0x65, 0x52, 0x05, 0x73, 0x61, 0x6c, 0x65, 0x73, 0x22, 0xaa, 0x04, 0x0a, 0x04, 0x53,
+    0x61, 0x6c, 0x65, 0x12, 0x20, 0x0a, 0x0b, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61,
+    0x63, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74,
+    0x70, 0x6c, 0x61, 0x63, 0x65, 0x12, 0x2f, 0x0a, 0x13, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70,
+    0x6c, 0x61, 0x63, 0x65, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x12, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x41,
+    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x5f,
+    0x68, 0x61, 0x73, 0x68, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x6f, 0x72, 0x64, 0x65,
+    0x72, 0x48, 0x61, 0x73, 0x68, 0x12, 0x29, 0x0a, 0x10, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63,
+    0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x71,
+    0x75, 0x61, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x71,
+    0x75, 0x61, 0x6e, 0x74, 0x69, 0x74, 0x79, 0x12, 0x1d, 0x0a, 0x0a, 0x69, 0x74, 0x65, 0x6d, 0x5f,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52, 0x09, 0x69, 0x74, 0x65,
+    0x6d, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x16, 0x0a, 0x06, 0x73, 0x65, 0x6c, 0x6c, 0x65, 0x72,
+    0x18, 0x08, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x73, 0x65, 0x6c, 0x6c, 0x65, 0x72, 0x12, 0x14,
+    0x0a, 0x05, 0x62, 0x75, 0x79, 0x65, 0x72, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x62,
+    0x75, 0x79, 0x65, 0x72, 0x12, 0x23, 0x0a, 0x0d, 0x70, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74, 0x5f,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0c, 0x70, 0x61, 0x79,
+    0x6d, 0x65, 0x6e, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x70, 0x72, 0x69,
+    0x63, 0x65, 0x18, 0x0b, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x70, 0x72, 0x69, 0x63, 0x65, 0x12,
+    0x36, 0x0a, 0x07, 0x70, 0x61, 0x79, 0x6f, 0x75, 0x74, 0x73, 0x18, 0x0c, 0x20, 0x03, 0x28, 0x0b,
+    0x32, 0x1c, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73,
+    0x2e, 0x76, 0x31, 0x2e, 0x53, 0x61, 0x6c, 0x65, 0x50, 0x61, 0x79, 0x6f, 0x75, 0x74, 0x52, 0x07,
+    0x70, 0x61, 0x79, 0x6f, 0x75, 0x74, 0x73, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68, 0x61,
+    0x73, 0x68, 0x18, 0x0d, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x74, 0x78, 0x48, 0x61, 0x73, 0x68,
+    0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72,
+    0x18, 0x0e, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d,
+    0x62, 0x65, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x0f,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x38, 0x0a,
+    0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x10, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62,
+    0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69,
+    0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x22, 0xb1, 0x01, 0x0a, 0x0a, 0x53, 0x61, 0x6c, 0x65,
+    0x50, 0x61, 0x79, 0x6f, 0x75, 0x74, 0x12, 0x12, 0x0a, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x72, 0x6f, 0x6c, 0x65, 0x12, 0x1c, 0x0a, 0x09, 0x72, 0x65,
+    0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x72,
+    0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e, 0x74, 0x12, 0x23, 0x0a, 0x0d, 0x70, 0x61, 0x79, 0x6d,
+    0x65, 0x6e, 0x74, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x0c, 0x70, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x16, 0x0a,
+    0x06, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06, 0x61,
+    0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x22, 0x34, 0x0a, 0x04, 0x52, 0x6f, 0x6c, 0x65, 0x12, 0x0a, 0x0a,
+    0x06, 0x73, 0x65, 0x6c, 0x6c, 0x65, 0x72, 0x10, 0x00, 0x12, 0x13, 0x0a, 0x0f, 0x6d, 0x61, 0x72,
+    0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x5f, 0x66, 0x65, 0x65, 0x10, 0x01, 0x12, 0x0b,
+    0x0a, 0x07, 0x72, 0x6f, 0x79, 0x61, 0x6c, 0x74, 0x79, 0x10, 0x02, 0x22, 0x6a, 0x0a, 0x12, 0x4f,
+    0x72, 0x64, 0x65, 0x72, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x73, 0x12, 0x54, 0x0a, 0x13, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x5f, 0x63, 0x61, 0x6e, 0x63, 0x65,
+    0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x23,
+    0x2e, 0x65, 0x74, 0x68, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e, 0x76,
+    0x31, 0x2e, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x61, 0x74,
+    0x69, 0x6f, 0x6e, 0x52, 0x12, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c,
+    0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x22, 0xa7, 0x03, 0x0a, 0x11, 0x4f, 0x72, 0x64, 0x65,
+    0x72, 0x43, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x12, 0x0a,
+    0x04, 0x6b, 0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6b, 0x69, 0x6e,
+    0x64, 0x12, 0x20, 0x0a, 0x0b, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0b, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c,
+    0x61, 0x63, 0x65, 0x12, 0x2f, 0x0a, 0x13, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61,
+    0x63, 0x65, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c,
+    0x52, 0x12, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x41, 0x64, 0x64,
+    0x72, 0x65, 0x73, 0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x5f, 0x68, 0x61,
+    0x73, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x48,
+    0x61, 0x73, 0x68, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x65, 0x72, 0x18, 0x05,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x65, 0x72, 0x12, 0x12, 0x0a,
+    0x04, 0x7a, 0x6f, 0x6e, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x7a, 0x6f, 0x6e,
+    0x65, 0x12, 0x18, 0x0a, 0x07, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x72, 0x18, 0x07, 0x20, 0x01,
+    0x28, 0x09, 0x52, 0x07, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x72, 0x12, 0x17, 0x0a, 0x07, 0x74,
+    0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x08, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x74, 0x78,
+    0x48, 0x61, 0x73, 0x68, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75,
+    0x6d, 0x62, 0x65, 0x72, 0x18, 0x09, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63,
+    0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61,
+    0x6c, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x0b,
+    0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72,
+    0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70,
+    0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x22, 0x34, 0x0a, 0x04, 0x4b,
+    0x69, 0x6e, 0x64, 0x12, 0x13, 0x0a, 0x0f, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x5f, 0x63, 0x61, 0x6e,
+    0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x10, 0x00, 0x12, 0x17, 0x0a, 0x13, 0x63, 0x6f, 0x75, 0x6e,
+    0x74, 0x65, 0x72, 0x5f, 0x69, 0x6e, 0x63, 0x72, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x65, 0x64, 0x10,
+    0x01, 0x22, 0x4a, 0x0a, 0x0a, 0x50, 0x75, 0x6e, 0x6b, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x12,
+    0x3c, 0x0a, 0x0b, 0x70, 0x75, 0x6e, 0x6b, 0x5f, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x18, 0x01,
+    0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x50, 0x75, 0x6e, 0x6b, 0x4f, 0x72, 0x64, 0x65,
+    0x72, 0x52, 0x0a, 0x70, 0x75, 0x6e, 0x6b, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x22, 0xf3, 0x02,
+    0x0a, 0x09, 0x50, 0x75, 0x6e, 0x6b, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x12, 0x12, 0x0a, 0x04, 0x6b,
+    0x69, 0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12,
+    0x29, 0x0a, 0x10, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72,
+    0x65, 0x73, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x04,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x12, 0x21, 0x0a, 0x0c, 0x66,
+    0x72, 0x6f, 0x6d, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x0b, 0x66, 0x72, 0x6f, 0x6d, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x1d,
+    0x0a, 0x0a, 0x74, 0x6f, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x06, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x09, 0x74, 0x6f, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x17, 0x0a,
+    0x07, 0x74, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06,
+    0x74, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f,
+    0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64,
+    0x69, 0x6e, 0x61, 0x6c, 0x18, 0x09, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69,
+    0x6e, 0x61, 0x6c, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70,
+    0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e,
+    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x22, 0x24, 0x0a,
+    0x04, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x0b, 0x0a, 0x07, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x65, 0x64,
+    0x10, 0x00, 0x12, 0x0f, 0x0a, 0x0b, 0x62, 0x69, 0x64, 0x5f, 0x65, 0x6e, 0x74, 0x65, 0x72, 0x65,
+    0x64, 0x10, 0x01, 0x22, 0x5a, 0x0a, 0x0e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x52,
+    0x61, 0x6e, 0x67, 0x65, 0x73, 0x12, 0x48, 0x0a, 0x0f, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65,
+    0x72, 0x5f, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1f,
+    0x2e, 0x65, 0x74, 0x68, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e, 0x76,
+    0x31, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x52,
+    0x0e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x73, 0x22,
+    0xd0, 0x02, 0x0a, 0x0d, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x52, 0x61, 0x6e, 0x67,
+    0x65, 0x12, 0x29, 0x0a, 0x10, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61, 0x64,
+    0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x6f, 0x6e,
+    0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x22, 0x0a, 0x0d,
+    0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x0b, 0x66, 0x72, 0x6f, 0x6d, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64,
+    0x12, 0x1e, 0x0a, 0x0b, 0x74, 0x6f, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x74, 0x6f, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64,
+    0x12, 0x21, 0x0a, 0x0c, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0b, 0x66, 0x72, 0x6f, 0x6d, 0x41, 0x64, 0x64, 0x72,
+    0x65, 0x73, 0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x74, 0x6f, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73,
+    0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x74, 0x6f, 0x41, 0x64, 0x64, 0x72, 0x65,
+    0x73, 0x73, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x06, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x06, 0x74, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x21, 0x0a, 0x0c, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x07, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x18,
+    0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52,
+    0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65,
+    0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f,
+    0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69,
+    0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x22, 0x65, 0x0a, 0x11, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x52, 0x65,
+    0x66, 0x72, 0x65, 0x73, 0x68, 0x65, 0x73, 0x12, 0x50, 0x0a, 0x12, 0x6d, 0x65, 0x74, 0x61, 0x64,
+    0x61, 0x74, 0x61, 0x5f, 0x72, 0x65, 0x66, 0x72, 0x65, 0x73, 0x68, 0x65, 0x73, 0x18, 0x01, 0x20,
+    0x03, 0x28, 0x0b, 0x32, 0x21, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x52,
+    0x65, 0x66, 0x72, 0x65, 0x73, 0x68, 0x52, 0x11, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61,
+    0x52, 0x65, 0x66, 0x72, 0x65, 0x73, 0x68, 0x65, 0x73, 0x22, 0x90, 0x02, 0x0a, 0x0f, 0x4d, 0x65,
+    0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x52, 0x65, 0x66, 0x72, 0x65, 0x73, 0x68, 0x12, 0x29, 0x0a,
+    0x10, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73,
+    0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63,
+    0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x22, 0x0a, 0x0d, 0x66, 0x72, 0x6f, 0x6d,
+    0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x0b, 0x66, 0x72, 0x6f, 0x6d, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x1e, 0x0a, 0x0b,
+    0x74, 0x6f, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x09, 0x74, 0x6f, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x17, 0x0a, 0x07,
+    0x74, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x74,
+    0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e,
+    0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f,
+    0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69,
+    0x6e, 0x61, 0x6c, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18,
+    0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70,
+    0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
+    0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x22, 0x4a, 0x0a, 0x0a,
+    0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x55, 0x73, 0x65, 0x72, 0x73, 0x12, 0x3c, 0x0a, 0x0b, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x5f, 0x75, 0x73, 0x65, 0x72, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32,
+    0x1b, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e,
+    0x76, 0x31, 0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x55, 0x73, 0x65, 0x72, 0x52, 0x0a, 0x74, 0x6f,
+    0x6b, 0x65, 0x6e, 0x55, 0x73, 0x65, 0x72, 0x73, 0x22, 0x8f, 0x02, 0x0a, 0x09, 0x54, 0x6f, 0x6b,
+    0x65, 0x6e, 0x55, 0x73, 0x65, 0x72, 0x12, 0x29, 0x0a, 0x10, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
+    0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c,
+    0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73,
+    0x73, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x12, 0x0a, 0x04,
+    0x75, 0x73, 0x65, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x75, 0x73, 0x65, 0x72,
+    0x12, 0x18, 0x0a, 0x07, 0x65, 0x78, 0x70, 0x69, 0x72, 0x65, 0x73, 0x18, 0x04, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x07, 0x65, 0x78, 0x70, 0x69, 0x72, 0x65, 0x73, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78,
+    0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x74, 0x78, 0x48,
+    0x61, 0x73, 0x68, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d,
+    0x62, 0x65, 0x72, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61,
+    0x6c, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c,
+    0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x08, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52,
+    0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x22, 0x4a, 0x0a, 0x0a, 0x54, 0x6f,
+    0x6b, 0x65, 0x6e, 0x4c, 0x6f, 0x63, 0x6b, 0x73, 0x12, 0x3c, 0x0a, 0x0b, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x5f, 0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1b, 0x2e,
+    0x65, 0x74, 0x68, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e, 0x76, 0x31,
+    0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x4c, 0x6f, 0x63, 0x6b, 0x52, 0x0a, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x4c, 0x6f, 0x63, 0x6b, 0x73, 0x22, 0xf9, 0x01, 0x0a, 0x09, 0x54, 0x6f, 0x6b, 0x65, 0x6e,
+    0x4c, 0x6f, 0x63, 0x6b, 0x12, 0x29, 0x0a, 0x10, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
+    0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f,
+    0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12,
+    0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x02, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x16, 0x0a, 0x06, 0x6c, 0x6f,
+    0x63, 0x6b, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x08, 0x52, 0x06, 0x6c, 0x6f, 0x63, 0x6b,
+    0x65, 0x64, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x04, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x06, 0x74, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x21, 0x0a, 0x0c, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28,
+    0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x18,
+    0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52,
+    0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65,
+    0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f,
+    0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69,
+    0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x22, 0x6b, 0x0a, 0x12, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x42, 0x6f, 0x75, 0x6e, 0x64,
+    0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x12, 0x55, 0x0a, 0x14, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x5f, 0x62, 0x6f, 0x75, 0x6e, 0x64, 0x5f, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x73,
+    0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x74, 0x72, 0x61,
+    0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x42,
+    0x6f, 0x75, 0x6e, 0x64, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x52, 0x12, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x42, 0x6f, 0x75, 0x6e, 0x64, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x73, 0x22,
+    0xda, 0x02, 0x0a, 0x11, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x42, 0x6f, 0x75, 0x6e, 0x64, 0x41, 0x63,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12, 0x18, 0x0a, 0x07, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x12,
+    0x26, 0x0a, 0x0e, 0x69, 0x6d, 0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0e, 0x69, 0x6d, 0x70, 0x6c, 0x65, 0x6d, 0x65,
+    0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x12, 0x0a, 0x04, 0x73, 0x61, 0x6c, 0x74, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x73, 0x61, 0x6c, 0x74, 0x12, 0x19, 0x0a, 0x08, 0x63,
+    0x68, 0x61, 0x69, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x07, 0x63,
+    0x68, 0x61, 0x69, 0x6e, 0x49, 0x64, 0x12, 0x29, 0x0a, 0x10, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
+    0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c,
+    0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73,
+    0x73, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x18, 0x06, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x64, 0x12, 0x17, 0x0a, 0x07,
+    0x74, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x74,
+    0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e,
+    0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f,
+    0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69,
+    0x6e, 0x61, 0x6c, 0x18, 0x09, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18,
+    0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70,
+    0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
+    0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x22, 0x7e, 0x0a, 0x17,
+    0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x43, 0x6c, 0x61, 0x73, 0x73, 0x69, 0x66, 0x69,
+    0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x63, 0x0a, 0x18, 0x63, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x5f, 0x63, 0x6c, 0x61, 0x73, 0x73, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x28, 0x2e, 0x65, 0x74, 0x68, 0x2e,
+    0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6e,
+    0x74, 0x72, 0x61, 0x63, 0x74, 0x43, 0x6c, 0x61, 0x73, 0x73, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74,
+    0x69, 0x6f, 0x6e, 0x52, 0x17, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x43, 0x6c, 0x61,
+    0x73, 0x73, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x22, 0xd3, 0x02, 0x0a,
+    0x16, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x43, 0x6c, 0x61, 0x73, 0x73, 0x69, 0x66,
+    0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x1a, 0x0a, 0x08, 0x73, 0x74, 0x61, 0x6e, 0x64,
+    0x61, 0x72, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x73, 0x74, 0x61, 0x6e, 0x64,
+    0x61, 0x72, 0x64, 0x12, 0x29, 0x0a, 0x10, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f,
+    0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63,
+    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x1a,
+    0x0a, 0x08, 0x64, 0x65, 0x70, 0x6c, 0x6f, 0x79, 0x65, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c,
+    0x52, 0x08, 0x64, 0x65, 0x70, 0x6c, 0x6f, 0x79, 0x65, 0x72, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78,
+    0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x74, 0x78, 0x48,
+    0x61, 0x73, 0x68, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d,
+    0x62, 0x65, 0x72, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61,
+    0x6c, 0x18, 0x06, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c,
+    0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x07, 0x20,
+    0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52,
+    0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x22, 0x46, 0x0a, 0x08, 0x53, 0x74,
+    0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x12, 0x0b, 0x0a, 0x07, 0x75, 0x6e, 0x6b, 0x6e, 0x6f, 0x77,
+    0x6e, 0x10, 0x00, 0x12, 0x09, 0x0a, 0x05, 0x65, 0x72, 0x63, 0x32, 0x30, 0x10, 0x01, 0x12, 0x0a,
+    0x0a, 0x06, 0x65, 0x72, 0x63, 0x37, 0x32, 0x31, 0x10, 0x02, 0x12, 0x0b, 0x0a, 0x07, 0x65, 0x72,
+    0x63, 0x31, 0x31, 0x35, 0x35, 0x10, 0x03, 0x12, 0x09, 0x0a, 0x05, 0x70, 0x72, 0x6f, 0x78, 0x79,
+    0x10, 0x04, 0x22, 0x62, 0x0a, 0x10, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x55, 0x70,
+    0x67, 0x72, 0x61, 0x64, 0x65, 0x73, 0x12, 0x4e, 0x0a, 0x11, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
+    0x63, 0x74, 0x5f, 0x75, 0x70, 0x67, 0x72, 0x61, 0x64, 0x65, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28,
+    0x0b, 0x32, 0x21, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
+    0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x55, 0x70, 0x67,
+    0x72, 0x61, 0x64, 0x65, 0x52, 0x10, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x55, 0x70,
+    0x67, 0x72, 0x61, 0x64, 0x65, 0x73, 0x22, 0x8d, 0x03, 0x0a, 0x0f, 0x43, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x55, 0x70, 0x67, 0x72, 0x61, 0x64, 0x65, 0x12, 0x12, 0x0a, 0x04, 0x6b, 0x69,
+    0x6e, 0x64, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x16,
+    0x0a, 0x06, 0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x06,
+    0x73, 0x6f, 0x75, 0x72, 0x63, 0x65, 0x12, 0x29, 0x0a, 0x10, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
+    0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c,
+    0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73,
+    0x73, 0x12, 0x26, 0x0a, 0x0e, 0x69, 0x6d, 0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x61, 0x74,
+    0x69, 0x6f, 0x6e, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0e, 0x69, 0x6d, 0x70, 0x6c, 0x65,
+    0x6d, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x25, 0x0a, 0x0e, 0x70, 0x72, 0x65,
+    0x76, 0x69, 0x6f, 0x75, 0x73, 0x5f, 0x61, 0x64, 0x6d, 0x69, 0x6e, 0x18, 0x05, 0x20, 0x01, 0x28,
+    0x0c, 0x52, 0x0d, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x41, 0x64, 0x6d, 0x69, 0x6e,
+    0x12, 0x1b, 0x0a, 0x09, 0x6e, 0x65, 0x77, 0x5f, 0x61, 0x64, 0x6d, 0x69, 0x6e, 0x18, 0x06, 0x20,
+    0x01, 0x28, 0x0c, 0x52, 0x08, 0x6e, 0x65, 0x77, 0x41, 0x64, 0x6d, 0x69, 0x6e, 0x12, 0x17, 0x0a,
+    0x07, 0x74, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06,
+    0x74, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f,
+    0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64,
+    0x69, 0x6e, 0x61, 0x6c, 0x18, 0x09, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69,
+    0x6e, 0x61, 0x6c, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70,
+    0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e,
+    0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x22, 0x27, 0x0a,
+    0x04, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x0c, 0x0a, 0x08, 0x75, 0x70, 0x67, 0x72, 0x61, 0x64, 0x65,
+    0x64, 0x10, 0x00, 0x12, 0x11, 0x0a, 0x0d, 0x61, 0x64, 0x6d, 0x69, 0x6e, 0x5f, 0x63, 0x68, 0x61,
+    0x6e, 0x67, 0x65, 0x64, 0x10, 0x01, 0x22, 0x6a, 0x0a, 0x12, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x73,
+    0x68, 0x69, 0x70, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x12, 0x54, 0x0a, 0x13,
+    0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x5f, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x65, 0x74, 0x68, 0x2e,
+    0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x4f, 0x77, 0x6e,
+    0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x52, 0x12,
+    0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65,
+    0x72, 0x73, 0x22, 0x92, 0x02, 0x0a, 0x11, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70,
+    0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x12, 0x29, 0x0a, 0x10, 0x63, 0x6f, 0x6e, 0x74,
+    0x72, 0x61, 0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x01, 0x20, 0x01,
+    0x28, 0x0c, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72,
+    0x65, 0x73, 0x73, 0x12, 0x25, 0x0a, 0x0e, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x5f,
+    0x6f, 0x77, 0x6e, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0d, 0x70, 0x72, 0x65,
+    0x76, 0x69, 0x6f, 0x75, 0x73, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x12, 0x1b, 0x0a, 0x09, 0x6e, 0x65,
+    0x77, 0x5f, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x08, 0x6e,
+    0x65, 0x77, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x12, 0x17, 0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68, 0x61,
+    0x73, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x06, 0x74, 0x78, 0x48, 0x61, 0x73, 0x68,
+    0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72,
+    0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d,
+    0x62, 0x65, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x06,
+    0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x38, 0x0a,
+    0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b,
+    0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62,
+    0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69,
+    0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x22, 0x51, 0x0a, 0x0c, 0x54, 0x72, 0x61, 0x6e, 0x73,
+    0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x41, 0x0a, 0x0c, 0x74, 0x72, 0x61, 0x6e, 0x73,
+    0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1d, 0x2e,
+    0x65, 0x74, 0x68, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73, 0x2e, 0x76, 0x31,
+    0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x0c, 0x74, 0x72,
+    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x22, 0xb7, 0x02, 0x0a, 0x0b, 0x54,
+    0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x12, 0x0a, 0x04, 0x68, 0x61,
+    0x73, 0x68, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x04, 0x68, 0x61, 0x73, 0x68, 0x12, 0x21,
+    0x0a, 0x0c, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18, 0x02,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x0b, 0x66, 0x72, 0x6f, 0x6d, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73,
+    0x73, 0x12, 0x1d, 0x0a, 0x0a, 0x74, 0x6f, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x18,
+    0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x74, 0x6f, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x12, 0x14, 0x0a, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x05, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x12, 0x19, 0x0a, 0x08, 0x67, 0x61, 0x73, 0x5f, 0x75, 0x73,
+    0x65, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x67, 0x61, 0x73, 0x55, 0x73, 0x65,
+    0x64, 0x12, 0x2e, 0x0a, 0x13, 0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x69, 0x76, 0x65, 0x5f, 0x67,
+    0x61, 0x73, 0x5f, 0x70, 0x72, 0x69, 0x63, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x09, 0x52, 0x11,
+    0x65, 0x66, 0x66, 0x65, 0x63, 0x74, 0x69, 0x76, 0x65, 0x47, 0x61, 0x73, 0x50, 0x72, 0x69, 0x63,
+    0x65, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65,
+    0x72, 0x18, 0x07, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75,
+    0x6d, 0x62, 0x65, 0x72, 0x12, 0x14, 0x0a, 0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x08, 0x20,
+    0x01, 0x28, 0x0d, 0x52, 0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69,
+    0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e,
+    0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e,
+    0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73,
+    0x74, 0x61, 0x6d, 0x70, 0x22, 0x45, 0x0a, 0x09, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c,
+    0x73, 0x12, 0x38, 0x0a, 0x09, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x73, 0x18, 0x01,
+    0x20, 0x03, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x65, 0x74, 0x68, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c,
+    0x52, 0x09, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x73, 0x22, 0xec, 0x02, 0x0a, 0x08,
+    0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x12, 0x12, 0x0a, 0x04, 0x6b, 0x69, 0x6e, 0x64,
+    0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x6b, 0x69, 0x6e, 0x64, 0x12, 0x29, 0x0a, 0x10,
+    0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x18, 0x02, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x0f, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
+    0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x12, 0x14, 0x0a, 0x05, 0x6f, 0x77, 0x6e, 0x65, 0x72,
+    0x18, 0x03, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x05, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x12, 0x18, 0x0a,
+    0x07, 0x73, 0x70, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x18, 0x04, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07,
+    0x73, 0x70, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x12, 0x19, 0x0a, 0x08, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x5f, 0x69, 0x64, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x07, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x49, 0x64, 0x12, 0x1a, 0x0a, 0x08, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x64, 0x18, 0x06,
+    0x20, 0x01, 0x28, 0x08, 0x52, 0x08, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x64, 0x12, 0x17,
+    0x0a, 0x07, 0x74, 0x78, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0c, 0x52,
+    0x06, 0x74, 0x78, 0x48, 0x61, 0x73, 0x68, 0x12, 0x21, 0x0a, 0x0c, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x5f, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x08, 0x20, 0x01, 0x28, 0x04, 0x52, 0x0b, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x4e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x18, 0x0a, 0x07, 0x6f, 0x72,
+    0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x09, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72, 0x64,
+    0x69, 0x6e, 0x61, 0x6c, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
+    0x70, 0x18, 0x0a, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65,
+    0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74,
+    0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x22, 0x2a,
+    0x0a, 0x04, 0x4b, 0x69, 0x6e, 0x64, 0x12, 0x0c, 0x0a, 0x08, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76,
+    0x61, 0x6c, 0x10, 0x00, 0x12, 0x14, 0x0a, 0x10, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c,
+    0x5f, 0x66, 0x6f, 0x72, 0x5f, 0x61, 0x6c, 0x6c, 0x10, 0x01, 0x4a, 0xc5, 0xa7, 0x01, 0x0a, 0x07,
+    0x12, 0x05, 0x00, 0x00, 0xb2, 0x04, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00,
+    0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x19, 0x0a, 0x09, 0x0a, 0x02, 0x03,
+    0x00, 0x12, 0x03, 0x04, 0x00, 0x29, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x06, 0x00,
+    0x08, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x06, 0x08, 0x11, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x07, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x07, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x06, 0x12, 0x03, 0x07, 0x0b, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x07, 0x14, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x07, 0x20, 0x21, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0a, 0x00, 0x3a, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x01, 0x04, 0x00, 0x12, 0x04, 0x0b, 0x02, 0x10, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x04,
+    0x00, 0x01, 0x12, 0x03, 0x0b, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02,
+    0x00, 0x12, 0x03, 0x0c, 0x04, 0x0e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x03, 0x0c, 0x04, 0x09, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00,
+    0x02, 0x12, 0x03, 0x0c, 0x0c, 0x0d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01,
+    0x12, 0x03, 0x0d, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x01,
+    0x12, 0x03, 0x0d, 0x04, 0x0a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x02,
+    0x12, 0x03, 0x0d, 0x0d, 0x0e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x12,
+    0x03, 0x0e, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x0e, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12,
+    0x03, 0x0e, 0x0e, 0x0f, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03,
+    0x0f, 0x04, 0x14, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x0f, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03,
+    0x0f, 0x12, 0x13, 0x0a, 0xed, 0x01, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x15, 0x02,
+    0x14, 0x1a, 0xdf, 0x01, 0x20, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x69, 0x73, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73,
+    0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x6f, 0x6e, 0x65, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x75, 0x6d, 0x20, 0x64, 0x65, 0x66, 0x69,
+    0x6e, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x53, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x2e, 0x20, 0x57,
+    0x65, 0x20, 0x75, 0x73, 0x65, 0x20, 0x69, 0x74, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x53, 0x74,
+    0x72, 0x69, 0x6e, 0x67, 0x0a, 0x20, 0x68, 0x65, 0x72, 0x65, 0x20, 0x62, 0x65, 0x63, 0x61, 0x75,
+    0x73, 0x65, 0x20, 0x52, 0x75, 0x73, 0x74, 0x20, 0x63, 0x6f, 0x64, 0x65, 0x20, 0x75, 0x73, 0x65,
+    0x73, 0x20, 0x61, 0x20, 0x60, 0x75, 0x33, 0x32, 0x60, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x69, 0x74,
+    0x73, 0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x20, 0x62, 0x75, 0x74, 0x20, 0x69, 0x74, 0x27, 0x73, 0x20, 0x6e, 0x69, 0x63, 0x65, 0x72, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x6c, 0x65, 0x20, 0x66, 0x6f, 0x72,
+    0x6d, 0x61, 0x74, 0x0a, 0x20, 0x74, 0x6f, 0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x79, 0x70, 0x65, 0x20, 0x61, 0x73, 0x20, 0x61, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e,
+    0x67, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12, 0x03, 0x15, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x15, 0x09, 0x0f, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x15, 0x12, 0x13, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x17, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x01, 0x05, 0x12, 0x03, 0x17, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x17, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x17, 0x18, 0x19, 0x0a, 0x7b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x1b, 0x02,
+    0x19, 0x1a, 0x6e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x65, 0x72, 0x73, 0x6f, 0x6e, 0x20, 0x74,
+    0x68, 0x61, 0x74, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2c, 0x20, 0x6d, 0x69, 0x67, 0x68, 0x74,
+    0x20, 0x6e, 0x6f, 0x74, 0x20, 0x62, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65,
+    0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x74, 0x68, 0x61, 0x74,
+    0x20, 0x64, 0x69, 0x64, 0x20, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74,
+    0x68, 0x65, 0x0a, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x1b, 0x02, 0x07, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1b, 0x08, 0x14, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1b, 0x17, 0x18, 0x0a, 0x35, 0x0a, 0x04, 0x04,
+    0x01, 0x02, 0x03, 0x12, 0x03, 0x1e, 0x02, 0x17, 0x1a, 0x28, 0x20, 0x54, 0x68, 0x65, 0x20, 0x70,
+    0x65, 0x72, 0x73, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69,
+    0x76, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
+    0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x05, 0x12, 0x03, 0x1e, 0x02, 0x07,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1e, 0x08, 0x12, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03, 0x1e, 0x15, 0x16, 0x0a, 0xf9, 0x03, 0x0a,
+    0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03, 0x25, 0x02, 0x15, 0x1a, 0xeb, 0x03, 0x20, 0x4f, 0x70,
+    0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x22, 0x73,
+    0x65, 0x6e, 0x64, 0x65, 0x72, 0x22, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63,
+    0x74, 0x75, 0x61, 0x6c, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x74, 0x65, 0x64, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2c, 0x20, 0x69, 0x74,
+    0x27, 0x73, 0x20, 0x65, 0x71, 0x75, 0x61, 0x6c, 0x20, 0x74, 0x6f, 0x0a, 0x20, 0x60, 0x6d, 0x73,
+    0x67, 0x2e, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x60, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x72, 0x74, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x2e, 0x20, 0x54, 0x68, 0x69, 0x73, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62,
+    0x65, 0x20, 0x64, 0x69, 0x66, 0x66, 0x65, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x61, 0x6e,
+    0x20, 0x60, 0x66, 0x72, 0x6f, 0x6d, 0x60, 0x20, 0x69, 0x6e, 0x20, 0x63, 0x61, 0x73, 0x65, 0x20,
+    0x6f, 0x66, 0x20, 0x60, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x60, 0x0a, 0x20, 0x66,
+    0x6f, 0x72, 0x20, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x2e, 0x20, 0x46, 0x6f, 0x72, 0x20,
+    0x60, 0x45, 0x52, 0x43, 0x31, 0x31, 0x35, 0x35, 0x60, 0x2c, 0x20, 0x69, 0x74, 0x20, 0x63, 0x6f,
+    0x6d, 0x65, 0x73, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x76, 0x65,
+    0x6e, 0x74, 0x2e, 0x20, 0x46, 0x6f, 0x72, 0x20, 0x60, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x60,
+    0x2c, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x68, 0x61, 0x73, 0x20, 0x6e, 0x6f, 0x20, 0x6f,
+    0x70, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x69, 0x6e, 0x20, 0x69, 0x74, 0x73, 0x0a, 0x20,
+    0x65, 0x76, 0x65, 0x6e, 0x74, 0x2c, 0x20, 0x69, 0x74, 0x27, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x63, 0x61, 0x6c, 0x6c, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61,
+    0x6c, 0x6c, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x65, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x28, 0x64, 0x65, 0x6c, 0x65, 0x67,
+    0x61, 0x74, 0x65, 0x20, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x73, 0x6b,
+    0x69, 0x70, 0x70, 0x65, 0x64, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x79, 0x20, 0x6b, 0x65,
+    0x65, 0x70, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x6d, 0x73, 0x67, 0x2e, 0x73, 0x65, 0x6e,
+    0x64, 0x65, 0x72, 0x60, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x69, 0x72, 0x20, 0x70, 0x61,
+    0x72, 0x65, 0x6e, 0x74, 0x29, 0x2e, 0x20, 0x46, 0x6f, 0x72, 0x20, 0x60, 0x45, 0x52, 0x43, 0x32,
+    0x30, 0x60, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x60, 0x63, 0x72, 0x79, 0x70, 0x74, 0x6f, 0x70, 0x75,
+    0x6e, 0x6b, 0x73, 0x60, 0x2c, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20,
+    0x62, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x73, 0x74, 0x72,
+    0x69, 0x6e, 0x67, 0x20, 0x22, 0x22, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04,
+    0x05, 0x12, 0x03, 0x25, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x01, 0x12,
+    0x03, 0x25, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x03, 0x12, 0x03, 0x25,
+    0x13, 0x14, 0x0a, 0x68, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x05, 0x12, 0x03, 0x28, 0x02, 0x16, 0x1a,
+    0x5b, 0x20, 0x48, 0x6f, 0x77, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x20, 0x77, 0x65, 0x72, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x72, 0x65,
+    0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x2c, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x61, 0x6c, 0x77, 0x61, 0x79, 0x73, 0x20,
+    0x62, 0x65, 0x20, 0x31, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x73, 0x65,
+    0x20, 0x6f, 0x66, 0x20, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x05, 0x05, 0x12, 0x03, 0x28, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x05, 0x01, 0x12, 0x03, 0x28, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05,
+    0x03, 0x12, 0x03, 0x28, 0x14, 0x15, 0x0a, 0x83, 0x02, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x06, 0x12,
+    0x03, 0x2d, 0x02, 0x15, 0x1a, 0xf5, 0x01, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x44, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x66, 0x6f, 0x72, 0x20,
+    0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x68, 0x61, 0x70, 0x70, 0x65, 0x6e, 0x69, 0x6e, 0x67, 0x2e,
+    0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x0a, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65,
+    0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x60, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x3d, 0x20,
+    0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x73, 0x63, 0x68, 0x65,
+    0x6d, 0x61, 0x20, 0x3d, 0x20, 0x45, 0x52, 0x43, 0x31, 0x31, 0x35, 0x35, 0x60, 0x2e, 0x20, 0x57,
+    0x68, 0x65, 0x6e, 0x20, 0x60, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x3d, 0x20, 0x45, 0x52,
+    0x43, 0x32, 0x30, 0x60, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20,
+    0x69, 0x64, 0x0a, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x65, 0x6d, 0x70, 0x74,
+    0x79, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x22, 0x22, 0x20, 0x61, 0x73, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x69, 0x74, 0x73, 0x65,
+    0x6c, 0x66, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20,
+    0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x06, 0x05, 0x12, 0x03, 0x2d, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x06, 0x01, 0x12, 0x03, 0x2d, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06,
+    0x03, 0x12, 0x03, 0x2d, 0x13, 0x14, 0x0a, 0x5f, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x07, 0x12, 0x03,
+    0x30, 0x02, 0x1d, 0x1a, 0x52, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64,
+    0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64,
+    0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x72,
+    0x74, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
+    0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x63, 0x6f, 0x6e,
+    0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x05,
+    0x12, 0x03, 0x30, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x01, 0x12, 0x03,
+    0x30, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x03, 0x12, 0x03, 0x30, 0x1b,
+    0x1c, 0x0a, 0x34, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x08, 0x12, 0x03, 0x33, 0x02, 0x14, 0x1a, 0x27,
+    0x20, 0x54, 0x78, 0x48, 0x61, 0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68,
+    0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73,
+    0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x05,
+    0x12, 0x03, 0x33, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x01, 0x12, 0x03,
+    0x33, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x03, 0x12, 0x03, 0x33, 0x12,
+    0x13, 0x0a, 0x45, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x09, 0x12, 0x03, 0x36, 0x02, 0x16, 0x1a, 0x38,
+    0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61,
+    0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09,
+    0x05, 0x12, 0x03, 0x36, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x01, 0x12,
+    0x03, 0x36, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x03, 0x12, 0x03, 0x36,
+    0x13, 0x15, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0a, 0x12, 0x03, 0x39, 0x02, 0x2b, 0x1a,
+    0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x0a, 0x06, 0x12, 0x03, 0x39, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x0a, 0x01, 0x12, 0x03, 0x39, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x03,
+    0x12, 0x03, 0x39, 0x28, 0x2a, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x3c, 0x00, 0x3e,
+    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x3c, 0x08, 0x0d, 0x0a, 0x0b, 0x0a,
+    0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x3d, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
+    0x02, 0x00, 0x04, 0x12, 0x03, 0x3d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
+    0x06, 0x12, 0x03, 0x3d, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x3d, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x3d,
+    0x18, 0x19, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x40, 0x00, 0x74, 0x01, 0x0a, 0x0a,
+    0x0a, 0x03, 0x04, 0x03, 0x01, 0x12, 0x03, 0x40, 0x08, 0x0c, 0x0a, 0x64, 0x0a, 0x04, 0x04, 0x03,
+    0x02, 0x00, 0x12, 0x03, 0x42, 0x02, 0x19, 0x1a, 0x57, 0x20, 0x4d, 0x61, 0x72, 0x6b, 0x65, 0x74,
+    0x70, 0x6c, 0x61, 0x63, 0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x61, 0x6d,
+    0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f,
+    0x6c, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x73, 0x65, 0x74, 0x74, 0x6c, 0x65, 0x64, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x73, 0x61, 0x6c, 0x65, 0x2c, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x65, 0x78, 0x61,
+    0x6d, 0x70, 0x6c, 0x65, 0x20, 0x22, 0x73, 0x65, 0x61, 0x70, 0x6f, 0x72, 0x74, 0x22, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x05, 0x12, 0x03, 0x42, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x42, 0x09, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x42, 0x17, 0x18, 0x0a, 0x69, 0x0a, 0x04, 0x04, 0x03,
+    0x02, 0x01, 0x12, 0x03, 0x45, 0x02, 0x20, 0x1a, 0x5c, 0x20, 0x4d, 0x61, 0x72, 0x6b, 0x65, 0x74,
+    0x70, 0x6c, 0x61, 0x63, 0x65, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x69, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20, 0x63,
+    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x65, 0x6d, 0x69,
+    0x74, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6c, 0x65, 0x20, 0x65, 0x76,
+    0x65, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x45, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x45, 0x08,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x45, 0x1e, 0x1f, 0x0a,
+    0x7a, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x02, 0x12, 0x03, 0x49, 0x02, 0x17, 0x1a, 0x6d, 0x20, 0x4f,
+    0x72, 0x64, 0x65, 0x72, 0x48, 0x61, 0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65,
+    0x72, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x66, 0x75, 0x6c, 0x66, 0x69,
+    0x6c, 0x6c, 0x65, 0x64, 0x2c, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x77, 0x68, 0x65, 0x6e,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65,
+    0x20, 0x68, 0x61, 0x73, 0x20, 0x6e, 0x6f, 0x0a, 0x20, 0x6e, 0x6f, 0x74, 0x69, 0x6f, 0x6e, 0x20,
+    0x6f, 0x66, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x73, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x02, 0x05, 0x12, 0x03, 0x49, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x49, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x02, 0x03,
+    0x12, 0x03, 0x49, 0x15, 0x16, 0x0a, 0x5d, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x03, 0x12, 0x03, 0x4c,
+    0x02, 0x1d, 0x1a, 0x50, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64,
+    0x72, 0x65, 0x73, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72,
+    0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x63,
+    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x73, 0x6f,
+    0x6c, 0x64, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x05, 0x12, 0x03, 0x4c,
+    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x01, 0x12, 0x03, 0x4c, 0x08, 0x18,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x03, 0x03, 0x12, 0x03, 0x4c, 0x1b, 0x1c, 0x0a, 0xdd,
+    0x01, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x04, 0x12, 0x03, 0x51, 0x02, 0x15, 0x1a, 0xcf, 0x01, 0x20,
+    0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x44, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69,
+    0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20,
+    0x73, 0x6f, 0x6c, 0x64, 0x2e, 0x20, 0x57, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x72,
+    0x64, 0x65, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x73, 0x20, 0x6d, 0x6f, 0x72,
+    0x65, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x6f, 0x6e, 0x65, 0x0a, 0x20, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x20, 0x28, 0x61, 0x20, 0x62, 0x75, 0x6e, 0x64, 0x6c, 0x65, 0x29, 0x2c, 0x20, 0x74, 0x68,
+    0x69, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64,
+    0x65, 0x72, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x60, 0x69, 0x74, 0x65, 0x6d, 0x5f, 0x63, 0x6f, 0x75,
+    0x6e, 0x74, 0x60, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x75,
+    0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x0a, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20,
+    0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x04, 0x05, 0x12, 0x03, 0x51, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x04, 0x01, 0x12, 0x03, 0x51, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x04, 0x03, 0x12, 0x03, 0x51, 0x13, 0x14, 0x0a, 0x57, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x05,
+    0x12, 0x03, 0x54, 0x02, 0x16, 0x1a, 0x4a, 0x20, 0x48, 0x6f, 0x77, 0x20, 0x6d, 0x61, 0x6e, 0x79,
+    0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x60, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x5f, 0x69, 0x64, 0x60, 0x20, 0x77, 0x65, 0x72, 0x65, 0x20, 0x73, 0x6f, 0x6c, 0x64, 0x2c,
+    0x20, 0x61, 0x6c, 0x77, 0x61, 0x79, 0x73, 0x20, 0x31, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x63, 0x61, 0x73, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x05, 0x12, 0x03, 0x54, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x05, 0x01, 0x12, 0x03, 0x54, 0x09, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x05, 0x03, 0x12, 0x03, 0x54, 0x14, 0x15, 0x0a, 0x52, 0x0a, 0x04, 0x04,
+    0x03, 0x02, 0x06, 0x12, 0x03, 0x57, 0x02, 0x18, 0x1a, 0x45, 0x20, 0x49, 0x74, 0x65, 0x6d, 0x43,
+    0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x75, 0x6d, 0x62,
+    0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x20,
+    0x74, 0x68, 0x61, 0x74, 0x20, 0x77, 0x65, 0x72, 0x65, 0x20, 0x74, 0x72, 0x61, 0x64, 0x65, 0x64,
+    0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x06, 0x05, 0x12, 0x03, 0x57, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x06, 0x01, 0x12, 0x03, 0x57, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x06, 0x03, 0x12, 0x03, 0x57, 0x16, 0x17, 0x0a, 0x34, 0x0a, 0x04, 0x04, 0x03, 0x02,
+    0x07, 0x12, 0x03, 0x5a, 0x02, 0x13, 0x1a, 0x27, 0x20, 0x54, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x67, 0x61, 0x76, 0x65, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x61, 0x77, 0x61, 0x79, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x07, 0x05, 0x12, 0x03, 0x5a, 0x02, 0x07, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x07, 0x01, 0x12, 0x03, 0x5a, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x07, 0x03, 0x12, 0x03, 0x5a, 0x11, 0x12, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x03, 0x02,
+    0x08, 0x12, 0x03, 0x5d, 0x02, 0x12, 0x1a, 0x26, 0x20, 0x54, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76,
+    0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x08, 0x05, 0x12, 0x03, 0x5d, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x08, 0x01, 0x12, 0x03, 0x5d, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x08, 0x03, 0x12, 0x03, 0x5d, 0x10, 0x11, 0x0a, 0x82, 0x01, 0x0a, 0x04, 0x04, 0x03, 0x02,
+    0x09, 0x12, 0x03, 0x61, 0x02, 0x1b, 0x1a, 0x75, 0x20, 0x50, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74,
+    0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64,
+    0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x45, 0x52, 0x43, 0x32,
+    0x30, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x70, 0x61, 0x79, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6c, 0x65, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x7a, 0x65, 0x72, 0x6f, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x77, 0x68, 0x65,
+    0x6e, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6c, 0x65, 0x20, 0x77, 0x61, 0x73, 0x20,
+    0x70, 0x61, 0x69, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x45, 0x54, 0x48, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x09, 0x05, 0x12, 0x03, 0x61, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x09, 0x01, 0x12, 0x03, 0x61, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x09, 0x03, 0x12, 0x03, 0x61, 0x18, 0x1a, 0x0a, 0x82, 0x01, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0a,
+    0x12, 0x03, 0x65, 0x02, 0x14, 0x1a, 0x75, 0x20, 0x50, 0x72, 0x69, 0x63, 0x65, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x20, 0x61, 0x6d, 0x6f, 0x75, 0x6e,
+    0x74, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x75,
+    0x79, 0x65, 0x72, 0x2c, 0x20, 0x66, 0x65, 0x65, 0x73, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x72, 0x6f,
+    0x79, 0x61, 0x6c, 0x74, 0x69, 0x65, 0x73, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x64,
+    0x2c, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x6c, 0x6c, 0x65, 0x73,
+    0x74, 0x0a, 0x20, 0x75, 0x6e, 0x69, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x60, 0x70, 0x61, 0x79, 0x6d,
+    0x65, 0x6e, 0x74, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x0a, 0x05, 0x12, 0x03, 0x65, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
+    0x02, 0x0a, 0x01, 0x12, 0x03, 0x65, 0x09, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0a,
+    0x03, 0x12, 0x03, 0x65, 0x11, 0x13, 0x0a, 0x43, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0b, 0x12, 0x03,
+    0x68, 0x02, 0x23, 0x1a, 0x36, 0x20, 0x50, 0x61, 0x79, 0x6f, 0x75, 0x74, 0x73, 0x20, 0x61, 0x72,
+    0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x64, 0x69, 0x76, 0x69, 0x64, 0x75, 0x61, 0x6c,
+    0x20, 0x70, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74, 0x20, 0x6c, 0x65, 0x67, 0x73, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6c, 0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x0b, 0x04, 0x12, 0x03, 0x68, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x0b, 0x06, 0x12, 0x03, 0x68, 0x0b, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0b, 0x01,
+    0x12, 0x03, 0x68, 0x16, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0b, 0x03, 0x12, 0x03,
+    0x68, 0x20, 0x22, 0x0a, 0x34, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0c, 0x12, 0x03, 0x6b, 0x02, 0x15,
+    0x1a, 0x27, 0x20, 0x54, 0x78, 0x48, 0x61, 0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61,
+    0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x0c, 0x05, 0x12, 0x03, 0x6b, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0c, 0x01,
+    0x12, 0x03, 0x6b, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0c, 0x03, 0x12, 0x03,
+    0x6b, 0x12, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0d, 0x12, 0x03, 0x6d, 0x02, 0x1b,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0d, 0x05, 0x12, 0x03, 0x6d, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x0d, 0x01, 0x12, 0x03, 0x6d, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x0d, 0x03, 0x12, 0x03, 0x6d, 0x18, 0x1a, 0x0a, 0x41, 0x0a, 0x04, 0x04, 0x03,
+    0x02, 0x0e, 0x12, 0x03, 0x70, 0x02, 0x16, 0x1a, 0x34, 0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e, 0x61,
+    0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6c, 0x65, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69,
+    0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x0e, 0x05, 0x12, 0x03, 0x70, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x03, 0x02, 0x0e, 0x01, 0x12, 0x03, 0x70, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02,
+    0x0e, 0x03, 0x12, 0x03, 0x70, 0x13, 0x15, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x0f, 0x12,
+    0x03, 0x73, 0x02, 0x2b, 0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
+    0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x0f, 0x06, 0x12, 0x03, 0x73, 0x02, 0x1b, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x03, 0x02, 0x0f, 0x01, 0x12, 0x03, 0x73, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x03, 0x02, 0x0f, 0x03, 0x12, 0x03, 0x73, 0x28, 0x2a, 0x0a, 0x0b, 0x0a, 0x02, 0x04, 0x04,
+    0x12, 0x05, 0x76, 0x00, 0x89, 0x01, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03,
+    0x76, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x04, 0x04, 0x00, 0x12, 0x04, 0x77, 0x02, 0x7b,
+    0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x04, 0x00, 0x01, 0x12, 0x03, 0x77, 0x07, 0x0b, 0x0a,
+    0x0d, 0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x78, 0x04, 0x0f, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x78, 0x04, 0x0a, 0x0a, 0x0e,
+    0x0a, 0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x78, 0x0d, 0x0e, 0x0a, 0x0d,
+    0x0a, 0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x79, 0x04, 0x18, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x79, 0x04, 0x13, 0x0a, 0x0e, 0x0a,
+    0x07, 0x04, 0x04, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x79, 0x16, 0x17, 0x0a, 0x0d, 0x0a,
+    0x06, 0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x7a, 0x04, 0x10, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x7a, 0x04, 0x0b, 0x0a, 0x0e, 0x0a, 0x07,
+    0x04, 0x04, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x7a, 0x0e, 0x0f, 0x0a, 0x82, 0x01, 0x0a,
+    0x04, 0x04, 0x04, 0x02, 0x00, 0x12, 0x03, 0x7f, 0x02, 0x12, 0x1a, 0x75, 0x20, 0x52, 0x6f, 0x6c,
+    0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20,
     0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f,
     0x66, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x75,
-    0x6d, 0x20, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x53, 0x63, 0x68,
-    0x65, 0x6d, 0x61, 0x2e, 0x20, 0x57, 0x65, 0x20, 0x75, 0x73, 0x65, 0x20, 0x69, 0x74, 0x20, 0x61,
-    0x73, 0x20, 0x61, 0x20, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x0a, 0x20, 0x68, 0x65, 0x72, 0x65,
-    0x20, 0x62, 0x65, 0x63, 0x61, 0x75, 0x73, 0x65, 0x20, 0x52, 0x75, 0x73, 0x74, 0x20, 0x63, 0x6f,
-    0x64, 0x65, 0x20, 0x75, 0x73, 0x65, 0x73, 0x20, 0x61, 0x20, 0x60, 0x75, 0x33, 0x32, 0x60, 0x20,
-    0x66, 0x6f, 0x72, 0x20, 0x69, 0x74, 0x73, 0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e,
-    0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x62, 0x75, 0x74, 0x20, 0x69, 0x74, 0x27, 0x73, 0x20,
-    0x6e, 0x69, 0x63, 0x65, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69,
-    0x6c, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x0a, 0x20, 0x74, 0x6f, 0x20, 0x68, 0x61,
-    0x76, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x79, 0x70, 0x65, 0x20, 0x61, 0x73, 0x20, 0x61,
-    0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x00, 0x05, 0x12, 0x03, 0x14, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x14, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x14, 0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x16, 0x02, 0x1a,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x16, 0x02, 0x08, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x16, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x16, 0x18, 0x19, 0x0a, 0x7b, 0x0a, 0x04, 0x04, 0x01,
-    0x02, 0x02, 0x12, 0x03, 0x1a, 0x02, 0x19, 0x1a, 0x6e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x65,
-    0x72, 0x73, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76,
-    0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2c,
-    0x20, 0x6d, 0x69, 0x67, 0x68, 0x74, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x62, 0x65, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x6e,
-    0x65, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x64, 0x69, 0x64, 0x20, 0x69, 0x6e, 0x69, 0x74, 0x69,
-    0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x0a, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61,
-    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05,
-    0x12, 0x03, 0x1a, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03,
-    0x1a, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x1a, 0x17,
-    0x18, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x1d, 0x02, 0x17, 0x1a, 0x28,
-    0x20, 0x54, 0x68, 0x65, 0x20, 0x70, 0x65, 0x72, 0x73, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74,
-    0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72,
-    0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03,
-    0x05, 0x12, 0x03, 0x1d, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01, 0x12,
-    0x03, 0x1d, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03, 0x1d,
-    0x15, 0x16, 0x0a, 0xc0, 0x02, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03, 0x23, 0x02, 0x15,
-    0x1a, 0xb2, 0x02, 0x20, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x69, 0x73, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x22, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x22, 0x20, 0x6f, 0x66, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x74, 0x75, 0x61, 0x6c, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73,
-    0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x69, 0x6e, 0x69, 0x74,
-    0x69, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
-    0x65, 0x72, 0x2c, 0x20, 0x69, 0x74, 0x27, 0x73, 0x20, 0x65, 0x71, 0x75, 0x61, 0x6c, 0x20, 0x74,
-    0x6f, 0x0a, 0x20, 0x60, 0x6d, 0x73, 0x67, 0x2e, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x60, 0x20,
-    0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x72, 0x74,
-    0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x20, 0x54, 0x68, 0x69, 0x73, 0x20,
-    0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x64, 0x69, 0x66, 0x66, 0x65, 0x72, 0x65, 0x6e,
-    0x74, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x20, 0x60, 0x66, 0x72, 0x6f, 0x6d, 0x60, 0x20, 0x69, 0x6e,
-    0x20, 0x63, 0x61, 0x73, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x60, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76,
-    0x61, 0x6c, 0x60, 0x0a, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65,
-    0x2e, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65,
-    0x20, 0x69, 0x66, 0x20, 0x60, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x3d, 0x20, 0x45, 0x52,
-    0x43, 0x31, 0x31, 0x35, 0x35, 0x60, 0x2c, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x60, 0x45, 0x52, 0x43,
-    0x32, 0x30, 0x60, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x60, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x60,
-    0x2c, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x0a, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67,
-    0x20, 0x22, 0x22, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x05, 0x12, 0x03,
-    0x23, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x23, 0x08,
-    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x03, 0x12, 0x03, 0x23, 0x13, 0x14, 0x0a,
-    0x68, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x05, 0x12, 0x03, 0x26, 0x02, 0x16, 0x1a, 0x5b, 0x20, 0x48,
-    0x6f, 0x77, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x77, 0x65,
-    0x72, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x72, 0x65, 0x64, 0x20, 0x69,
-    0x6e, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2c,
-    0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x61, 0x6c, 0x77, 0x61, 0x79, 0x73, 0x20, 0x62, 0x65, 0x20,
-    0x31, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x73, 0x65, 0x20, 0x6f, 0x66,
-    0x20, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x05, 0x05, 0x12, 0x03, 0x26, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x01,
-    0x12, 0x03, 0x26, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12, 0x03,
-    0x26, 0x14, 0x15, 0x0a, 0x83, 0x02, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x06, 0x12, 0x03, 0x2b, 0x02,
-    0x15, 0x1a, 0xf5, 0x01, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x44, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x77, 0x68, 0x69,
-    0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20,
-    0x69, 0x73, 0x20, 0x68, 0x61, 0x70, 0x70, 0x65, 0x6e, 0x69, 0x6e, 0x67, 0x2e, 0x20, 0x4f, 0x6e,
-    0x6c, 0x79, 0x0a, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x77, 0x68,
-    0x65, 0x6e, 0x20, 0x60, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x3d, 0x20, 0x45, 0x52, 0x43,
-    0x37, 0x32, 0x31, 0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20,
-    0x3d, 0x20, 0x45, 0x52, 0x43, 0x31, 0x31, 0x35, 0x35, 0x60, 0x2e, 0x20, 0x57, 0x68, 0x65, 0x6e,
-    0x20, 0x60, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x20, 0x3d, 0x20, 0x45, 0x52, 0x43, 0x32, 0x30,
-    0x60, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x64, 0x0a,
-    0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x62, 0x65, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x73,
-    0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x22, 0x22, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x69, 0x74, 0x73, 0x65, 0x6c, 0x66, 0x20,
-    0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x64, 0x65,
-    0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x06, 0x05, 0x12, 0x03, 0x2b, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x01,
-    0x12, 0x03, 0x2b, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x03, 0x12, 0x03,
-    0x2b, 0x13, 0x14, 0x0a, 0x5f, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x07, 0x12, 0x03, 0x2e, 0x02, 0x1d,
-    0x1a, 0x52, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65,
-    0x73, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73,
-    0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x72, 0x74, 0x20, 0x63,
-    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x69, 0x73, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
-    0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x05, 0x12, 0x03, 0x2e,
-    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x01, 0x12, 0x03, 0x2e, 0x08, 0x18,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x03, 0x12, 0x03, 0x2e, 0x1b, 0x1c, 0x0a, 0x34,
-    0x0a, 0x04, 0x04, 0x01, 0x02, 0x08, 0x12, 0x03, 0x31, 0x02, 0x14, 0x1a, 0x27, 0x20, 0x54, 0x78,
+    0x6d, 0x20, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x52, 0x6f, 0x6c,
+    0x65, 0x2c, 0x20, 0x73, 0x65, 0x65, 0x20, 0x60, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72,
+    0x2e, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x60, 0x0a, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x77, 0x68,
+    0x79, 0x20, 0x69, 0x74, 0x27, 0x73, 0x20, 0x61, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x05, 0x12, 0x03, 0x7f, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x00, 0x01, 0x12, 0x03, 0x7f, 0x09, 0x0d, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x00, 0x03, 0x12, 0x03, 0x7f, 0x10, 0x11, 0x0a, 0x42, 0x0a, 0x04, 0x04,
+    0x04, 0x02, 0x01, 0x12, 0x04, 0x82, 0x01, 0x02, 0x16, 0x1a, 0x34, 0x20, 0x54, 0x68, 0x65, 0x20,
+    0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x72, 0x65, 0x63,
+    0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x6c, 0x65, 0x67, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x61, 0x79, 0x6d, 0x65, 0x6e, 0x74, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x05, 0x12, 0x04, 0x82, 0x01, 0x02, 0x07, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x04, 0x02, 0x01, 0x01, 0x12, 0x04, 0x82, 0x01, 0x08, 0x11, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x01, 0x03, 0x12, 0x04, 0x82, 0x01, 0x14, 0x15, 0x0a, 0x5c, 0x0a, 0x04,
+    0x04, 0x04, 0x02, 0x02, 0x12, 0x04, 0x85, 0x01, 0x02, 0x1a, 0x1a, 0x4e, 0x20, 0x50, 0x61, 0x79,
+    0x6d, 0x65, 0x6e, 0x74, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x45, 0x52, 0x43, 0x32, 0x30, 0x20, 0x70, 0x61, 0x69, 0x64, 0x20, 0x6f, 0x75, 0x74, 0x2c, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x7a, 0x65, 0x72, 0x6f, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x45, 0x54, 0x48, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x04,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0x85, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x04, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0x85, 0x01, 0x08, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x04, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0x85, 0x01, 0x18, 0x19, 0x0a, 0x54, 0x0a, 0x04, 0x04, 0x04, 0x02, 0x03, 0x12,
+    0x04, 0x88, 0x01, 0x02, 0x14, 0x1a, 0x46, 0x20, 0x41, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x70,
+    0x61, 0x69, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x60, 0x72, 0x65, 0x63, 0x69, 0x70, 0x69, 0x65, 0x6e,
+    0x74, 0x60, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x6d, 0x61, 0x6c, 0x6c,
+    0x65, 0x73, 0x74, 0x20, 0x75, 0x6e, 0x69, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x60, 0x70, 0x61, 0x79,
+    0x6d, 0x65, 0x6e, 0x74, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x04, 0x02, 0x03, 0x05, 0x12, 0x04, 0x88, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x04, 0x02, 0x03, 0x01, 0x12, 0x04, 0x88, 0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x04, 0x02, 0x03, 0x03, 0x12, 0x04, 0x88, 0x01, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x05,
+    0x12, 0x06, 0x8b, 0x01, 0x00, 0x8d, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12,
+    0x04, 0x8b, 0x01, 0x08, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x05, 0x02, 0x00, 0x12, 0x04, 0x8c,
+    0x01, 0x02, 0x35, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x04, 0x12, 0x04, 0x8c, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x06, 0x12, 0x04, 0x8c, 0x01, 0x0b,
+    0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8c, 0x01, 0x1d, 0x30,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x05, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8c, 0x01, 0x33, 0x34, 0x0a,
+    0x0c, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x06, 0x8f, 0x01, 0x00, 0xb7, 0x01, 0x01, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x06, 0x01, 0x12, 0x04, 0x8f, 0x01, 0x08, 0x19, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x06,
+    0x04, 0x00, 0x12, 0x06, 0x90, 0x01, 0x02, 0x95, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06,
+    0x04, 0x00, 0x01, 0x12, 0x04, 0x90, 0x01, 0x07, 0x0b, 0x0a, 0x59, 0x0a, 0x06, 0x04, 0x06, 0x04,
+    0x00, 0x02, 0x00, 0x12, 0x04, 0x92, 0x01, 0x04, 0x18, 0x1a, 0x49, 0x20, 0x41, 0x20, 0x73, 0x69,
+    0x6e, 0x67, 0x6c, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x77, 0x61, 0x73, 0x20, 0x63,
+    0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x2c, 0x20, 0x60, 0x6f, 0x72, 0x64, 0x65, 0x72,
+    0x5f, 0x68, 0x61, 0x73, 0x68, 0x60, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64,
+    0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x06, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0x92, 0x01, 0x04, 0x13, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x06, 0x04, 0x00, 0x02, 0x00, 0x02,
+    0x12, 0x04, 0x92, 0x01, 0x16, 0x17, 0x0a, 0x64, 0x0a, 0x06, 0x04, 0x06, 0x04, 0x00, 0x02, 0x01,
+    0x12, 0x04, 0x94, 0x01, 0x04, 0x1c, 0x1a, 0x54, 0x20, 0x45, 0x76, 0x65, 0x72, 0x79, 0x20, 0x6f,
+    0x72, 0x64, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x60, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x65, 0x72,
+    0x60, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x20,
+    0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x72, 0x20, 0x6c, 0x6f, 0x77, 0x65, 0x72, 0x20, 0x74, 0x68,
+    0x61, 0x6e, 0x20, 0x60, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x72, 0x60, 0x20, 0x77, 0x61, 0x73,
+    0x20, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x06, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x94, 0x01, 0x04, 0x17, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x06, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x94, 0x01, 0x1a, 0x1b, 0x0a, 0x83,
+    0x01, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00, 0x12, 0x04, 0x99, 0x01, 0x02, 0x12, 0x1a, 0x75, 0x20,
+    0x4b, 0x69, 0x6e, 0x64, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x72, 0x69,
+    0x6e, 0x67, 0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x20, 0x6f, 0x66, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x65, 0x6e, 0x75, 0x6d, 0x20, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20,
+    0x4b, 0x69, 0x6e, 0x64, 0x2c, 0x20, 0x73, 0x65, 0x65, 0x20, 0x60, 0x54, 0x72, 0x61, 0x6e, 0x73,
+    0x66, 0x65, 0x72, 0x2e, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x60, 0x0a, 0x20, 0x66, 0x6f, 0x72,
+    0x20, 0x77, 0x68, 0x79, 0x20, 0x69, 0x74, 0x27, 0x73, 0x20, 0x61, 0x20, 0x73, 0x74, 0x72, 0x69,
+    0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x05, 0x12, 0x04, 0x99,
+    0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x04, 0x99, 0x01,
+    0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x04, 0x99, 0x01, 0x10,
+    0x11, 0x0a, 0x64, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x04, 0x9c, 0x01, 0x02, 0x19, 0x1a,
+    0x56, 0x20, 0x4d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x63, 0x6f, 0x6c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72,
+    0x64, 0x65, 0x72, 0x73, 0x20, 0x62, 0x65, 0x6c, 0x6f, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x2c, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x20, 0x22, 0x73, 0x65, 0x61,
+    0x70, 0x6f, 0x72, 0x74, 0x22, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0x9c, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0x9c, 0x01, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0x9c, 0x01, 0x17, 0x18, 0x0a, 0x65, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x02, 0x12, 0x04, 0x9f, 0x01,
+    0x02, 0x20, 0x1a, 0x57, 0x20, 0x4d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65,
+    0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61,
+    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61,
+    0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
+    0x63, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x65, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x02, 0x05, 0x12, 0x04, 0x9f, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x02, 0x01, 0x12, 0x04, 0x9f, 0x01, 0x08, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x02, 0x03, 0x12, 0x04, 0x9f, 0x01, 0x1e, 0x1f, 0x0a, 0x63, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x03,
+    0x12, 0x04, 0xa2, 0x01, 0x02, 0x17, 0x1a, 0x55, 0x20, 0x4f, 0x72, 0x64, 0x65, 0x72, 0x48, 0x61,
+    0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x20,
+    0x6f, 0x72, 0x64, 0x65, 0x72, 0x2c, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x61, 0x76, 0x61, 0x69,
+    0x6c, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x60, 0x6f, 0x72, 0x64, 0x65, 0x72,
+    0x5f, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x03, 0x05, 0x12, 0x04, 0xa2, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x03, 0x01, 0x12, 0x04, 0xa2, 0x01, 0x08, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x03, 0x03, 0x12, 0x04, 0xa2, 0x01, 0x15, 0x16, 0x0a, 0x3f, 0x0a, 0x04, 0x04, 0x06,
+    0x02, 0x04, 0x12, 0x04, 0xa5, 0x01, 0x02, 0x14, 0x1a, 0x31, 0x20, 0x54, 0x68, 0x65, 0x20, 0x61,
+    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x73, 0x69, 0x67, 0x6e,
+    0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64,
+    0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x28, 0x73, 0x29, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x04, 0x05, 0x12, 0x04, 0xa5, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x04, 0x01, 0x12, 0x04, 0xa5, 0x01, 0x08, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x04, 0x03, 0x12, 0x04, 0xa5, 0x01, 0x12, 0x13, 0x0a, 0x5e, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x05,
+    0x12, 0x04, 0xa8, 0x01, 0x02, 0x11, 0x1a, 0x50, 0x20, 0x5a, 0x6f, 0x6e, 0x65, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x7a, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x65, 0x64, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72,
+    0x2c, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x60, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x5f, 0x63, 0x61, 0x6e, 0x63,
+    0x65, 0x6c, 0x6c, 0x65, 0x64, 0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05,
+    0x05, 0x12, 0x04, 0xa8, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x01,
+    0x12, 0x04, 0xa8, 0x01, 0x08, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x05, 0x03, 0x12,
+    0x04, 0xa8, 0x01, 0x0f, 0x10, 0x0a, 0x62, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x06, 0x12, 0x04, 0xab,
+    0x01, 0x02, 0x15, 0x1a, 0x54, 0x20, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x72, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x77, 0x20, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x72,
+    0x20, 0x6f, 0x66, 0x20, 0x60, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x65, 0x72, 0x60, 0x2c, 0x20, 0x6f,
+    0x6e, 0x6c, 0x79, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x60, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x65, 0x72, 0x5f, 0x69, 0x6e, 0x63, 0x72, 0x65,
+    0x6d, 0x65, 0x6e, 0x74, 0x65, 0x64, 0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02,
+    0x06, 0x05, 0x12, 0x04, 0xab, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06,
+    0x01, 0x12, 0x04, 0xab, 0x01, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x06, 0x03,
+    0x12, 0x04, 0xab, 0x01, 0x13, 0x14, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x07, 0x12, 0x04,
+    0xae, 0x01, 0x02, 0x14, 0x1a, 0x27, 0x20, 0x54, 0x78, 0x48, 0x61, 0x73, 0x68, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x06, 0x02, 0x07, 0x05, 0x12, 0x04, 0xae, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x07, 0x01, 0x12, 0x04, 0xae, 0x01, 0x08, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x06, 0x02, 0x07, 0x03, 0x12, 0x04, 0xae, 0x01, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x06,
+    0x02, 0x08, 0x12, 0x04, 0xb0, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08,
+    0x05, 0x12, 0x04, 0xb0, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x01,
+    0x12, 0x04, 0xb0, 0x01, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x08, 0x03, 0x12,
+    0x04, 0xb0, 0x01, 0x18, 0x19, 0x0a, 0x4a, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x09, 0x12, 0x04, 0xb3,
+    0x01, 0x02, 0x16, 0x1a, 0x3c, 0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x63, 0x61, 0x6e, 0x63, 0x65, 0x6c, 0x6c, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x09, 0x05, 0x12, 0x04, 0xb3, 0x01, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x09, 0x01, 0x12, 0x04, 0xb3, 0x01, 0x09, 0x10, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x09, 0x03, 0x12, 0x04, 0xb3, 0x01, 0x13, 0x15, 0x0a, 0x38,
+    0x0a, 0x04, 0x04, 0x06, 0x02, 0x0a, 0x12, 0x04, 0xb6, 0x01, 0x02, 0x2b, 0x1a, 0x2a, 0x20, 0x54,
+    0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x0a,
+    0x06, 0x12, 0x04, 0xb6, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x0a, 0x01,
+    0x12, 0x04, 0xb6, 0x01, 0x1c, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x0a, 0x03, 0x12,
+    0x04, 0xb6, 0x01, 0x28, 0x2a, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x06, 0xb9, 0x01, 0x00,
+    0xbb, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x04, 0xb9, 0x01, 0x08, 0x12,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x04, 0xba, 0x01, 0x02, 0x25, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x04, 0x12, 0x04, 0xba, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x07, 0x02, 0x00, 0x06, 0x12, 0x04, 0xba, 0x01, 0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x00, 0x01, 0x12, 0x04, 0xba, 0x01, 0x15, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x00, 0x03, 0x12, 0x04, 0xba, 0x01, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x08,
+    0x12, 0x06, 0xbd, 0x01, 0x00, 0xe3, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12,
+    0x04, 0xbd, 0x01, 0x08, 0x11, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x08, 0x04, 0x00, 0x12, 0x06, 0xbe,
+    0x01, 0x02, 0xc3, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x04, 0x00, 0x01, 0x12, 0x04,
+    0xbe, 0x01, 0x07, 0x0b, 0x0a, 0x74, 0x0a, 0x06, 0x04, 0x08, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04,
+    0xc0, 0x01, 0x04, 0x10, 0x1a, 0x64, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x75, 0x6e, 0x6b, 0x20, 0x6f, 0x66, 0x66,
+    0x65, 0x72, 0x65, 0x64, 0x20, 0x69, 0x74, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x73, 0x61, 0x6c, 0x65,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x61, 0x74, 0x20, 0x6c, 0x65, 0x61, 0x73, 0x74, 0x20, 0x60, 0x76,
+    0x61, 0x6c, 0x75, 0x65, 0x60, 0x2c, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e, 0x79, 0x6f, 0x6e, 0x65,
+    0x20, 0x6f, 0x72, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x74, 0x6f, 0x20, 0x60, 0x74, 0x6f, 0x5f,
+    0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x60, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x08,
+    0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc0, 0x01, 0x04, 0x0b, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x08, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0xc0, 0x01, 0x0e, 0x0f, 0x0a, 0x46, 0x0a, 0x06,
+    0x04, 0x08, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0xc2, 0x01, 0x04, 0x14, 0x1a, 0x36, 0x20, 0x60,
+    0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x60, 0x20, 0x65, 0x6e,
+    0x74, 0x65, 0x72, 0x65, 0x64, 0x20, 0x61, 0x20, 0x62, 0x69, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x60,
+    0x76, 0x61, 0x6c, 0x75, 0x65, 0x60, 0x20, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x75,
+    0x6e, 0x6b, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xc2, 0x01, 0x04, 0x0f, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x08, 0x04, 0x00, 0x02, 0x01, 0x02,
+    0x12, 0x04, 0xc2, 0x01, 0x12, 0x13, 0x0a, 0x83, 0x01, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12,
+    0x04, 0xc7, 0x01, 0x02, 0x12, 0x1a, 0x75, 0x20, 0x4b, 0x69, 0x6e, 0x64, 0x20, 0x69, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x72, 0x65, 0x70, 0x72, 0x65,
+    0x73, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x6f, 0x6e, 0x65,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x75, 0x6d, 0x20, 0x64, 0x65, 0x66,
+    0x69, 0x6e, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x4b, 0x69, 0x6e, 0x64, 0x2c, 0x20, 0x73, 0x65,
+    0x65, 0x20, 0x60, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2e, 0x73, 0x63, 0x68, 0x65,
+    0x6d, 0x61, 0x60, 0x0a, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x77, 0x68, 0x79, 0x20, 0x69, 0x74, 0x27,
+    0x73, 0x20, 0x61, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x04, 0xc7, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc7, 0x01, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0xc7, 0x01, 0x10, 0x11, 0x0a, 0x52, 0x0a, 0x04, 0x04, 0x08, 0x02,
+    0x01, 0x12, 0x04, 0xca, 0x01, 0x02, 0x1d, 0x1a, 0x44, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61,
+    0x63, 0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x43, 0x72, 0x79, 0x70, 0x74, 0x6f, 0x50, 0x75, 0x6e, 0x6b, 0x73, 0x20, 0x6d, 0x61, 0x72, 0x6b,
+    0x65, 0x74, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x04, 0xca, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x01, 0x01, 0x12, 0x04, 0xca, 0x01, 0x08, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x01, 0x03, 0x12, 0x04, 0xca, 0x01, 0x1b, 0x1c, 0x0a, 0x31, 0x0a, 0x04, 0x04, 0x08,
+    0x02, 0x02, 0x12, 0x04, 0xcd, 0x01, 0x02, 0x15, 0x1a, 0x23, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e,
+    0x49, 0x44, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x75, 0x6e, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x04, 0xcd, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x08, 0x02, 0x02, 0x01, 0x12, 0x04, 0xcd, 0x01, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x02, 0x03, 0x12, 0x04, 0xcd, 0x01, 0x13, 0x14, 0x0a, 0x56, 0x0a, 0x04, 0x04, 0x08,
+    0x02, 0x03, 0x12, 0x04, 0xd0, 0x01, 0x02, 0x13, 0x1a, 0x48, 0x20, 0x56, 0x61, 0x6c, 0x75, 0x65,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x69, 0x6e, 0x69, 0x6d, 0x75, 0x6d, 0x20,
+    0x70, 0x72, 0x69, 0x63, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x66, 0x66, 0x65,
+    0x72, 0x20, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x20,
+    0x6f, 0x66, 0x20, 0x61, 0x20, 0x62, 0x69, 0x64, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x65, 0x69,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x05, 0x12, 0x04, 0xd0, 0x01, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x01, 0x12, 0x04, 0xd0, 0x01, 0x09, 0x0e,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x03, 0x12, 0x04, 0xd0, 0x01, 0x11, 0x12, 0x0a,
+    0x53, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x04, 0x12, 0x04, 0xd3, 0x01, 0x02, 0x19, 0x1a, 0x45, 0x20,
+    0x54, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74,
+    0x20, 0x65, 0x6e, 0x74, 0x65, 0x72, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x69, 0x64,
+    0x2c, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x60, 0x62, 0x69, 0x64, 0x5f, 0x65, 0x6e, 0x74, 0x65, 0x72, 0x65,
+    0x64, 0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x05, 0x12, 0x04, 0xd3,
+    0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x01, 0x12, 0x04, 0xd3, 0x01,
+    0x08, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x03, 0x12, 0x04, 0xd3, 0x01, 0x17,
+    0x18, 0x0a, 0x8c, 0x01, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x05, 0x12, 0x04, 0xd7, 0x01, 0x02, 0x17,
+    0x1a, 0x7e, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x62,
+    0x75, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x75, 0x6e, 0x6b, 0x2c, 0x20, 0x65, 0x6d, 0x70,
+    0x74, 0x79, 0x20, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x7a, 0x65, 0x72, 0x6f, 0x20, 0x61,
+    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6f, 0x66, 0x66, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x2c,
+    0x0a, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x60, 0x6f, 0x66, 0x66, 0x65, 0x72, 0x65, 0x64, 0x60, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x05, 0x12, 0x04, 0xd7, 0x01, 0x02, 0x07, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x01, 0x12, 0x04, 0xd7, 0x01, 0x08, 0x12, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x03, 0x12, 0x04, 0xd7, 0x01, 0x15, 0x16, 0x0a, 0x35, 0x0a,
+    0x04, 0x04, 0x08, 0x02, 0x06, 0x12, 0x04, 0xda, 0x01, 0x02, 0x14, 0x1a, 0x27, 0x20, 0x54, 0x78,
     0x48, 0x61, 0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68,
     0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
-    0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x05, 0x12, 0x03, 0x31,
-    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x01, 0x12, 0x03, 0x31, 0x08, 0x0f,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x03, 0x12, 0x03, 0x31, 0x12, 0x13, 0x0a, 0x45,
-    0x0a, 0x04, 0x04, 0x01, 0x02, 0x09, 0x12, 0x03, 0x34, 0x02, 0x16, 0x1a, 0x38, 0x20, 0x4f, 0x72,
-    0x64, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64,
-    0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
-    0x65, 0x72, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c,
-    0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x05, 0x12, 0x03,
-    0x34, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x01, 0x12, 0x03, 0x34, 0x09,
-    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x03, 0x12, 0x03, 0x34, 0x13, 0x15, 0x0a,
-    0x37, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0a, 0x12, 0x03, 0x37, 0x02, 0x2b, 0x1a, 0x2a, 0x20, 0x54,
+    0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x05, 0x12, 0x04, 0xda,
+    0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x01, 0x12, 0x04, 0xda, 0x01,
+    0x08, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x03, 0x12, 0x04, 0xda, 0x01, 0x12,
+    0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x07, 0x12, 0x04, 0xdc, 0x01, 0x02, 0x1a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x07, 0x05, 0x12, 0x04, 0xdc, 0x01, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x07, 0x01, 0x12, 0x04, 0xdc, 0x01, 0x09, 0x15, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x07, 0x03, 0x12, 0x04, 0xdc, 0x01, 0x18, 0x19, 0x0a, 0x43, 0x0a, 0x04,
+    0x04, 0x08, 0x02, 0x08, 0x12, 0x04, 0xdf, 0x01, 0x02, 0x15, 0x1a, 0x35, 0x20, 0x4f, 0x72, 0x64,
+    0x69, 0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65,
+    0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x77,
+    0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x05, 0x12, 0x04, 0xdf, 0x01, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x01, 0x12, 0x04, 0xdf, 0x01, 0x09, 0x10, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x03, 0x12, 0x04, 0xdf, 0x01, 0x13, 0x14, 0x0a, 0x38,
+    0x0a, 0x04, 0x04, 0x08, 0x02, 0x09, 0x12, 0x04, 0xe2, 0x01, 0x02, 0x2b, 0x1a, 0x2a, 0x20, 0x54,
     0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
     0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a,
-    0x06, 0x12, 0x03, 0x37, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x01, 0x12,
-    0x03, 0x37, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x03, 0x12, 0x03, 0x37,
-    0x28, 0x2a, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
+    0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x09,
+    0x06, 0x12, 0x04, 0xe2, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x09, 0x01,
+    0x12, 0x04, 0xe2, 0x01, 0x1c, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x09, 0x03, 0x12,
+    0x04, 0xe2, 0x01, 0x28, 0x2a, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x06, 0xe5, 0x01, 0x00,
+    0xe7, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12, 0x04, 0xe5, 0x01, 0x08, 0x16,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x04, 0xe6, 0x01, 0x02, 0x2d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x04, 0x12, 0x04, 0xe6, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x00, 0x06, 0x12, 0x04, 0xe6, 0x01, 0x0b, 0x18, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe6, 0x01, 0x19, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe6, 0x01, 0x2b, 0x2c, 0x0a, 0x85, 0x01, 0x0a, 0x02, 0x04,
+    0x0a, 0x12, 0x06, 0xeb, 0x01, 0x00, 0x84, 0x02, 0x01, 0x1a, 0x77, 0x20, 0x54, 0x72, 0x61, 0x6e,
+    0x73, 0x66, 0x65, 0x72, 0x52, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6e, 0x20,
+    0x45, 0x52, 0x43, 0x32, 0x33, 0x30, 0x39, 0x20, 0x60, 0x43, 0x6f, 0x6e, 0x73, 0x65, 0x63, 0x75,
+    0x74, 0x69, 0x76, 0x65, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x60, 0x20, 0x63, 0x6f,
+    0x76, 0x65, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x6f, 0x20, 0x6d, 0x61, 0x6e, 0x79, 0x20,
+    0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x62, 0x65, 0x20, 0x65, 0x78, 0x70,
+    0x61, 0x6e, 0x64, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x74, 0x6f, 0x0a, 0x20, 0x69, 0x6e, 0x64, 0x69,
+    0x76, 0x69, 0x64, 0x75, 0x61, 0x6c, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x73,
+    0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x04, 0xeb, 0x01, 0x08, 0x15, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12, 0x04, 0xec, 0x01, 0x02, 0x1d, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x00, 0x05, 0x12, 0x04, 0xec, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x04, 0xec, 0x01, 0x08, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x00, 0x03, 0x12, 0x04, 0xec, 0x01, 0x1b, 0x1c, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x0a,
+    0x02, 0x01, 0x12, 0x04, 0xef, 0x01, 0x02, 0x1a, 0x1a, 0x2a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x66,
+    0x69, 0x72, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x2c, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x73, 0x69,
+    0x76, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x05, 0x12, 0x04, 0xef,
+    0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x01, 0x12, 0x04, 0xef, 0x01,
+    0x08, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x03, 0x12, 0x04, 0xef, 0x01, 0x18,
+    0x19, 0x0a, 0x37, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12, 0x04, 0xf2, 0x01, 0x02, 0x18, 0x1a,
+    0x29, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x2c, 0x20, 0x69,
+    0x6e, 0x63, 0x6c, 0x75, 0x73, 0x69, 0x76, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0xf2, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0xf2, 0x01, 0x08, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0xf2, 0x01, 0x16, 0x17, 0x0a, 0x5b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x03, 0x12,
+    0x04, 0xf5, 0x01, 0x02, 0x19, 0x1a, 0x4d, 0x20, 0x54, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f,
+    0x75, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x65, 0x76,
+    0x65, 0x72, 0x79, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x72, 0x61, 0x6e, 0x67, 0x65, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x7a, 0x65, 0x72, 0x6f,
+    0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x6d, 0x69, 0x6e,
+    0x74, 0x73, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x05, 0x12, 0x04, 0xf5,
+    0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x04, 0xf5, 0x01,
+    0x08, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x04, 0xf5, 0x01, 0x17,
+    0x18, 0x0a, 0x43, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x04, 0x12, 0x04, 0xf8, 0x01, 0x02, 0x17, 0x1a,
+    0x35, 0x20, 0x54, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x74, 0x68,
+    0x61, 0x74, 0x20, 0x72, 0x65, 0x63, 0x65, 0x69, 0x76, 0x65, 0x64, 0x20, 0x65, 0x76, 0x65, 0x72,
+    0x79, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x72,
+    0x61, 0x6e, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x05, 0x12,
+    0x04, 0xf8, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04,
+    0xf8, 0x01, 0x08, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0xf8,
+    0x01, 0x15, 0x16, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x05, 0x12, 0x04, 0xfb, 0x01, 0x02,
+    0x14, 0x1a, 0x27, 0x20, 0x54, 0x78, 0x48, 0x61, 0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72,
+    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x05, 0x05, 0x12, 0x04, 0xfb, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x05, 0x01, 0x12, 0x04, 0xfb, 0x01, 0x08, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05,
+    0x03, 0x12, 0x04, 0xfb, 0x01, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x06, 0x12,
+    0x04, 0xfd, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x05, 0x12, 0x04,
+    0xfd, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x01, 0x12, 0x04, 0xfd,
+    0x01, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x03, 0x12, 0x04, 0xfd, 0x01,
+    0x18, 0x19, 0x0a, 0x43, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x07, 0x12, 0x04, 0x80, 0x02, 0x02, 0x15,
+    0x1a, 0x35, 0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65,
+    0x76, 0x65, 0x6e, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x05,
+    0x12, 0x04, 0x80, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x01, 0x12,
+    0x04, 0x80, 0x02, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x07, 0x03, 0x12, 0x04,
+    0x80, 0x02, 0x13, 0x14, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x08, 0x12, 0x04, 0x83, 0x02,
+    0x02, 0x2a, 0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x08, 0x06, 0x12, 0x04, 0x83, 0x02, 0x02, 0x1b, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x08, 0x01, 0x12, 0x04, 0x83, 0x02, 0x1c, 0x25, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x08, 0x03, 0x12, 0x04, 0x83, 0x02, 0x28, 0x29, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x0b, 0x12, 0x06, 0x86, 0x02, 0x00, 0x88, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0b, 0x01,
+    0x12, 0x04, 0x86, 0x02, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x04,
+    0x87, 0x02, 0x02, 0x32, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x04, 0x12, 0x04, 0x87,
+    0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x06, 0x12, 0x04, 0x87, 0x02,
+    0x0b, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x04, 0x87, 0x02, 0x1b,
+    0x2d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x04, 0x87, 0x02, 0x30, 0x31,
+    0x0a, 0xc1, 0x01, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x06, 0x8c, 0x02, 0x00, 0x9f, 0x02, 0x01, 0x1a,
+    0xb2, 0x01, 0x20, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x52, 0x65, 0x66, 0x72, 0x65,
+    0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x45, 0x52, 0x43, 0x34, 0x39, 0x30, 0x36,
+    0x20, 0x60, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65,
+    0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x42, 0x61, 0x74, 0x63, 0x68, 0x4d, 0x65, 0x74, 0x61, 0x64,
+    0x61, 0x74, 0x61, 0x55, 0x70, 0x64, 0x61, 0x74, 0x65, 0x60, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x20, 0x6f, 0x66, 0x20, 0x65, 0x76, 0x65, 0x72,
+    0x79, 0x0a, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x60, 0x66,
+    0x72, 0x6f, 0x6d, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x60, 0x20, 0x74, 0x6f,
+    0x20, 0x60, 0x74, 0x6f, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x60, 0x20, 0x63,
+    0x68, 0x61, 0x6e, 0x67, 0x65, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x73, 0x68, 0x6f, 0x75, 0x6c,
+    0x64, 0x20, 0x62, 0x65, 0x20, 0x66, 0x65, 0x74, 0x63, 0x68, 0x65, 0x64, 0x20, 0x61, 0x67, 0x61,
+    0x69, 0x6e, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12, 0x04, 0x8c, 0x02, 0x08,
+    0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x00, 0x12, 0x04, 0x8d, 0x02, 0x02, 0x1d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8d, 0x02, 0x02, 0x07, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8d, 0x02, 0x08, 0x18, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8d, 0x02, 0x1b, 0x1c, 0x0a, 0x36, 0x0a, 0x04,
+    0x04, 0x0c, 0x02, 0x01, 0x12, 0x04, 0x90, 0x02, 0x02, 0x1a, 0x1a, 0x28, 0x20, 0x54, 0x68, 0x65,
+    0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x74, 0x6f, 0x20,
+    0x72, 0x65, 0x66, 0x72, 0x65, 0x73, 0x68, 0x2c, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x73, 0x69,
+    0x76, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x05, 0x12, 0x04, 0x90,
+    0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x01, 0x12, 0x04, 0x90, 0x02,
+    0x08, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03, 0x12, 0x04, 0x90, 0x02, 0x18,
+    0x19, 0x0a, 0x65, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x02, 0x12, 0x04, 0x93, 0x02, 0x02, 0x18, 0x1a,
+    0x57, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x20, 0x74, 0x6f, 0x20, 0x72, 0x65, 0x66, 0x72, 0x65, 0x73, 0x68, 0x2c, 0x20, 0x69, 0x6e, 0x63,
+    0x6c, 0x75, 0x73, 0x69, 0x76, 0x65, 0x2c, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x61, 0x73, 0x20,
+    0x60, 0x66, 0x72, 0x6f, 0x6d, 0x5f, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x60, 0x20,
+    0x66, 0x6f, 0x72, 0x20, 0x61, 0x20, 0x60, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x55,
+    0x70, 0x64, 0x61, 0x74, 0x65, 0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02,
+    0x05, 0x12, 0x04, 0x93, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x01,
+    0x12, 0x04, 0x93, 0x02, 0x08, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x02, 0x03, 0x12,
+    0x04, 0x93, 0x02, 0x16, 0x17, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x03, 0x12, 0x04, 0x96,
+    0x02, 0x02, 0x14, 0x1a, 0x27, 0x20, 0x54, 0x78, 0x48, 0x61, 0x73, 0x68, 0x20, 0x69, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0c, 0x02, 0x03, 0x05, 0x12, 0x04, 0x96, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0c, 0x02, 0x03, 0x01, 0x12, 0x04, 0x96, 0x02, 0x08, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c,
+    0x02, 0x03, 0x03, 0x12, 0x04, 0x96, 0x02, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0c, 0x02,
+    0x04, 0x12, 0x04, 0x98, 0x02, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x04, 0x05,
+    0x12, 0x04, 0x98, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x04, 0x01, 0x12,
+    0x04, 0x98, 0x02, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x04, 0x03, 0x12, 0x04,
+    0x98, 0x02, 0x18, 0x19, 0x0a, 0x43, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x05, 0x12, 0x04, 0x9b, 0x02,
+    0x02, 0x15, 0x1a, 0x35, 0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02,
+    0x05, 0x05, 0x12, 0x04, 0x9b, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x05,
+    0x01, 0x12, 0x04, 0x9b, 0x02, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x05, 0x03,
+    0x12, 0x04, 0x9b, 0x02, 0x13, 0x14, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x06, 0x12, 0x04,
+    0x9e, 0x02, 0x02, 0x2a, 0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
+    0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x06, 0x06, 0x12, 0x04, 0x9e, 0x02, 0x02, 0x1b, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x06, 0x01, 0x12, 0x04, 0x9e, 0x02, 0x1c, 0x25, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0c, 0x02, 0x06, 0x03, 0x12, 0x04, 0x9e, 0x02, 0x28, 0x29, 0x0a, 0x0c, 0x0a,
+    0x02, 0x04, 0x0d, 0x12, 0x06, 0xa1, 0x02, 0x00, 0xa3, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x0d, 0x01, 0x12, 0x04, 0xa1, 0x02, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x00,
+    0x12, 0x04, 0xa2, 0x02, 0x02, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x04, 0x12,
+    0x04, 0xa2, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x06, 0x12, 0x04,
+    0xa2, 0x02, 0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa2,
+    0x02, 0x15, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa2, 0x02,
+    0x23, 0x24, 0x0a, 0x7b, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x06, 0xa7, 0x02, 0x00, 0xbc, 0x02, 0x01,
+    0x1a, 0x6d, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x55, 0x73, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x45, 0x52, 0x43, 0x34, 0x39, 0x30, 0x37, 0x20, 0x75, 0x73, 0x65, 0x72,
+    0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64,
+    0x20, 0x74, 0x6f, 0x20, 0x75, 0x73, 0x65, 0x20, 0x69, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x6f,
+    0x75, 0x74, 0x20, 0x6f, 0x77, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x74, 0x20, 0x75, 0x6e, 0x74,
+    0x69, 0x6c, 0x0a, 0x20, 0x60, 0x65, 0x78, 0x70, 0x69, 0x72, 0x65, 0x73, 0x60, 0x2e, 0x0a, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x04, 0xa7, 0x02, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x0e, 0x02, 0x00, 0x12, 0x04, 0xa8, 0x02, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0xa8, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xa8, 0x02, 0x08, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xa8, 0x02, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12,
+    0x04, 0xaa, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x05, 0x12, 0x04,
+    0xaa, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x01, 0x12, 0x04, 0xaa,
+    0x02, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x03, 0x12, 0x04, 0xaa, 0x02,
+    0x13, 0x14, 0x0a, 0x58, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x02, 0x12, 0x04, 0xad, 0x02, 0x02, 0x11,
+    0x1a, 0x4a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x75,
+    0x73, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2c, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x7a, 0x65, 0x72, 0x6f, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x20, 0x6f, 0x6e, 0x63, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x77,
+    0x61, 0x73, 0x20, 0x72, 0x65, 0x6d, 0x6f, 0x76, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0e, 0x02, 0x02, 0x05, 0x12, 0x04, 0xad, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x02, 0x01, 0x12, 0x04, 0xad, 0x02, 0x08, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0xad, 0x02, 0x0f, 0x10, 0x0a, 0x5f, 0x0a, 0x04, 0x04, 0x0e, 0x02,
+    0x03, 0x12, 0x04, 0xb0, 0x02, 0x02, 0x15, 0x1a, 0x51, 0x20, 0x45, 0x78, 0x70, 0x69, 0x72, 0x65,
+    0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x75, 0x6e, 0x69, 0x78, 0x20, 0x74, 0x69,
+    0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x61, 0x74, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68,
+    0x20, 0x60, 0x75, 0x73, 0x65, 0x72, 0x60, 0x20, 0x6c, 0x6f, 0x73, 0x65, 0x73, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x72, 0x69, 0x67, 0x68, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x75, 0x73, 0x65, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x03, 0x05, 0x12, 0x04, 0xb0, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x03, 0x01, 0x12, 0x04, 0xb0, 0x02, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03,
+    0x03, 0x12, 0x04, 0xb0, 0x02, 0x13, 0x14, 0x0a, 0x51, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x04, 0x12,
+    0x04, 0xb3, 0x02, 0x02, 0x14, 0x1a, 0x43, 0x20, 0x54, 0x78, 0x48, 0x61, 0x73, 0x68, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x68,
+    0x61, 0x74, 0x20, 0x6c, 0x61, 0x73, 0x74, 0x20, 0x75, 0x70, 0x64, 0x61, 0x74, 0x65, 0x64, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x75, 0x73, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x04, 0x05, 0x12, 0x04, 0xb3, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x04, 0x01, 0x12, 0x04, 0xb3, 0x02, 0x08, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04,
+    0x03, 0x12, 0x04, 0xb3, 0x02, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x05, 0x12,
+    0x04, 0xb5, 0x02, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x05, 0x12, 0x04,
+    0xb5, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x01, 0x12, 0x04, 0xb5,
+    0x02, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x03, 0x12, 0x04, 0xb5, 0x02,
+    0x18, 0x19, 0x0a, 0x43, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x06, 0x12, 0x04, 0xb8, 0x02, 0x02, 0x15,
+    0x1a, 0x35, 0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65,
+    0x76, 0x65, 0x6e, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x05,
+    0x12, 0x04, 0xb8, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x01, 0x12,
+    0x04, 0xb8, 0x02, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x03, 0x12, 0x04,
+    0xb8, 0x02, 0x13, 0x14, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x07, 0x12, 0x04, 0xbb, 0x02,
+    0x02, 0x2a, 0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x07, 0x06, 0x12, 0x04, 0xbb, 0x02, 0x02, 0x1b, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x07, 0x01, 0x12, 0x04, 0xbb, 0x02, 0x1c, 0x25, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0e, 0x02, 0x07, 0x03, 0x12, 0x04, 0xbb, 0x02, 0x28, 0x29, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x0f, 0x12, 0x06, 0xbe, 0x02, 0x00, 0xc0, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0f, 0x01,
+    0x12, 0x04, 0xbe, 0x02, 0x08, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x04,
+    0xbf, 0x02, 0x02, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x04, 0x12, 0x04, 0xbf,
+    0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x06, 0x12, 0x04, 0xbf, 0x02,
+    0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x04, 0xbf, 0x02, 0x15,
+    0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x04, 0xbf, 0x02, 0x23, 0x24,
+    0x0a, 0x64, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x06, 0xc3, 0x02, 0x00, 0xd4, 0x02, 0x01, 0x1a, 0x56,
+    0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x4c, 0x6f, 0x63, 0x6b, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6e,
+    0x20, 0x45, 0x52, 0x43, 0x35, 0x31, 0x39, 0x32, 0x20, 0x60, 0x4c, 0x6f, 0x63, 0x6b, 0x65, 0x64,
+    0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x55, 0x6e, 0x6c, 0x6f, 0x63, 0x6b, 0x65, 0x64, 0x60, 0x2c,
+    0x20, 0x61, 0x20, 0x6c, 0x6f, 0x63, 0x6b, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20,
+    0x63, 0x61, 0x6e, 0x27, 0x74, 0x20, 0x62, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65,
+    0x72, 0x72, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12, 0x04, 0xc3,
+    0x02, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x04, 0xc4, 0x02, 0x02,
+    0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x05, 0x12, 0x04, 0xc4, 0x02, 0x02, 0x07,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc4, 0x02, 0x08, 0x18, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc4, 0x02, 0x1b, 0x1c, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x10, 0x02, 0x01, 0x12, 0x04, 0xc6, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x10, 0x02, 0x01, 0x05, 0x12, 0x04, 0xc6, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x10, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc6, 0x02, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0xc6, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02,
+    0x02, 0x12, 0x04, 0xc8, 0x02, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x05,
+    0x12, 0x04, 0xc8, 0x02, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0xc8, 0x02, 0x07, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x03, 0x12, 0x04,
+    0xc8, 0x02, 0x10, 0x11, 0x0a, 0x58, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x03, 0x12, 0x04, 0xcb, 0x02,
+    0x02, 0x14, 0x1a, 0x4a, 0x20, 0x54, 0x78, 0x48, 0x61, 0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
+    0x6c, 0x6f, 0x63, 0x6b, 0x65, 0x64, 0x20, 0x6f, 0x72, 0x20, 0x75, 0x6e, 0x6c, 0x6f, 0x63, 0x6b,
+    0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x05, 0x12, 0x04, 0xcb, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x10, 0x02, 0x03, 0x01, 0x12, 0x04, 0xcb, 0x02, 0x08, 0x0f, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x10, 0x02, 0x03, 0x03, 0x12, 0x04, 0xcb, 0x02, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x10, 0x02, 0x04, 0x12, 0x04, 0xcd, 0x02, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02,
+    0x04, 0x05, 0x12, 0x04, 0xcd, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x04,
+    0x01, 0x12, 0x04, 0xcd, 0x02, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x04, 0x03,
+    0x12, 0x04, 0xcd, 0x02, 0x18, 0x19, 0x0a, 0x43, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x05, 0x12, 0x04,
+    0xd0, 0x02, 0x02, 0x15, 0x1a, 0x35, 0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x10, 0x02, 0x05, 0x05, 0x12, 0x04, 0xd0, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10,
+    0x02, 0x05, 0x01, 0x12, 0x04, 0xd0, 0x02, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02,
+    0x05, 0x03, 0x12, 0x04, 0xd0, 0x02, 0x13, 0x14, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x06,
+    0x12, 0x04, 0xd3, 0x02, 0x02, 0x2a, 0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74,
+    0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x06, 0x06, 0x12, 0x04, 0xd3, 0x02, 0x02,
+    0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x06, 0x01, 0x12, 0x04, 0xd3, 0x02, 0x1c, 0x25,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x06, 0x03, 0x12, 0x04, 0xd3, 0x02, 0x28, 0x29, 0x0a,
+    0x0c, 0x0a, 0x02, 0x04, 0x11, 0x12, 0x06, 0xd6, 0x02, 0x00, 0xd8, 0x02, 0x01, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x11, 0x01, 0x12, 0x04, 0xd6, 0x02, 0x08, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11,
+    0x02, 0x00, 0x12, 0x04, 0xd7, 0x02, 0x02, 0x36, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00,
+    0x04, 0x12, 0x04, 0xd7, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x06,
+    0x12, 0x04, 0xd7, 0x02, 0x0b, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xd7, 0x02, 0x1d, 0x31, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xd7, 0x02, 0x34, 0x35, 0x0a, 0x9a, 0x01, 0x0a, 0x02, 0x04, 0x12, 0x12, 0x06, 0xdc, 0x02, 0x00,
+    0xf8, 0x02, 0x01, 0x1a, 0x8b, 0x01, 0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x42, 0x6f, 0x75, 0x6e,
+    0x64, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x45,
+    0x52, 0x43, 0x36, 0x35, 0x35, 0x31, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x63,
+    0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x61,
+    0x6e, 0x6f, 0x6e, 0x69, 0x63, 0x61, 0x6c, 0x20, 0x72, 0x65, 0x67, 0x69, 0x73, 0x74, 0x72, 0x79,
+    0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69, 0x73,
+    0x20, 0x6f, 0x77, 0x6e, 0x65, 0x64, 0x0a, 0x20, 0x62, 0x79, 0x20, 0x77, 0x68, 0x6f, 0x65, 0x76,
+    0x65, 0x72, 0x20, 0x6f, 0x77, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65,
+    0x6e, 0x20, 0x69, 0x74, 0x27, 0x73, 0x20, 0x62, 0x6f, 0x75, 0x6e, 0x64, 0x20, 0x74, 0x6f, 0x2e,
+    0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x12, 0x01, 0x12, 0x04, 0xdc, 0x02, 0x08, 0x19, 0x0a, 0x47,
+    0x0a, 0x04, 0x04, 0x12, 0x02, 0x00, 0x12, 0x04, 0xde, 0x02, 0x02, 0x14, 0x1a, 0x39, 0x20, 0x41,
+    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64,
+    0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x77, 0x61, 0x73, 0x20, 0x63, 0x72,
+    0x65, 0x61, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x05,
+    0x12, 0x04, 0xde, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xde, 0x02, 0x08, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xde, 0x02, 0x12, 0x13, 0x0a, 0x61, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x01, 0x12, 0x04, 0xe1, 0x02,
+    0x02, 0x1b, 0x1a, 0x53, 0x20, 0x49, 0x6d, 0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x61, 0x74,
+    0x69, 0x6f, 0x6e, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65,
+    0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
+    0x63, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x64,
+    0x65, 0x6c, 0x65, 0x67, 0x61, 0x74, 0x65, 0x73, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6c, 0x6f, 0x67,
+    0x69, 0x63, 0x20, 0x74, 0x6f, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xe1, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xe1, 0x02, 0x08, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xe1, 0x02, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x02, 0x12, 0x04, 0xe3, 0x02,
+    0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x02, 0x05, 0x12, 0x04, 0xe3, 0x02, 0x02,
+    0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x02, 0x01, 0x12, 0x04, 0xe3, 0x02, 0x08, 0x0c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x02, 0x03, 0x12, 0x04, 0xe3, 0x02, 0x0f, 0x10, 0x0a,
+    0x89, 0x01, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x03, 0x12, 0x04, 0xe7, 0x02, 0x02, 0x16, 0x1a, 0x7b,
+    0x20, 0x43, 0x68, 0x61, 0x69, 0x6e, 0x49, 0x44, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b,
+    0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69,
+    0x73, 0x20, 0x62, 0x6f, 0x75, 0x6e, 0x64, 0x20, 0x74, 0x6f, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63,
+    0x68, 0x20, 0x63, 0x61, 0x6e, 0x20, 0x62, 0x65, 0x20, 0x61, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72,
+    0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x6e, 0x0a, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x6f, 0x6e, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+    0x20, 0x6c, 0x69, 0x76, 0x65, 0x73, 0x20, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x12, 0x02, 0x03, 0x05, 0x12, 0x04, 0xe7, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12,
+    0x02, 0x03, 0x01, 0x12, 0x04, 0xe7, 0x02, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02,
+    0x03, 0x03, 0x12, 0x04, 0xe7, 0x02, 0x14, 0x15, 0x0a, 0x68, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x04,
+    0x12, 0x04, 0xea, 0x02, 0x02, 0x1d, 0x1a, 0x5a, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63,
+    0x74, 0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e,
+    0x46, 0x54, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x20, 0x69, 0x73, 0x20, 0x62, 0x6f, 0x75, 0x6e, 0x64, 0x20, 0x74, 0x6f,
+    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x04, 0x05, 0x12, 0x04, 0xea, 0x02, 0x02,
+    0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x04, 0x01, 0x12, 0x04, 0xea, 0x02, 0x08, 0x18,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x04, 0x03, 0x12, 0x04, 0xea, 0x02, 0x1b, 0x1c, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x05, 0x12, 0x04, 0xec, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x12, 0x02, 0x05, 0x05, 0x12, 0x04, 0xec, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x12, 0x02, 0x05, 0x01, 0x12, 0x04, 0xec, 0x02, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x12, 0x02, 0x05, 0x03, 0x12, 0x04, 0xec, 0x02, 0x13, 0x14, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x12,
+    0x02, 0x06, 0x12, 0x04, 0xef, 0x02, 0x02, 0x14, 0x1a, 0x27, 0x20, 0x54, 0x78, 0x48, 0x61, 0x73,
+    0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x06, 0x05, 0x12, 0x04, 0xef, 0x02, 0x02, 0x07,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x06, 0x01, 0x12, 0x04, 0xef, 0x02, 0x08, 0x0f, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x06, 0x03, 0x12, 0x04, 0xef, 0x02, 0x12, 0x13, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x12, 0x02, 0x07, 0x12, 0x04, 0xf1, 0x02, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x12, 0x02, 0x07, 0x05, 0x12, 0x04, 0xf1, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x12, 0x02, 0x07, 0x01, 0x12, 0x04, 0xf1, 0x02, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12,
+    0x02, 0x07, 0x03, 0x12, 0x04, 0xf1, 0x02, 0x18, 0x19, 0x0a, 0x43, 0x0a, 0x04, 0x04, 0x12, 0x02,
+    0x08, 0x12, 0x04, 0xf4, 0x02, 0x02, 0x15, 0x1a, 0x35, 0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e, 0x61,
+    0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68,
+    0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x12, 0x02, 0x08, 0x05, 0x12, 0x04, 0xf4, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x12, 0x02, 0x08, 0x01, 0x12, 0x04, 0xf4, 0x02, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x12, 0x02, 0x08, 0x03, 0x12, 0x04, 0xf4, 0x02, 0x13, 0x14, 0x0a, 0x38, 0x0a, 0x04, 0x04,
+    0x12, 0x02, 0x09, 0x12, 0x04, 0xf7, 0x02, 0x02, 0x2b, 0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65,
+    0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d,
+    0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x09, 0x06, 0x12, 0x04,
+    0xf7, 0x02, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x09, 0x01, 0x12, 0x04, 0xf7,
+    0x02, 0x1c, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x09, 0x03, 0x12, 0x04, 0xf7, 0x02,
+    0x28, 0x2a, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x13, 0x12, 0x06, 0xfa, 0x02, 0x00, 0xfc, 0x02, 0x01,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x13, 0x01, 0x12, 0x04, 0xfa, 0x02, 0x08, 0x1f, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x13, 0x02, 0x00, 0x12, 0x04, 0xfb, 0x02, 0x02, 0x3f, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x13, 0x02, 0x00, 0x04, 0x12, 0x04, 0xfb, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13,
+    0x02, 0x00, 0x06, 0x12, 0x04, 0xfb, 0x02, 0x0b, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xfb, 0x02, 0x22, 0x3a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xfb, 0x02, 0x3d, 0x3e, 0x0a, 0x6d, 0x0a, 0x02, 0x04, 0x14, 0x12, 0x06, 0xff,
+    0x02, 0x00, 0x9d, 0x03, 0x01, 0x1a, 0x5f, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
+    0x43, 0x6c, 0x61, 0x73, 0x73, 0x69, 0x66, 0x69, 0x63, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x64,
+    0x65, 0x74, 0x65, 0x63, 0x74, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63,
+    0x6f, 0x64, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63,
+    0x74, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x69, 0x74, 0x27, 0x73, 0x20, 0x64, 0x65, 0x70, 0x6c,
+    0x6f, 0x79, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x14, 0x01, 0x12, 0x04, 0xff,
+    0x02, 0x08, 0x1e, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x14, 0x04, 0x00, 0x12, 0x06, 0x80, 0x03, 0x02,
+    0x88, 0x03, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x04, 0x00, 0x01, 0x12, 0x04, 0x80, 0x03,
+    0x07, 0x0f, 0x0a, 0x66, 0x0a, 0x06, 0x04, 0x14, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0x82, 0x03,
+    0x04, 0x10, 0x1a, 0x56, 0x20, 0x4e, 0x6f, 0x20, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64,
+    0x20, 0x77, 0x61, 0x73, 0x20, 0x72, 0x65, 0x63, 0x6f, 0x67, 0x6e, 0x69, 0x7a, 0x65, 0x64, 0x20,
+    0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x64, 0x65, 0x2c, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x63, 0x61, 0x6e, 0x27, 0x74, 0x20,
+    0x62, 0x65, 0x20, 0x74, 0x72, 0x75, 0x73, 0x74, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x62, 0x65,
+    0x20, 0x61, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x14,
+    0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x82, 0x03, 0x04, 0x0b, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x14, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x82, 0x03, 0x0e, 0x0f, 0x0a, 0x0e, 0x0a, 0x06,
+    0x04, 0x14, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x83, 0x03, 0x04, 0x0e, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x14, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x83, 0x03, 0x04, 0x09, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x14, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x83, 0x03, 0x0c, 0x0d, 0x0a, 0x0e,
+    0x0a, 0x06, 0x04, 0x14, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0x84, 0x03, 0x04, 0x0f, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x14, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x84, 0x03, 0x04, 0x0a, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x14, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x84, 0x03, 0x0d, 0x0e,
+    0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x14, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0x85, 0x03, 0x04, 0x10,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x14, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0x85, 0x03, 0x04,
+    0x0b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x14, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0x85, 0x03,
+    0x0e, 0x0f, 0x0a, 0x62, 0x0a, 0x06, 0x04, 0x14, 0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0x87, 0x03,
+    0x04, 0x0e, 0x1a, 0x52, 0x20, 0x54, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x64, 0x65, 0x20, 0x64, 0x65,
+    0x6c, 0x65, 0x67, 0x61, 0x74, 0x65, 0x73, 0x20, 0x69, 0x74, 0x73, 0x20, 0x6c, 0x6f, 0x67, 0x69,
+    0x63, 0x20, 0x74, 0x6f, 0x20, 0x61, 0x6e, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x63, 0x6f, 0x6e,
+    0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x68, 0x61, 0x73, 0x20, 0x6e, 0x6f,
+    0x20, 0x73, 0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x69, 0x74, 0x73,
+    0x20, 0x6f, 0x77, 0x6e, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x14, 0x04, 0x00, 0x02, 0x04,
+    0x01, 0x12, 0x04, 0x87, 0x03, 0x04, 0x09, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x14, 0x04, 0x00, 0x02,
+    0x04, 0x02, 0x12, 0x04, 0x87, 0x03, 0x0c, 0x0d, 0x0a, 0x8b, 0x01, 0x0a, 0x04, 0x04, 0x14, 0x02,
+    0x00, 0x12, 0x04, 0x8c, 0x03, 0x02, 0x16, 0x1a, 0x7d, 0x20, 0x53, 0x74, 0x61, 0x6e, 0x64, 0x61,
+    0x72, 0x64, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67,
+    0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20,
+    0x6f, 0x66, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e,
+    0x75, 0x6d, 0x20, 0x64, 0x65, 0x66, 0x69, 0x6e, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x53, 0x74,
+    0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x2c, 0x20, 0x73, 0x65, 0x65, 0x0a, 0x20, 0x60, 0x54, 0x72,
+    0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2e, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61, 0x60, 0x20, 0x66,
+    0x6f, 0x72, 0x20, 0x77, 0x68, 0x79, 0x20, 0x69, 0x74, 0x27, 0x73, 0x20, 0x61, 0x20, 0x73, 0x74,
+    0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x05, 0x12,
+    0x04, 0x8c, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0x8c, 0x03, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8c,
+    0x03, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x01, 0x12, 0x04, 0x8e, 0x03, 0x02,
+    0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x05, 0x12, 0x04, 0x8e, 0x03, 0x02, 0x07,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8e, 0x03, 0x08, 0x18, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8e, 0x03, 0x1b, 0x1c, 0x0a, 0x4e,
+    0x0a, 0x04, 0x04, 0x14, 0x02, 0x02, 0x12, 0x04, 0x91, 0x03, 0x02, 0x15, 0x1a, 0x40, 0x20, 0x44,
+    0x65, 0x70, 0x6c, 0x6f, 0x79, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61,
+    0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61,
+    0x63, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x72, 0x65, 0x61, 0x74, 0x65, 0x64, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x05, 0x12, 0x04, 0x91, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x14, 0x02, 0x02, 0x01, 0x12, 0x04, 0x91, 0x03, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x14, 0x02, 0x02, 0x03, 0x12, 0x04, 0x91, 0x03, 0x13, 0x14, 0x0a, 0x35, 0x0a, 0x04, 0x04,
+    0x14, 0x02, 0x03, 0x12, 0x04, 0x94, 0x03, 0x02, 0x14, 0x1a, 0x27, 0x20, 0x54, 0x78, 0x48, 0x61,
+    0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x05, 0x12, 0x04, 0x94, 0x03, 0x02,
+    0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x01, 0x12, 0x04, 0x94, 0x03, 0x08, 0x0f,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x03, 0x12, 0x04, 0x94, 0x03, 0x12, 0x13, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x04, 0x12, 0x04, 0x96, 0x03, 0x02, 0x1a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x14, 0x02, 0x04, 0x05, 0x12, 0x04, 0x96, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x14, 0x02, 0x04, 0x01, 0x12, 0x04, 0x96, 0x03, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x14, 0x02, 0x04, 0x03, 0x12, 0x04, 0x96, 0x03, 0x18, 0x19, 0x0a, 0x4b, 0x0a, 0x04, 0x04, 0x14,
+    0x02, 0x05, 0x12, 0x04, 0x99, 0x03, 0x02, 0x15, 0x1a, 0x3d, 0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61,
+    0x6c, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x64, 0x65, 0x20, 0x63, 0x68,
+    0x61, 0x6e, 0x67, 0x65, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x05, 0x05,
+    0x12, 0x04, 0x99, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x05, 0x01, 0x12,
+    0x04, 0x99, 0x03, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x05, 0x03, 0x12, 0x04,
+    0x99, 0x03, 0x13, 0x14, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x06, 0x12, 0x04, 0x9c, 0x03,
+    0x02, 0x2a, 0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x14, 0x02, 0x06, 0x06, 0x12, 0x04, 0x9c, 0x03, 0x02, 0x1b, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x14, 0x02, 0x06, 0x01, 0x12, 0x04, 0x9c, 0x03, 0x1c, 0x25, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x14, 0x02, 0x06, 0x03, 0x12, 0x04, 0x9c, 0x03, 0x28, 0x29, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x15, 0x12, 0x06, 0x9f, 0x03, 0x00, 0xa1, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x15, 0x01,
+    0x12, 0x04, 0x9f, 0x03, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x00, 0x12, 0x04,
+    0xa0, 0x03, 0x02, 0x31, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x04, 0x12, 0x04, 0xa0,
+    0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x06, 0x12, 0x04, 0xa0, 0x03,
+    0x0b, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa0, 0x03, 0x1b,
+    0x2c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa0, 0x03, 0x2f, 0x30,
+    0x0a, 0x5e, 0x0a, 0x02, 0x04, 0x16, 0x12, 0x06, 0xa4, 0x03, 0x00, 0xca, 0x03, 0x01, 0x1a, 0x50,
+    0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x55, 0x70, 0x67, 0x72, 0x61, 0x64, 0x65,
+    0x20, 0x69, 0x73, 0x20, 0x61, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x6c, 0x6f, 0x67, 0x69, 0x63, 0x20, 0x6f, 0x72, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x6d, 0x69, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x61, 0x6e, 0x20,
+    0x45, 0x49, 0x50, 0x2d, 0x31, 0x39, 0x36, 0x37, 0x20, 0x70, 0x72, 0x6f, 0x78, 0x79, 0x2e, 0x0a,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x16, 0x01, 0x12, 0x04, 0xa4, 0x03, 0x08, 0x17, 0x0a, 0x0e, 0x0a,
+    0x04, 0x04, 0x16, 0x04, 0x00, 0x12, 0x06, 0xa5, 0x03, 0x02, 0xaa, 0x03, 0x03, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x16, 0x04, 0x00, 0x01, 0x12, 0x04, 0xa5, 0x03, 0x07, 0x0b, 0x0a, 0x3e, 0x0a, 0x06,
+    0x04, 0x16, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0xa7, 0x03, 0x04, 0x11, 0x1a, 0x2e, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x78, 0x79, 0x20, 0x6e, 0x6f, 0x77, 0x20, 0x64, 0x65, 0x6c,
+    0x65, 0x67, 0x61, 0x74, 0x65, 0x73, 0x20, 0x74, 0x6f, 0x20, 0x60, 0x69, 0x6d, 0x70, 0x6c, 0x65,
+    0x6d, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x60, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x16, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa7, 0x03, 0x04, 0x0c, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x16, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0xa7, 0x03, 0x0f, 0x10, 0x0a, 0x66,
+    0x0a, 0x06, 0x04, 0x16, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0xa9, 0x03, 0x04, 0x16, 0x1a, 0x56,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x61, 0x64, 0x6d, 0x69, 0x6e, 0x20, 0x61, 0x6c, 0x6c, 0x6f, 0x77,
+    0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x75, 0x70, 0x67, 0x72, 0x61, 0x64, 0x65, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x70, 0x72, 0x6f, 0x78, 0x79, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x64, 0x20,
+    0x66, 0x72, 0x6f, 0x6d, 0x20, 0x60, 0x70, 0x72, 0x65, 0x76, 0x69, 0x6f, 0x75, 0x73, 0x5f, 0x61,
+    0x64, 0x6d, 0x69, 0x6e, 0x60, 0x20, 0x74, 0x6f, 0x20, 0x60, 0x6e, 0x65, 0x77, 0x5f, 0x61, 0x64,
+    0x6d, 0x69, 0x6e, 0x60, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x16, 0x04, 0x00, 0x02, 0x01,
+    0x01, 0x12, 0x04, 0xa9, 0x03, 0x04, 0x11, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x16, 0x04, 0x00, 0x02,
+    0x01, 0x02, 0x12, 0x04, 0xa9, 0x03, 0x14, 0x15, 0x0a, 0x83, 0x01, 0x0a, 0x04, 0x04, 0x16, 0x02,
+    0x00, 0x12, 0x04, 0xae, 0x03, 0x02, 0x12, 0x1a, 0x75, 0x20, 0x4b, 0x69, 0x6e, 0x64, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x72, 0x65, 0x70,
+    0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x6f,
+    0x6e, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x75, 0x6d, 0x20, 0x64,
+    0x65, 0x66, 0x69, 0x6e, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x4b, 0x69, 0x6e, 0x64, 0x2c, 0x20,
+    0x73, 0x65, 0x65, 0x20, 0x60, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2e, 0x73, 0x63,
+    0x68, 0x65, 0x6d, 0x61, 0x60, 0x0a, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x77, 0x68, 0x79, 0x20, 0x69,
+    0x74, 0x27, 0x73, 0x20, 0x61, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x05, 0x12, 0x04, 0xae, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x16, 0x02, 0x00, 0x01, 0x12, 0x04, 0xae, 0x03, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x16, 0x02, 0x00, 0x03, 0x12, 0x04, 0xae, 0x03, 0x10, 0x11, 0x0a, 0xca, 0x01, 0x0a, 0x04,
+    0x04, 0x16, 0x02, 0x01, 0x12, 0x04, 0xb2, 0x03, 0x02, 0x14, 0x1a, 0xbb, 0x01, 0x20, 0x53, 0x6f,
+    0x75, 0x72, 0x63, 0x65, 0x20, 0x69, 0x73, 0x20, 0x22, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x22, 0x20,
+    0x77, 0x68, 0x65, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x20,
+    0x63, 0x6f, 0x6d, 0x65, 0x73, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x61, 0x6e, 0x20, 0x60, 0x55,
+    0x70, 0x67, 0x72, 0x61, 0x64, 0x65, 0x64, 0x60, 0x20, 0x6f, 0x72, 0x20, 0x60, 0x41, 0x64, 0x6d,
+    0x69, 0x6e, 0x43, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x64, 0x60, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74,
+    0x2c, 0x20, 0x22, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67, 0x65, 0x22, 0x0a, 0x20, 0x77, 0x68, 0x65,
+    0x6e, 0x20, 0x69, 0x74, 0x20, 0x63, 0x6f, 0x6d, 0x65, 0x73, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20,
+    0x61, 0x20, 0x77, 0x72, 0x69, 0x74, 0x65, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x68, 0x65, 0x20, 0x45,
+    0x49, 0x50, 0x2d, 0x31, 0x39, 0x36, 0x37, 0x20, 0x69, 0x6d, 0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e,
+    0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x73, 0x6c, 0x6f, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68,
+    0x6f, 0x75, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x61, 0x74, 0x63, 0x68, 0x69, 0x6e, 0x67,
+    0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01,
+    0x05, 0x12, 0x04, 0xb2, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x01,
+    0x12, 0x04, 0xb2, 0x03, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x03, 0x12,
+    0x04, 0xb2, 0x03, 0x12, 0x13, 0x0a, 0x3c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x02, 0x12, 0x04, 0xb5,
+    0x03, 0x02, 0x1d, 0x1a, 0x2e, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x41, 0x64,
+    0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x64, 0x64,
+    0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x6f, 0x78,
+    0x79, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x05, 0x12, 0x04, 0xb5, 0x03,
+    0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x01, 0x12, 0x04, 0xb5, 0x03, 0x08,
+    0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x03, 0x12, 0x04, 0xb5, 0x03, 0x1b, 0x1c,
+    0x0a, 0x58, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x03, 0x12, 0x04, 0xb8, 0x03, 0x02, 0x1b, 0x1a, 0x4a,
+    0x20, 0x49, 0x6d, 0x70, 0x6c, 0x65, 0x6d, 0x65, 0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20,
+    0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x77, 0x20, 0x6c, 0x6f, 0x67, 0x69, 0x63,
+    0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2c, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20,
+    0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x60, 0x75,
+    0x70, 0x67, 0x72, 0x61, 0x64, 0x65, 0x64, 0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16,
+    0x02, 0x03, 0x05, 0x12, 0x04, 0xb8, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02,
+    0x03, 0x01, 0x12, 0x04, 0xb8, 0x03, 0x08, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x03,
+    0x03, 0x12, 0x04, 0xb8, 0x03, 0x19, 0x1a, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x04, 0x12,
+    0x04, 0xbb, 0x03, 0x02, 0x1b, 0x1a, 0x25, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x61, 0x76, 0x61,
+    0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x60, 0x61, 0x64, 0x6d, 0x69,
+    0x6e, 0x5f, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x64, 0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x16, 0x02, 0x04, 0x05, 0x12, 0x04, 0xbb, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x16, 0x02, 0x04, 0x01, 0x12, 0x04, 0xbb, 0x03, 0x08, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16,
+    0x02, 0x04, 0x03, 0x12, 0x04, 0xbb, 0x03, 0x19, 0x1a, 0x0a, 0x33, 0x0a, 0x04, 0x04, 0x16, 0x02,
+    0x05, 0x12, 0x04, 0xbe, 0x03, 0x02, 0x16, 0x1a, 0x25, 0x20, 0x4f, 0x6e, 0x6c, 0x79, 0x20, 0x61,
+    0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x60, 0x61, 0x64,
+    0x6d, 0x69, 0x6e, 0x5f, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x64, 0x60, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x16, 0x02, 0x05, 0x05, 0x12, 0x04, 0xbe, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x16, 0x02, 0x05, 0x01, 0x12, 0x04, 0xbe, 0x03, 0x08, 0x11, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x16, 0x02, 0x05, 0x03, 0x12, 0x04, 0xbe, 0x03, 0x14, 0x15, 0x0a, 0x35, 0x0a, 0x04, 0x04,
+    0x16, 0x02, 0x06, 0x12, 0x04, 0xc1, 0x03, 0x02, 0x14, 0x1a, 0x27, 0x20, 0x54, 0x78, 0x48, 0x61,
+    0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x06, 0x05, 0x12, 0x04, 0xc1, 0x03, 0x02,
+    0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x06, 0x01, 0x12, 0x04, 0xc1, 0x03, 0x08, 0x0f,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x06, 0x03, 0x12, 0x04, 0xc1, 0x03, 0x12, 0x13, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x07, 0x12, 0x04, 0xc3, 0x03, 0x02, 0x1a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x16, 0x02, 0x07, 0x05, 0x12, 0x04, 0xc3, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x16, 0x02, 0x07, 0x01, 0x12, 0x04, 0xc3, 0x03, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x16, 0x02, 0x07, 0x03, 0x12, 0x04, 0xc3, 0x03, 0x18, 0x19, 0x0a, 0x5e, 0x0a, 0x04, 0x04, 0x16,
+    0x02, 0x08, 0x12, 0x04, 0xc6, 0x03, 0x02, 0x15, 0x1a, 0x50, 0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20,
+    0x69, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x76, 0x65,
+    0x6e, 0x74, 0x2c, 0x20, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x6f, 0x72, 0x61, 0x67,
+    0x65, 0x20, 0x63, 0x68, 0x61, 0x6e, 0x67, 0x65, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16,
+    0x02, 0x08, 0x05, 0x12, 0x04, 0xc6, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02,
+    0x08, 0x01, 0x12, 0x04, 0xc6, 0x03, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x08,
+    0x03, 0x12, 0x04, 0xc6, 0x03, 0x13, 0x14, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x09, 0x12,
+    0x04, 0xc9, 0x03, 0x02, 0x2b, 0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d,
+    0x70, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61,
+    0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x09, 0x06, 0x12, 0x04, 0xc9, 0x03, 0x02, 0x1b,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x09, 0x01, 0x12, 0x04, 0xc9, 0x03, 0x1c, 0x25, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x09, 0x03, 0x12, 0x04, 0xc9, 0x03, 0x28, 0x2a, 0x0a, 0x0c,
+    0x0a, 0x02, 0x04, 0x17, 0x12, 0x06, 0xcc, 0x03, 0x00, 0xce, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x17, 0x01, 0x12, 0x04, 0xcc, 0x03, 0x08, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02,
+    0x00, 0x12, 0x04, 0xcd, 0x03, 0x02, 0x35, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x04,
+    0x12, 0x04, 0xcd, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x06, 0x12,
+    0x04, 0xcd, 0x03, 0x0b, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0xcd, 0x03, 0x1d, 0x30, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x03, 0x12, 0x04, 0xcd,
+    0x03, 0x33, 0x34, 0x0a, 0xad, 0x01, 0x0a, 0x02, 0x04, 0x18, 0x12, 0x06, 0xd2, 0x03, 0x00, 0xe5,
+    0x03, 0x01, 0x1a, 0x9e, 0x01, 0x20, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x54,
+    0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x4f, 0x77,
+    0x6e, 0x61, 0x62, 0x6c, 0x65, 0x20, 0x60, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70,
+    0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x72, 0x65, 0x64, 0x60, 0x20, 0x65, 0x6d, 0x69,
+    0x74, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x6e, 0x20, 0x4e, 0x46, 0x54, 0x20, 0x63,
+    0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x77, 0x6e,
+    0x65, 0x72, 0x20, 0x69, 0x73, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75,
+    0x6e, 0x74, 0x20, 0x6d, 0x61, 0x72, 0x6b, 0x65, 0x74, 0x70, 0x6c, 0x61, 0x63, 0x65, 0x73, 0x20,
+    0x74, 0x72, 0x75, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x63, 0x6f, 0x6e, 0x66, 0x69, 0x67, 0x75,
+    0x72, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6c, 0x6c, 0x65, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x18, 0x01, 0x12, 0x04, 0xd2, 0x03, 0x08, 0x19,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x00, 0x12, 0x04, 0xd3, 0x03, 0x02, 0x1d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x05, 0x12, 0x04, 0xd3, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x18, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd3, 0x03, 0x08, 0x18, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x18, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd3, 0x03, 0x1b, 0x1c, 0x0a, 0x64, 0x0a, 0x04, 0x04,
+    0x18, 0x02, 0x01, 0x12, 0x04, 0xd6, 0x03, 0x02, 0x1b, 0x1a, 0x56, 0x20, 0x54, 0x68, 0x65, 0x20,
+    0x6f, 0x77, 0x6e, 0x65, 0x72, 0x20, 0x62, 0x65, 0x66, 0x6f, 0x72, 0x65, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x7a,
+    0x65, 0x72, 0x6f, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x77, 0x68, 0x65, 0x6e,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x77, 0x61,
+    0x73, 0x20, 0x6a, 0x75, 0x73, 0x74, 0x20, 0x64, 0x65, 0x70, 0x6c, 0x6f, 0x79, 0x65, 0x64, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x05, 0x12, 0x04, 0xd6, 0x03, 0x02, 0x07,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd6, 0x03, 0x08, 0x16, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x03, 0x12, 0x04, 0xd6, 0x03, 0x19, 0x1a, 0x0a, 0x5c,
+    0x0a, 0x04, 0x04, 0x18, 0x02, 0x02, 0x12, 0x04, 0xd9, 0x03, 0x02, 0x16, 0x1a, 0x4e, 0x20, 0x54,
+    0x68, 0x65, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x20, 0x61, 0x66, 0x74, 0x65, 0x72, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2c, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x7a, 0x65, 0x72, 0x6f, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x77, 0x68,
+    0x65, 0x6e, 0x20, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x20, 0x77, 0x61, 0x73,
+    0x20, 0x72, 0x65, 0x6e, 0x6f, 0x75, 0x6e, 0x63, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x18, 0x02, 0x02, 0x05, 0x12, 0x04, 0xd9, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x18, 0x02, 0x02, 0x01, 0x12, 0x04, 0xd9, 0x03, 0x08, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0xd9, 0x03, 0x14, 0x15, 0x0a, 0x35, 0x0a, 0x04, 0x04, 0x18, 0x02,
+    0x03, 0x12, 0x04, 0xdc, 0x03, 0x02, 0x14, 0x1a, 0x27, 0x20, 0x54, 0x78, 0x48, 0x61, 0x73, 0x68,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x03, 0x05, 0x12, 0x04, 0xdc, 0x03, 0x02, 0x07, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x03, 0x01, 0x12, 0x04, 0xdc, 0x03, 0x08, 0x0f, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x18, 0x02, 0x03, 0x03, 0x12, 0x04, 0xdc, 0x03, 0x12, 0x13, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x18, 0x02, 0x04, 0x12, 0x04, 0xde, 0x03, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x18, 0x02, 0x04, 0x05, 0x12, 0x04, 0xde, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18,
+    0x02, 0x04, 0x01, 0x12, 0x04, 0xde, 0x03, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02,
+    0x04, 0x03, 0x12, 0x04, 0xde, 0x03, 0x18, 0x19, 0x0a, 0x43, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x05,
+    0x12, 0x04, 0xe1, 0x03, 0x02, 0x15, 0x1a, 0x35, 0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c,
+    0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69,
+    0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x18, 0x02, 0x05, 0x05, 0x12, 0x04, 0xe1, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x18, 0x02, 0x05, 0x01, 0x12, 0x04, 0xe1, 0x03, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x18, 0x02, 0x05, 0x03, 0x12, 0x04, 0xe1, 0x03, 0x13, 0x14, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x18,
+    0x02, 0x06, 0x12, 0x04, 0xe4, 0x03, 0x02, 0x2a, 0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73,
+    0x74, 0x61, 0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65,
+    0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f,
+    0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x06, 0x06, 0x12, 0x04, 0xe4,
+    0x03, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x06, 0x01, 0x12, 0x04, 0xe4, 0x03,
+    0x1c, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x06, 0x03, 0x12, 0x04, 0xe4, 0x03, 0x28,
+    0x29, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x19, 0x12, 0x06, 0xe7, 0x03, 0x00, 0xe9, 0x03, 0x01, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x19, 0x01, 0x12, 0x04, 0xe7, 0x03, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x19, 0x02, 0x00, 0x12, 0x04, 0xe8, 0x03, 0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19,
+    0x02, 0x00, 0x04, 0x12, 0x04, 0xe8, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02,
+    0x00, 0x06, 0x12, 0x04, 0xe8, 0x03, 0x0b, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xe8, 0x03, 0x17, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0xe8, 0x03, 0x26, 0x27, 0x0a, 0x5a, 0x0a, 0x02, 0x04, 0x1a, 0x12, 0x06, 0xec, 0x03,
+    0x00, 0x85, 0x04, 0x01, 0x1a, 0x4c, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69,
+    0x6f, 0x6e, 0x20, 0x69, 0x73, 0x20, 0x61, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e,
+    0x65, 0x64, 0x20, 0x61, 0x74, 0x20, 0x6c, 0x65, 0x61, 0x73, 0x74, 0x20, 0x6f, 0x6e, 0x65, 0x20,
+    0x74, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x20, 0x6f, 0x72, 0x20, 0x73, 0x61, 0x6c, 0x65,
+    0x2e, 0x0a, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1a, 0x01, 0x12, 0x04, 0xec, 0x03, 0x08, 0x13, 0x0a,
+    0x33, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x00, 0x12, 0x04, 0xee, 0x03, 0x02, 0x11, 0x1a, 0x25, 0x20,
+    0x48, 0x61, 0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x05, 0x12, 0x04, 0xee,
+    0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x01, 0x12, 0x04, 0xee, 0x03,
+    0x08, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x03, 0x12, 0x04, 0xee, 0x03, 0x0f,
+    0x10, 0x0a, 0x52, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x01, 0x12, 0x04, 0xf1, 0x03, 0x02, 0x19, 0x1a,
+    0x44, 0x20, 0x54, 0x68, 0x65, 0x20, 0x65, 0x78, 0x74, 0x65, 0x72, 0x6e, 0x61, 0x6c, 0x6c, 0x79,
+    0x20, 0x6f, 0x77, 0x6e, 0x65, 0x64, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x74,
+    0x68, 0x61, 0x74, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x73,
+    0x65, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x05, 0x12, 0x04,
+    0xf1, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x01, 0x12, 0x04, 0xf1,
+    0x03, 0x08, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x03, 0x12, 0x04, 0xf1, 0x03,
+    0x17, 0x18, 0x0a, 0x67, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x02, 0x12, 0x04, 0xf4, 0x03, 0x02, 0x17,
+    0x1a, 0x59, 0x20, 0x54, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6f,
+    0x72, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x77, 0x61, 0x73, 0x20, 0x73,
+    0x65, 0x6e, 0x74, 0x20, 0x74, 0x6f, 0x2c, 0x20, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x20, 0x77, 0x68,
+    0x65, 0x6e, 0x20, 0x69, 0x74, 0x20, 0x64, 0x65, 0x70, 0x6c, 0x6f, 0x79, 0x65, 0x64, 0x20, 0x61,
+    0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1a, 0x02, 0x02, 0x05, 0x12, 0x04, 0xf4, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a,
+    0x02, 0x02, 0x01, 0x12, 0x04, 0xf4, 0x03, 0x08, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02,
+    0x02, 0x03, 0x12, 0x04, 0xf4, 0x03, 0x15, 0x16, 0x0a, 0x4d, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x03,
+    0x12, 0x04, 0xf7, 0x03, 0x02, 0x13, 0x1a, 0x3f, 0x20, 0x56, 0x61, 0x6c, 0x75, 0x65, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6f, 0x66, 0x20,
+    0x45, 0x54, 0x48, 0x20, 0x73, 0x65, 0x6e, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2c, 0x20, 0x69,
+    0x6e, 0x20, 0x77, 0x65, 0x69, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x03, 0x05,
+    0x12, 0x04, 0xf7, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x03, 0x01, 0x12,
+    0x04, 0xf7, 0x03, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x03, 0x03, 0x12, 0x04,
+    0xf7, 0x03, 0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x04, 0x12, 0x04, 0xf9, 0x03,
+    0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x04, 0x05, 0x12, 0x04, 0xf9, 0x03, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x04, 0x01, 0x12, 0x04, 0xf9, 0x03, 0x09, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x04, 0x03, 0x12, 0x04, 0xf9, 0x03, 0x14, 0x15, 0x0a,
+    0x5f, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x05, 0x12, 0x04, 0xfc, 0x03, 0x02, 0x21, 0x1a, 0x51, 0x20,
+    0x45, 0x66, 0x66, 0x65, 0x63, 0x74, 0x69, 0x76, 0x65, 0x47, 0x61, 0x73, 0x50, 0x72, 0x69, 0x63,
+    0x65, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x69, 0x63, 0x65, 0x20, 0x70,
+    0x61, 0x69, 0x64, 0x20, 0x70, 0x65, 0x72, 0x20, 0x75, 0x6e, 0x69, 0x74, 0x20, 0x6f, 0x66, 0x20,
+    0x67, 0x61, 0x73, 0x2c, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x65, 0x69, 0x2c, 0x20, 0x62, 0x61, 0x73,
+    0x65, 0x20, 0x66, 0x65, 0x65, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64, 0x65, 0x64, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x05, 0x05, 0x12, 0x04, 0xfc, 0x03, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x05, 0x01, 0x12, 0x04, 0xfc, 0x03, 0x09, 0x1c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1a, 0x02, 0x05, 0x03, 0x12, 0x04, 0xfc, 0x03, 0x1f, 0x20, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x1a, 0x02, 0x06, 0x12, 0x04, 0xfe, 0x03, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1a, 0x02, 0x06, 0x05, 0x12, 0x04, 0xfe, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a,
+    0x02, 0x06, 0x01, 0x12, 0x04, 0xfe, 0x03, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02,
+    0x06, 0x03, 0x12, 0x04, 0xfe, 0x03, 0x18, 0x19, 0x0a, 0x4a, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x07,
+    0x12, 0x04, 0x81, 0x04, 0x02, 0x13, 0x1a, 0x3c, 0x20, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x20, 0x69,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x6f, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f,
+    0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x07, 0x05, 0x12, 0x04, 0x81,
+    0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x07, 0x01, 0x12, 0x04, 0x81, 0x04,
+    0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x07, 0x03, 0x12, 0x04, 0x81, 0x04, 0x11,
+    0x12, 0x0a, 0x38, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x08, 0x12, 0x04, 0x84, 0x04, 0x02, 0x2a, 0x1a,
+    0x2a, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1a, 0x02, 0x08, 0x06, 0x12, 0x04, 0x84, 0x04, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a,
+    0x02, 0x08, 0x01, 0x12, 0x04, 0x84, 0x04, 0x1c, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02,
+    0x08, 0x03, 0x12, 0x04, 0x84, 0x04, 0x28, 0x29, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1b, 0x12, 0x06,
+    0x87, 0x04, 0x00, 0x89, 0x04, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1b, 0x01, 0x12, 0x04, 0x87,
+    0x04, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x00, 0x12, 0x04, 0x88, 0x04, 0x02,
+    0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x04, 0x12, 0x04, 0x88, 0x04, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x06, 0x12, 0x04, 0x88, 0x04, 0x0b, 0x13, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x01, 0x12, 0x04, 0x88, 0x04, 0x14, 0x1d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x03, 0x12, 0x04, 0x88, 0x04, 0x20, 0x21, 0x0a, 0x8d, 0x01,
+    0x0a, 0x02, 0x04, 0x1c, 0x12, 0x06, 0x8d, 0x04, 0x00, 0xb2, 0x04, 0x01, 0x1a, 0x7f, 0x20, 0x41,
+    0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x45, 0x52,
+    0x43, 0x37, 0x32, 0x31, 0x20, 0x60, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x60, 0x20,
+    0x6f, 0x72, 0x20, 0x61, 0x6e, 0x20, 0x45, 0x52, 0x43, 0x37, 0x32, 0x31, 0x2f, 0x45, 0x52, 0x43,
+    0x31, 0x31, 0x35, 0x35, 0x20, 0x60, 0x41, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x46, 0x6f,
+    0x72, 0x41, 0x6c, 0x6c, 0x60, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x77, 0x6f, 0x20, 0x73,
+    0x74, 0x61, 0x6e, 0x64, 0x61, 0x72, 0x64, 0x73, 0x0a, 0x20, 0x73, 0x68, 0x61, 0x72, 0x65, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x20, 0x6f, 0x66,
+    0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x61, 0x74, 0x74, 0x65, 0x72, 0x2e, 0x0a, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x1c, 0x01, 0x12, 0x04, 0x8d, 0x04, 0x08, 0x10, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x1c,
+    0x04, 0x00, 0x12, 0x06, 0x8e, 0x04, 0x02, 0x93, 0x04, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c,
+    0x04, 0x00, 0x01, 0x12, 0x04, 0x8e, 0x04, 0x07, 0x0b, 0x0a, 0x6a, 0x0a, 0x06, 0x04, 0x1c, 0x04,
+    0x00, 0x02, 0x00, 0x12, 0x04, 0x90, 0x04, 0x04, 0x11, 0x1a, 0x5a, 0x20, 0x60, 0x6f, 0x77, 0x6e,
+    0x65, 0x72, 0x60, 0x20, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x64, 0x20, 0x60, 0x73, 0x70,
+    0x65, 0x6e, 0x64, 0x65, 0x72, 0x60, 0x20, 0x74, 0x6f, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x66,
+    0x65, 0x72, 0x20, 0x60, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x5f, 0x69, 0x64, 0x60, 0x2c, 0x20, 0x61,
+    0x20, 0x7a, 0x65, 0x72, 0x6f, 0x20, 0x60, 0x73, 0x70, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x60, 0x20,
+    0x63, 0x6c, 0x65, 0x61, 0x72, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x70, 0x70, 0x72, 0x6f,
+    0x76, 0x61, 0x6c, 0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1c, 0x04, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0x90, 0x04, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1c, 0x04, 0x00, 0x02, 0x00,
+    0x02, 0x12, 0x04, 0x90, 0x04, 0x0f, 0x10, 0x0a, 0x69, 0x0a, 0x06, 0x04, 0x1c, 0x04, 0x00, 0x02,
+    0x01, 0x12, 0x04, 0x92, 0x04, 0x04, 0x19, 0x1a, 0x59, 0x20, 0x60, 0x6f, 0x77, 0x6e, 0x65, 0x72,
+    0x60, 0x20, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x64, 0x20, 0x6f, 0x72, 0x20, 0x72, 0x65,
+    0x76, 0x6f, 0x6b, 0x65, 0x64, 0x20, 0x60, 0x73, 0x70, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x60, 0x20,
+    0x61, 0x73, 0x20, 0x61, 0x6e, 0x20, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x6f,
+    0x66, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x69, 0x74, 0x73, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73,
+    0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
+    0x2e, 0x0a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1c, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x92,
+    0x04, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1c, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04,
+    0x92, 0x04, 0x17, 0x18, 0x0a, 0x83, 0x01, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x00, 0x12, 0x04, 0x97,
+    0x04, 0x02, 0x12, 0x1a, 0x75, 0x20, 0x4b, 0x69, 0x6e, 0x64, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65,
+    0x6e, 0x74, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x6f, 0x6e, 0x65, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x75, 0x6d, 0x20, 0x64, 0x65, 0x66, 0x69, 0x6e,
+    0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x4b, 0x69, 0x6e, 0x64, 0x2c, 0x20, 0x73, 0x65, 0x65, 0x20,
+    0x60, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x2e, 0x73, 0x63, 0x68, 0x65, 0x6d, 0x61,
+    0x60, 0x0a, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x77, 0x68, 0x79, 0x20, 0x69, 0x74, 0x27, 0x73, 0x20,
+    0x61, 0x20, 0x73, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0x97, 0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0x97, 0x04, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0x97, 0x04, 0x10, 0x11, 0x0a, 0x5a, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x01, 0x12,
+    0x04, 0x9a, 0x04, 0x02, 0x1d, 0x1a, 0x4c, 0x20, 0x43, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74,
+    0x41, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61,
+    0x64, 0x64, 0x72, 0x65, 0x73, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4e, 0x46,
+    0x54, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
+    0x65, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e,
+    0x74, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x05, 0x12, 0x04, 0x9a, 0x04,
+    0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9a, 0x04, 0x08,
+    0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x03, 0x12, 0x04, 0x9a, 0x04, 0x1b, 0x1c,
+    0x0a, 0x3e, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x02, 0x12, 0x04, 0x9d, 0x04, 0x02, 0x12, 0x1a, 0x30,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x61,
+    0x74, 0x20, 0x6f, 0x77, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x20, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x73, 0x2e, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x02, 0x05, 0x12, 0x04, 0x9d, 0x04, 0x02, 0x07, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x02, 0x01, 0x12, 0x04, 0x9d, 0x04, 0x08, 0x0d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1c, 0x02, 0x02, 0x03, 0x12, 0x04, 0x9d, 0x04, 0x10, 0x11, 0x0a, 0x64, 0x0a,
+    0x04, 0x04, 0x1c, 0x02, 0x03, 0x12, 0x04, 0xa0, 0x04, 0x02, 0x14, 0x1a, 0x56, 0x20, 0x53, 0x70,
+    0x65, 0x6e, 0x64, 0x65, 0x72, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x61, 0x70, 0x70,
+    0x72, 0x6f, 0x76, 0x65, 0x64, 0x20, 0x61, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x60, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x60, 0x2c, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x60,
+    0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x5f, 0x66, 0x6f, 0x72, 0x5f, 0x61, 0x6c, 0x6c,
+    0x60, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x03, 0x05, 0x12, 0x04, 0xa0, 0x04,
+    0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x03, 0x01, 0x12, 0x04, 0xa0, 0x04, 0x08,
+    0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x03, 0x03, 0x12, 0x04, 0xa0, 0x04, 0x12, 0x13,
+    0x0a, 0x5f, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x04, 0x12, 0x04, 0xa3, 0x04, 0x02, 0x15, 0x1a, 0x51,
+    0x20, 0x54, 0x6f, 0x6b, 0x65, 0x6e, 0x49, 0x44, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x6b, 0x65, 0x6e,
+    0x2c, 0x20, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x61, 0x62, 0x6c, 0x65,
+    0x20, 0x66, 0x6f, 0x72, 0x20, 0x60, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x60, 0x2e,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x04, 0x05, 0x12, 0x04, 0xa3, 0x04, 0x02, 0x07,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x04, 0x01, 0x12, 0x04, 0xa3, 0x04, 0x08, 0x10, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x04, 0x03, 0x12, 0x04, 0xa3, 0x04, 0x13, 0x14, 0x0a, 0x6e,
+    0x0a, 0x04, 0x04, 0x1c, 0x02, 0x05, 0x12, 0x04, 0xa6, 0x04, 0x02, 0x14, 0x1a, 0x60, 0x20, 0x41,
+    0x70, 0x70, 0x72, 0x6f, 0x76, 0x65, 0x64, 0x20, 0x69, 0x73, 0x20, 0x66, 0x61, 0x6c, 0x73, 0x65,
+    0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x61, 0x6e, 0x20, 0x60, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76,
+    0x61, 0x6c, 0x5f, 0x66, 0x6f, 0x72, 0x5f, 0x61, 0x6c, 0x6c, 0x60, 0x20, 0x72, 0x65, 0x76, 0x6f,
+    0x6b, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x6f, 0x72,
+    0x2c, 0x20, 0x61, 0x6c, 0x77, 0x61, 0x79, 0x73, 0x20, 0x74, 0x72, 0x75, 0x65, 0x20, 0x66, 0x6f,
+    0x72, 0x20, 0x60, 0x61, 0x70, 0x70, 0x72, 0x6f, 0x76, 0x61, 0x6c, 0x60, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1c, 0x02, 0x05, 0x05, 0x12, 0x04, 0xa6, 0x04, 0x02, 0x06, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1c, 0x02, 0x05, 0x01, 0x12, 0x04, 0xa6, 0x04, 0x07, 0x0f, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1c, 0x02, 0x05, 0x03, 0x12, 0x04, 0xa6, 0x04, 0x12, 0x13, 0x0a, 0x35, 0x0a, 0x04, 0x04,
+    0x1c, 0x02, 0x06, 0x12, 0x04, 0xa9, 0x04, 0x02, 0x14, 0x1a, 0x27, 0x20, 0x54, 0x78, 0x48, 0x61,
+    0x73, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f,
+    0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x06, 0x05, 0x12, 0x04, 0xa9, 0x04, 0x02,
+    0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x06, 0x01, 0x12, 0x04, 0xa9, 0x04, 0x08, 0x0f,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x06, 0x03, 0x12, 0x04, 0xa9, 0x04, 0x12, 0x13, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x07, 0x12, 0x04, 0xab, 0x04, 0x02, 0x1a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1c, 0x02, 0x07, 0x05, 0x12, 0x04, 0xab, 0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1c, 0x02, 0x07, 0x01, 0x12, 0x04, 0xab, 0x04, 0x09, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1c, 0x02, 0x07, 0x03, 0x12, 0x04, 0xab, 0x04, 0x18, 0x19, 0x0a, 0x43, 0x0a, 0x04, 0x04, 0x1c,
+    0x02, 0x08, 0x12, 0x04, 0xae, 0x04, 0x02, 0x15, 0x1a, 0x35, 0x20, 0x4f, 0x72, 0x64, 0x69, 0x6e,
+    0x61, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x20, 0x77, 0x69, 0x74,
+    0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x08, 0x05, 0x12, 0x04, 0xae, 0x04, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1c, 0x02, 0x08, 0x01, 0x12, 0x04, 0xae, 0x04, 0x09, 0x10, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1c, 0x02, 0x08, 0x03, 0x12, 0x04, 0xae, 0x04, 0x13, 0x14, 0x0a, 0x38, 0x0a, 0x04,
+    0x04, 0x1c, 0x02, 0x09, 0x12, 0x04, 0xb1, 0x04, 0x02, 0x2b, 0x1a, 0x2a, 0x20, 0x54, 0x69, 0x6d,
+    0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74, 0x69,
+    0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62,
+    0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x09, 0x06, 0x12,
+    0x04, 0xb1, 0x04, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x09, 0x01, 0x12, 0x04,
+    0xb1, 0x04, 0x1c, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x09, 0x03, 0x12, 0x04, 0xb1,
+    0x04, 0x28, 0x2a, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x33,
 ];
 // @@protoc_insertion_point(module)
\ No newline at end of file
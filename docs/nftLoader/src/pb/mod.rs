@@ -1,3 +1,7 @@
 #[path = "eth.transfers.v1.rs"]
 #[allow(dead_code)]
-pub mod transfers;
\ No newline at end of file
+pub mod transfers;
+
+#[path = "sf.substreams.sink.files.v1.rs"]
+#[allow(dead_code)]
+pub mod files;
\ No newline at end of file
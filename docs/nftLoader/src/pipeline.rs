@@ -0,0 +1,131 @@
+use substreams::scalar::BigInt;
+use substreams_database_change::pb::database::DatabaseChanges;
+use substreams_entity_change::pb::entity::EntityChanges;
+use substreams_ethereum::pb as ethpb;
+
+use crate::memory_store::{
+    new_delta_big_int, new_delta_int64, new_delta_proto, new_delta_string, MemoryStore,
+};
+use crate::params::Params;
+use crate::pb::files::Lines;
+use crate::pb::transfers;
+use crate::stores;
+
+// Runs the modules of `substreams.yaml` natively over a sequence of blocks, with the stores kept
+// in memory between blocks. The stores start empty, like the runtime at the start block of the
+// modules.
+#[derive(Default)]
+pub struct Pipeline {
+    params: Params,
+    token_users: MemoryStore<transfers::TokenUser>,
+    token_locks: MemoryStore<transfers::TokenLock>,
+    contract_standards: MemoryStore<String>,
+    nft_contracts: MemoryStore<String>,
+    contract_owners: MemoryStore<transfers::OwnershipTransfer>,
+    nft_owners: MemoryStore<String>,
+    balances: MemoryStore<BigInt>,
+    collections: MemoryStore<i64>,
+    tokens: MemoryStore<String>,
+    accounts: MemoryStore<String>,
+    punk_bids: MemoryStore<String>,
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    // Same as `new`, with the params given to the modules in `substreams.yaml`.
+    pub fn with_params(params: Params) -> Self {
+        Pipeline {
+            params: params,
+            ..Default::default()
+        }
+    }
+
+    // Returns the output of `db_out` for `blk`, blocks must be given in order.
+    pub fn db_out(&mut self, blk: &ethpb::eth::v2::Block) -> DatabaseChanges {
+        self.run_stores(blk);
+
+        let database_changes = crate::transform_block_to_database_changes(
+            blk,
+            &self.params,
+            self.token_users.to_deltas(new_delta_proto),
+            &self.token_locks,
+            &self.contract_standards,
+            &self.nft_contracts,
+            self.contract_owners.to_deltas(new_delta_proto),
+            self.nft_owners.to_deltas(new_delta_string),
+            self.balances.to_deltas(new_delta_big_int),
+            self.collections.to_deltas(new_delta_int64),
+            &self.punk_bids,
+        );
+
+        self.commit();
+        database_changes
+    }
+
+    // Same as `db_out` for `graph_out`, a pipeline runs a single output module over the blocks.
+    pub fn graph_out(&mut self, blk: &ethpb::eth::v2::Block) -> EntityChanges {
+        self.run_stores(blk);
+
+        let entity_changes = crate::transform_block_to_entity_changes(
+            blk,
+            &self.params,
+            &self.contract_standards,
+            &self.nft_contracts,
+            self.accounts.to_deltas(new_delta_string),
+            self.tokens.to_deltas(new_delta_string),
+            self.nft_owners.to_deltas(new_delta_string),
+            self.collections.to_deltas(new_delta_int64),
+        );
+
+        self.commit();
+        entity_changes
+    }
+
+    // Same as `db_out` for `jsonl_out`.
+    pub fn jsonl_out(&mut self, blk: &ethpb::eth::v2::Block) -> Lines {
+        self.run_stores(blk);
+
+        let lines = crate::transform_block_to_lines(
+            blk,
+            &self.params,
+            &self.contract_standards,
+            &self.nft_contracts,
+            &self.punk_bids,
+        );
+
+        self.commit();
+        lines
+    }
+
+    // In the order of their dependencies, like the runtime does.
+    fn run_stores(&self, blk: &ethpb::eth::v2::Block) {
+        stores::store_token_users(blk, &self.token_users);
+        stores::store_token_locks(blk, &self.token_locks);
+        stores::store_contract_standards(blk, &self.contract_standards);
+        stores::store_nft_contracts(blk, &self.params, &self.contract_standards, &self.nft_contracts);
+        stores::store_contract_owners(blk, &self.contract_standards, &self.nft_contracts, &self.contract_owners);
+        stores::store_nft_owners(blk, &self.params, &self.contract_standards, &self.nft_contracts, &self.nft_owners);
+        stores::store_balances(blk, &self.params, &self.contract_standards, &self.nft_contracts, &self.balances);
+        stores::store_collections(blk, &self.params, &self.contract_standards, &self.nft_contracts, &self.collections);
+        stores::store_tokens(blk, &self.params, &self.contract_standards, &self.nft_contracts, &self.tokens);
+        stores::store_accounts(blk, &self.params, &self.contract_standards, &self.nft_contracts, &self.accounts);
+        stores::store_punk_bids(blk, &self.punk_bids);
+    }
+
+    fn commit(&self) {
+        self.token_users.commit();
+        self.token_locks.commit();
+        self.contract_standards.commit();
+        self.nft_contracts.commit();
+        self.contract_owners.commit();
+        self.nft_owners.commit();
+        self.balances.commit();
+        self.collections.commit();
+        self.tokens.commit();
+        self.accounts.commit();
+        self.punk_bids.commit();
+    }
+}
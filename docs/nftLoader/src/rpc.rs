@@ -0,0 +1,343 @@
+// Converts the JSON returned by a node for `eth_getBlockByNumber`, with full transactions, and
+// `eth_getTransactionReceipt` or `eth_getBlockReceipts` into a `Block`. Either the JSON-RPC
+// responses or only their `result` are accepted.
+//
+// JSON-RPC has no traces, every transaction gets a single root call to its `to` address, or to the
+// created contract, holding the input and the logs of the receipt. Storage, balance and code
+// changes are left empty, so contracts are not classified from their code.
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Context};
+use prost_types::Timestamp;
+use serde_json::Value;
+use substreams_ethereum::pb as ethpb;
+use substreams_ethereum::pb::eth::v2::{CallType, TransactionTraceStatus};
+
+// The receipts of `eth_getBlockReceipts`, of a single `eth_getTransactionReceipt` or of an array
+// of them.
+pub fn rpc_to_receipts(receipts: &Value) -> Result<Vec<Value>, anyhow::Error> {
+    match result(receipts) {
+        Value::Array(receipts) => Ok(receipts.clone()),
+        receipt @ Value::Object(_) => Ok(vec![receipt.clone()]),
+        receipts => Err(anyhow!("not a receipt or an array of receipts: {}", receipts)),
+    }
+}
+
+pub fn rpc_to_block(block: &Value, receipts: &[Value]) -> Result<ethpb::eth::v2::Block, anyhow::Error> {
+    let block = result(block);
+
+    let receipts: HashMap<Vec<u8>, &Value> = receipts.iter()
+        .map(|receipt| {
+            let receipt = result(receipt);
+            Ok((bytes(receipt, "transactionHash")?, receipt))
+        })
+        .collect::<Result<_, anyhow::Error>>()?;
+
+    let number = quantity(block, "number")?;
+    let header = ethpb::eth::v2::BlockHeader {
+        parent_hash: bytes(block, "parentHash")?,
+        uncle_hash: bytes(block, "sha3Uncles")?,
+        coinbase: bytes(block, "miner")?,
+        state_root: bytes(block, "stateRoot")?,
+        transactions_root: bytes(block, "transactionsRoot")?,
+        receipt_root: bytes(block, "receiptsRoot")?,
+        logs_bloom: bytes(block, "logsBloom")?,
+        difficulty: big_int(block, "difficulty")?,
+        total_difficulty: big_int(block, "totalDifficulty")?,
+        number: number,
+        gas_limit: quantity(block, "gasLimit")?,
+        gas_used: quantity(block, "gasUsed")?,
+        timestamp: Some(Timestamp { seconds: quantity(block, "timestamp")? as i64, nanos: 0 }),
+        extra_data: bytes(block, "extraData")?,
+        mix_hash: bytes(block, "mixHash")?,
+        nonce: quantity(block, "nonce")?,
+        hash: bytes(block, "hash")?,
+        base_fee_per_gas: big_int(block, "baseFeePerGas")?,
+    };
+
+    let transactions = block.get("transactions").and_then(Value::as_array)
+        .ok_or_else(|| anyhow!("block {} has no transactions, fetch it with full transactions", number))?;
+
+    let mut ordinal = 0;
+
+    let transaction_traces = transactions.iter().map(|transaction| {
+        let hash = bytes(transaction, "hash")?;
+        let receipt = receipts.get(&hash)
+            .ok_or_else(|| anyhow!("missing the receipt of transaction 0x{}", substreams::Hex(&hash)))?;
+
+        new_transaction_trace(transaction, receipt, &mut ordinal)
+            .with_context(|| format!("converting transaction 0x{}", substreams::Hex(&hash)))
+    }).collect::<Result<_, anyhow::Error>>()?;
+
+    Ok(ethpb::eth::v2::Block {
+        ver: 2,
+        hash: header.hash.clone(),
+        number: number,
+        size: quantity(block, "size")?,
+        header: Some(header),
+        transaction_traces: transaction_traces,
+        ..Default::default()
+    })
+}
+
+fn new_transaction_trace(
+    transaction: &Value,
+    receipt: &Value,
+    ordinal: &mut u64,
+) -> Result<ethpb::eth::v2::TransactionTrace, anyhow::Error> {
+    let begin_ordinal = next(ordinal);
+    let from = bytes(transaction, "from")?;
+    let to = bytes(transaction, "to")?;
+    // Receipts before Byzantium have no status, their transactions are taken as succeeded.
+    let succeeded = field(receipt, "status")?.is_empty() || quantity(receipt, "status")? == 1;
+    let call_begin_ordinal = next(ordinal);
+
+    let logs: Vec<ethpb::eth::v2::Log> = receipt.get("logs").and_then(Value::as_array)
+        .map(|logs| logs.iter().enumerate().map(|(i, log)| new_log(log, i as u32, ordinal)).collect())
+        .unwrap_or_else(|| Ok(Vec::new()))?;
+
+    let (call_type, address) = match to.is_empty() {
+        true => (CallType::Create, bytes(receipt, "contractAddress")?),
+        false => (CallType::Call, to.clone()),
+    };
+
+    let call = ethpb::eth::v2::Call {
+        index: 1,
+        depth: 0,
+        call_type: call_type as i32,
+        caller: from.clone(),
+        address: address,
+        value: big_int(transaction, "value")?,
+        gas_limit: quantity(transaction, "gas")?,
+        gas_consumed: quantity(receipt, "gasUsed")?,
+        input: bytes(transaction, "input")?,
+        logs: logs.clone(),
+        status_failed: !succeeded,
+        state_reverted: !succeeded,
+        begin_ordinal: call_begin_ordinal,
+        end_ordinal: next(ordinal),
+        ..Default::default()
+    };
+
+    Ok(ethpb::eth::v2::TransactionTrace {
+        to: to,
+        nonce: quantity(transaction, "nonce")?,
+        gas_price: big_int(transaction, "gasPrice")?,
+        gas_limit: quantity(transaction, "gas")?,
+        value: big_int(transaction, "value")?,
+        input: bytes(transaction, "input")?,
+        v: bytes(transaction, "v")?,
+        r: bytes(transaction, "r")?,
+        s: bytes(transaction, "s")?,
+        gas_used: quantity(receipt, "gasUsed")?,
+        max_fee_per_gas: big_int(transaction, "maxFeePerGas")?,
+        r#type: quantity(transaction, "type")? as i32,
+        max_priority_fee_per_gas: big_int(transaction, "maxPriorityFeePerGas")?,
+        index: quantity(transaction, "transactionIndex")? as u32,
+        hash: bytes(transaction, "hash")?,
+        from: from,
+        begin_ordinal: begin_ordinal,
+        end_ordinal: next(ordinal),
+        status: match succeeded {
+            true => TransactionTraceStatus::Succeeded as i32,
+            false => TransactionTraceStatus::Failed as i32,
+        },
+        receipt: Some(ethpb::eth::v2::TransactionReceipt {
+            state_root: bytes(receipt, "root")?,
+            cumulative_gas_used: quantity(receipt, "cumulativeGasUsed")?,
+            logs_bloom: bytes(receipt, "logsBloom")?,
+            logs: logs,
+        }),
+        calls: vec![call],
+        ..Default::default()
+    })
+}
+
+// The `logIndex` of a receipt is the index of the log in the block.
+fn new_log(log: &Value, index: u32, ordinal: &mut u64) -> Result<ethpb::eth::v2::Log, anyhow::Error> {
+    if field(log, "logIndex")?.is_empty() {
+        return Err(anyhow!("log {} of the receipt has no logIndex", index));
+    }
+
+    let topics = log.get("topics").and_then(Value::as_array)
+        .map(|topics| topics.iter().map(|topic| decode_hex(topic.as_str().unwrap_or_default())).collect())
+        .unwrap_or_else(|| Ok(Vec::new()))?;
+
+    Ok(ethpb::eth::v2::Log {
+        address: bytes(log, "address")?,
+        topics: topics,
+        data: bytes(log, "data")?,
+        index: index,
+        block_index: quantity(log, "logIndex")? as u32,
+        ordinal: next(ordinal),
+    })
+}
+
+// The `result` of a JSON-RPC response, or the value itself.
+fn result(value: &Value) -> &Value {
+    value.get("result").unwrap_or(value)
+}
+
+fn next(ordinal: &mut u64) -> u64 {
+    let current = *ordinal;
+    *ordinal += 1;
+    current
+}
+
+// Missing and null fields are empty, like absent fields of the proto.
+fn field<'a>(value: &'a Value, name: &str) -> Result<&'a str, anyhow::Error> {
+    match value.get(name) {
+        None | Some(Value::Null) => Ok(""),
+        Some(Value::String(field)) => Ok(field),
+        Some(field) => Err(anyhow!("{} is not a string: {}", name, field)),
+    }
+}
+
+fn bytes(value: &Value, name: &str) -> Result<Vec<u8>, anyhow::Error> {
+    decode_hex(field(value, name)?).with_context(|| format!("decoding {}", name))
+}
+
+fn quantity(value: &Value, name: &str) -> Result<u64, anyhow::Error> {
+    let field = field(value, name)?.trim_start_matches("0x");
+    if field.is_empty() {
+        return Ok(0);
+    }
+
+    u64::from_str_radix(field, 16).with_context(|| format!("decoding {}", name))
+}
+
+// Quantities are big endian without leading zeros, an absent field stays absent.
+fn big_int(value: &Value, name: &str) -> Result<Option<ethpb::eth::v2::BigInt>, anyhow::Error> {
+    if field(value, name)?.is_empty() {
+        return Ok(None);
+    }
+
+    let bytes = bytes(value, name)?;
+    let start = bytes.iter().position(|byte| *byte != 0).unwrap_or(bytes.len());
+
+    Ok(Some(ethpb::eth::v2::BigInt { bytes: bytes[start..].to_vec() }))
+}
+
+fn decode_hex(value: &str) -> Result<Vec<u8>, anyhow::Error> {
+    let value = value.trim_start_matches("0x");
+    let value = match value.len() % 2 {
+        0 => value.to_string(),
+        _ => format!("0{}", value),
+    };
+
+    (0..value.len()).step_by(2)
+        .map(|i| u8::from_str_radix(&value[i..i + 2], 16).map_err(|err| anyhow!("invalid hex {}: {}", value, err)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    const TRANSACTION_HASH: &str = "0x0404040404040404040404040404040404040404040404040404040404040404";
+
+    fn block() -> Value {
+        json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": {
+                "number": "0xbb7d13",
+                "hash": "0x0101010101010101010101010101010101010101010101010101010101010101",
+                "parentHash": "0x0202020202020202020202020202020202020202020202020202020202020202",
+                "timestamp": "0x60784e47",
+                "gasLimit": "0xe4e1c0",
+                "gasUsed": "0x1b5a0",
+                "difficulty": "0x1b9e2d8f3c3e4",
+                "nonce": "0x1a2b3c4d5e6f7081",
+                "miner": "0x0303030303030303030303030303030303030303",
+                "transactions": [{
+                    "hash": TRANSACTION_HASH,
+                    "from": "0xa1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1",
+                    "to": "0x7272727272727272727272727272727272727272",
+                    "input": "0x23b872dd",
+                    "value": "0x0",
+                    "gas": "0x186a0",
+                    "gasPrice": "0x2540be400",
+                    "nonce": "0x7",
+                    "transactionIndex": "0x0",
+                    "type": "0x0"
+                }]
+            }
+        })
+    }
+
+    // The receipt of the transaction of `block`, with the ERC721 transfer of token 42 from
+    // 0xa1..a1 to 0xb0..b0.
+    fn receipt(log_index: &str) -> Value {
+        json!({
+            "transactionHash": TRANSACTION_HASH,
+            "status": "0x1",
+            "gasUsed": "0x1b5a0",
+            "cumulativeGasUsed": "0x1b5a0",
+            "contractAddress": null,
+            "logs": [{
+                "address": "0x7272727272727272727272727272727272727272",
+                "topics": [
+                    "0xddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef",
+                    "0x000000000000000000000000a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1",
+                    "0x000000000000000000000000b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0",
+                    "0x000000000000000000000000000000000000000000000000000000000000002a"
+                ],
+                "data": "0x",
+                "logIndex": log_index
+            }]
+        })
+    }
+
+    #[test]
+    fn it_rpc_to_block() {
+        let blk = rpc_to_block(&block(), &[receipt("0x0")]).unwrap();
+
+        assert_eq!(blk.number, 12287251);
+        assert_eq!(blk.header.as_ref().unwrap().timestamp.as_ref().unwrap().seconds, 1618497095);
+        assert_eq!(blk.transaction_traces[0].gas_price.as_ref().unwrap().bytes, vec![0x02, 0x54, 0x0b, 0xe4, 0x00]);
+
+        let (_timestamp, transfers) = crate::transform_block_to_transfers(&blk);
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].schema, "erc721");
+        assert_eq!(transfers[0].token_id, vec![0x2a]);
+        assert_eq!(transfers[0].to_address, vec![0xb0; 20]);
+        assert_eq!(transfers[0].operator, vec![0xa1; 20]);
+    }
+
+    #[test]
+    fn it_takes_the_block_index_of_logs_from_their_log_index() {
+        let blk = rpc_to_block(&block(), &[receipt("0x1c")]).unwrap();
+
+        let log = &blk.transaction_traces[0].receipt.as_ref().unwrap().logs[0];
+        assert_eq!(log.index, 0);
+        assert_eq!(log.block_index, 28);
+        assert_eq!(blk.transaction_traces[0].calls[0].logs[0].block_index, 28);
+    }
+
+    #[test]
+    fn it_rejects_logs_without_a_log_index() {
+        let mut receipt = receipt("0x0");
+        receipt["logs"][0].as_object_mut().unwrap().remove("logIndex");
+
+        assert!(rpc_to_block(&block(), &[receipt]).is_err());
+    }
+
+    #[test]
+    fn it_rpc_to_receipts() {
+        let response = |result: Value| json!({ "jsonrpc": "2.0", "id": 1, "result": result });
+
+        // `eth_getBlockReceipts`
+        assert_eq!(rpc_to_receipts(&response(json!([receipt("0x0")]))).unwrap(), vec![receipt("0x0")]);
+        // A single `eth_getTransactionReceipt`
+        assert_eq!(rpc_to_receipts(&response(receipt("0x0"))).unwrap(), vec![receipt("0x0")]);
+        assert_eq!(rpc_to_receipts(&receipt("0x0")).unwrap(), vec![receipt("0x0")]);
+        // An array of `eth_getTransactionReceipt`, the responses are unwrapped by `rpc_to_block`.
+        assert_eq!(rpc_to_receipts(&json!([response(receipt("0x0"))])).unwrap(), vec![response(receipt("0x0"))]);
+
+        assert!(rpc_to_receipts(&response(Value::Null)).is_err());
+    }
+}
@@ -0,0 +1,615 @@
+use std::collections::HashSet;
+
+use hex_literal::hex;
+use prost_types::Timestamp;
+use substreams::scalar::BigInt;
+use substreams::{log, Hex};
+use substreams_ethereum::pb as ethpb;
+use substreams_ethereum::Event;
+
+use crate::abi::seaport::events::CounterIncremented as SeaportCounterIncrementedEvent;
+use crate::abi::seaport::events::OrderCancelled as SeaportOrderCancelledEvent;
+use crate::abi::seaport::events::OrderFulfilled as SeaportOrderFulfilledEvent;
+use crate::pb::transfers;
+use crate::pb::transfers::order_cancellation::Kind;
+use crate::pb::transfers::sale_payout::Role;
+use crate::{kind_to_string, role_to_string};
+
+// Seaport v1.0, v1.1, v1.4, v1.5 and v1.6, `OrderFulfilled` logs emitted by any other contract are ignored.
+const SEAPORT_ADDRESSES: [[u8; 20]; 5] = [
+    hex!("00000000006cee72100d161c57ada5bb2be1ca79"),
+    hex!("00000000006c3852cbef3e08e8df289169ede581"),
+    hex!("00000000000001ad428e4906ae43d8f9852d0dd6"),
+    hex!("00000000000000adc04c56bf30ac9d3c0aaf14dc"),
+    hex!("0000000000000068f116a894984e2db1123eb395"),
+];
+
+// OpenSea fee collectors. Any other payment that does not go to the seller has the `other` role, a
+// creator royalty can't be told apart from a fee paid to a third party.
+const MARKETPLACE_FEE_RECIPIENTS: [[u8; 20]; 3] = [
+    hex!("0000a26b00c1f0df003000390027140000faa719"),
+    hex!("8de9c5a032463c561423387a9648c5c7bcc5bc90"),
+    hex!("5b3256965e7c3cf26e11fcaf296dfc8807c01073"),
+];
+
+// Values of Seaport's `ItemType` enum.
+const ITEM_TYPE_NATIVE: u64 = 0;
+const ITEM_TYPE_ERC20: u64 = 1;
+const ITEM_TYPE_ERC721: u64 = 2;
+const ITEM_TYPE_ERC1155_WITH_CRITERIA: u64 = 5;
+
+// (itemType, token, identifier, amount)
+type SpentItem = (BigInt, Vec<u8>, BigInt, BigInt);
+// (itemType, token, identifier, amount, recipient)
+type ReceivedItem = (BigInt, Vec<u8>, BigInt, BigInt, Vec<u8>);
+
+struct Fill<'a> {
+    log: &'a ethpb::eth::v2::Log,
+    event: SeaportOrderFulfilledEvent,
+}
+
+pub fn transform_block_to_sales(blk: &ethpb::eth::v2::Block) -> Vec<transfers::Sale> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts().flat_map(|receipt| {
+        let hash = &receipt.transaction.hash;
+        let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+        let fills: Vec<Fill> = receipt.receipt.logs.iter()
+            .filter(|log| is_seaport_address(&log.address))
+            .filter_map(|log| SeaportOrderFulfilledEvent::match_and_decode(log).map(|event| Fill { log, event }))
+            .collect();
+
+        transform_fills_to_sales(hash, blk.number, timestamp, &fills)
+    }).collect()
+}
+
+pub fn transform_block_to_order_cancellations(blk: &ethpb::eth::v2::Block) -> Vec<transfers::OrderCancellation> {
+    let header = blk.header.as_ref().unwrap();
+
+    blk.receipts().flat_map(|receipt| {
+        let hash = &receipt.transaction.hash;
+        let timestamp = Some(header.timestamp.as_ref().unwrap().clone());
+
+        receipt.receipt.logs.iter()
+            .filter(|log| is_seaport_address(&log.address))
+            .filter_map(move |log| {
+                if let Some(event) = SeaportOrderCancelledEvent::match_and_decode(log) {
+                    return Some(new_order_cancelled(hash, log, blk.number, timestamp.clone(), event));
+                }
+
+                SeaportCounterIncrementedEvent::match_and_decode(log)
+                    .map(|event| new_counter_incremented(hash, log, blk.number, timestamp.clone(), event))
+            })
+    }).collect()
+}
+
+fn new_order_cancelled(
+    hash: &[u8],
+    log: &ethpb::eth::v2::Log,
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+    event: SeaportOrderCancelledEvent,
+) -> transfers::OrderCancellation {
+    transfers::OrderCancellation {
+        kind: kind_to_string(Kind::OrderCancelled),
+        marketplace: "seaport".to_string(),
+        marketplace_address: log.address.to_vec(),
+        order_hash: event.order_hash.to_vec(),
+        offerer: event.offerer,
+        zone: event.zone,
+        tx_hash: hash.to_vec(),
+        block_number: block_number,
+        ordinal: log.block_index as u64,
+        timestamp: timestamp,
+
+        counter: String::new(),
+    }
+}
+
+fn new_counter_incremented(
+    hash: &[u8],
+    log: &ethpb::eth::v2::Log,
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+    event: SeaportCounterIncrementedEvent,
+) -> transfers::OrderCancellation {
+    transfers::OrderCancellation {
+        kind: kind_to_string(Kind::CounterIncremented),
+        marketplace: "seaport".to_string(),
+        marketplace_address: log.address.to_vec(),
+        offerer: event.offerer,
+        counter: event.new_counter.to_string(),
+        tx_hash: hash.to_vec(),
+        block_number: block_number,
+        ordinal: log.block_index as u64,
+        timestamp: timestamp,
+
+        order_hash: Vec::new(),
+        zone: Vec::new(),
+    }
+}
+
+// A sale is either a listing being filled (the offerer gives NFTs for a payment) or a bid being
+// accepted (the offerer gives a payment for NFTs). When orders are matched together, both sides
+// of the trade are emitted in the same transaction and are merged into a single sale, each side
+// carrying a part of the payment legs.
+fn transform_fills_to_sales(
+    hash: &[u8],
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+    fills: &[Fill],
+) -> Vec<transfers::Sale> {
+    let mut merged_bids: HashSet<usize> = HashSet::new();
+    let mut sales = Vec::new();
+
+    for (i, listing) in fills.iter().enumerate() {
+        if !is_listing(&listing.event) {
+            continue;
+        }
+
+        let nft = offered_nfts(&listing.event)[0];
+        let bid = fills.iter().enumerate().find(|(j, fill)| {
+            *j != i && !merged_bids.contains(j) && is_bid(&fill.event) && considers_nft(&fill.event, &nft.1, &nft.2)
+        });
+
+        match bid {
+            Some((j, bid)) => {
+                merged_bids.insert(j);
+                sales.push(new_matched_sale(hash, block_number, timestamp.clone(), listing, bid));
+            }
+            None => sales.push(new_listing_sale(hash, block_number, timestamp.clone(), listing)),
+        }
+    }
+
+    for (j, bid) in fills.iter().enumerate() {
+        if !is_bid(&bid.event) || merged_bids.contains(&j) {
+            continue;
+        }
+
+        sales.push(new_bid_sale(hash, block_number, timestamp.clone(), bid));
+    }
+
+    sales.sort_by_key(|sale| sale.ordinal);
+    sales
+}
+
+fn new_listing_sale(
+    hash: &[u8],
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+    listing: &Fill,
+) -> transfers::Sale {
+    let event = &listing.event;
+    let nfts = offered_nfts(event);
+    let payments = considered_payments(event);
+
+    let payment_token = payments[0].1.clone();
+    let price = sum_amounts(payments.iter().map(|item| (&item.1, &item.3)), &payment_token);
+
+    new_seaport_sale(
+        hash,
+        listing,
+        block_number,
+        timestamp,
+        &nfts[0].1,
+        &nfts[0].2,
+        &nfts[0].3,
+        nfts.len(),
+        event.offerer.clone(),
+        event.recipient.clone(),
+        payment_token,
+        price,
+        payments,
+    )
+}
+
+fn new_bid_sale(
+    hash: &[u8],
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+    bid: &Fill,
+) -> transfers::Sale {
+    let event = &bid.event;
+    let nfts = considered_nfts(event);
+    let payments = offered_payments(event);
+
+    let payment_token = payments[0].1.clone();
+    let price = sum_amounts(payments.iter().map(|item| (&item.1, &item.3)), &payment_token);
+
+    new_seaport_sale(
+        hash,
+        bid,
+        block_number,
+        timestamp,
+        &nfts[0].1,
+        &nfts[0].2,
+        &nfts[0].3,
+        nfts.len(),
+        event.recipient.clone(),
+        event.offerer.clone(),
+        payment_token,
+        price,
+        considered_payments(event),
+    )
+}
+
+fn new_matched_sale(
+    hash: &[u8],
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+    listing: &Fill,
+    bid: &Fill,
+) -> transfers::Sale {
+    let nfts = offered_nfts(&listing.event);
+    let payments = offered_payments(&bid.event);
+
+    let payment_token = payments[0].1.clone();
+    let price = sum_amounts(payments.iter().map(|item| (&item.1, &item.3)), &payment_token);
+
+    new_seaport_sale(
+        hash,
+        listing,
+        block_number,
+        timestamp,
+        &nfts[0].1,
+        &nfts[0].2,
+        &nfts[0].3,
+        nfts.len(),
+        listing.event.offerer.clone(),
+        bid.event.offerer.clone(),
+        payment_token,
+        price,
+        considered_payments(&listing.event)
+            .into_iter()
+            .chain(considered_payments(&bid.event))
+            .collect(),
+    )
+}
+
+fn new_seaport_sale(
+    hash: &[u8],
+    fill: &Fill,
+    block_number: u64,
+    timestamp: Option<Timestamp>,
+    contract_address: &[u8],
+    token_id: &BigInt,
+    quantity: &BigInt,
+    item_count: usize,
+    seller: Vec<u8>,
+    buyer: Vec<u8>,
+    payment_token: Vec<u8>,
+    price: BigInt,
+    legs: Vec<&ReceivedItem>,
+) -> transfers::Sale {
+    let mut payouts: Vec<transfers::SalePayout> = legs
+        .iter()
+        .map(|item| transfers::SalePayout {
+            role: role_to_string(payout_role(&item.4, &seller)),
+            recipient: item.4.clone(),
+            payment_token: item.1.clone(),
+            amount: item.3.to_string(),
+        })
+        .collect();
+
+    // When a bid is accepted, the seller receives what's left of the offered payment once fees
+    // and royalties are paid, this leg is not part of the event so we compute it.
+    if !legs.iter().any(|item| item.4 == seller) {
+        let paid_out = sum_amounts(legs.iter().map(|item| (&item.1, &item.3)), &payment_token);
+
+        if paid_out > price {
+            log::info!(
+                "Payouts exceed the price of the sale in transaction {} for log at block index {}, not computing seller proceeds",
+                Hex(&hash).to_string(),
+                fill.log.block_index,
+            );
+        } else {
+            payouts.push(transfers::SalePayout {
+                role: role_to_string(Role::Seller),
+                recipient: seller.clone(),
+                payment_token: payment_token.clone(),
+                amount: (price.clone() - paid_out).to_string(),
+            });
+        }
+    }
+
+    transfers::Sale {
+        marketplace: "seaport".to_string(),
+        marketplace_address: fill.log.address.to_vec(),
+        order_hash: fill.event.order_hash.to_vec(),
+        contract_address: contract_address.to_vec(),
+        token_id: token_id.to_bytes_be().1,
+        quantity: quantity.to_string(),
+        item_count: item_count as u64,
+        seller: seller,
+        buyer: buyer,
+        payment_token: payment_token,
+        price: price.to_string(),
+        payouts: payouts,
+        tx_hash: hash.to_vec(),
+        block_number: block_number,
+        ordinal: fill.log.block_index as u64,
+        timestamp: timestamp,
+    }
+}
+
+fn payout_role(recipient: &[u8], seller: &[u8]) -> Role {
+    if recipient == seller {
+        return Role::Seller;
+    }
+
+    if MARKETPLACE_FEE_RECIPIENTS.iter().any(|address| address == recipient) {
+        return Role::MarketplaceFee;
+    }
+
+    Role::Other
+}
+
+fn sum_amounts<'a>(
+    items: impl Iterator<Item = (&'a Vec<u8>, &'a BigInt)>,
+    payment_token: &[u8],
+) -> BigInt {
+    items
+        .filter(|(token, _)| token.as_slice() == payment_token)
+        .fold(BigInt::zero(), |total, (_, amount)| total + amount.clone())
+}
+
+fn is_listing(event: &SeaportOrderFulfilledEvent) -> bool {
+    !offered_nfts(event).is_empty() && offered_payments(event).is_empty() && !considered_payments(event).is_empty()
+}
+
+fn is_bid(event: &SeaportOrderFulfilledEvent) -> bool {
+    !offered_payments(event).is_empty() && offered_nfts(event).is_empty() && !considered_nfts(event).is_empty()
+}
+
+fn considers_nft(event: &SeaportOrderFulfilledEvent, token: &[u8], identifier: &BigInt) -> bool {
+    considered_nfts(event)
+        .iter()
+        .any(|item| item.1 == token && &item.2 == identifier)
+}
+
+fn offered_nfts(event: &SeaportOrderFulfilledEvent) -> Vec<&SpentItem> {
+    event.offer.iter().filter(|item| is_nft_item(&item.0)).collect()
+}
+
+fn offered_payments(event: &SeaportOrderFulfilledEvent) -> Vec<&SpentItem> {
+    event.offer.iter().filter(|item| is_payment_item(&item.0)).collect()
+}
+
+fn considered_nfts(event: &SeaportOrderFulfilledEvent) -> Vec<&ReceivedItem> {
+    event.consideration.iter().filter(|item| is_nft_item(&item.0)).collect()
+}
+
+fn considered_payments(event: &SeaportOrderFulfilledEvent) -> Vec<&ReceivedItem> {
+    event.consideration.iter().filter(|item| is_payment_item(&item.0)).collect()
+}
+
+fn is_nft_item(item_type: &BigInt) -> bool {
+    let item_type = item_type.to_u64();
+    (ITEM_TYPE_ERC721..=ITEM_TYPE_ERC1155_WITH_CRITERIA).contains(&item_type)
+}
+
+fn is_payment_item(item_type: &BigInt) -> bool {
+    let item_type = item_type.to_u64();
+    item_type == ITEM_TYPE_NATIVE || item_type == ITEM_TYPE_ERC20
+}
+
+fn is_seaport_address(address: &[u8]) -> bool {
+    SEAPORT_ADDRESSES.iter().any(|seaport| seaport == address)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::testing::{seaport_order_fulfilled_log, BlockBuilder, CallBuilder, ReceivedItem, SpentItem, TransactionBuilder, SEAPORT_ADDRESS};
+
+    const NFT: [u8; 20] = [0x72; 20];
+    const WETH: [u8; 20] = hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+    const ALICE: [u8; 20] = [0xa1; 20];
+    const BOB: [u8; 20] = [0xb0; 20];
+    const CREATOR: [u8; 20] = [0xc1; 20];
+    const CO_CREATOR: [u8; 20] = [0xc2; 20];
+    const OPENSEA: [u8; 20] = MARKETPLACE_FEE_RECIPIENTS[0];
+    // The fee collector of another marketplace front-end filling Seaport orders.
+    const THIRD_PARTY: [u8; 20] = [0x3d; 20];
+
+    // (offerer, recipient, offer, consideration) of an `OrderFulfilled`.
+    type TestFill<'a> = (&'a [u8], &'a [u8], Vec<SpentItem>, Vec<ReceivedItem>);
+
+    // The sales of a transaction emitting `fills` in order.
+    fn sales(fills: Vec<TestFill>) -> Vec<transfers::Sale> {
+        let call = fills.into_iter().enumerate().fold(
+            CallBuilder::new(&SEAPORT_ADDRESS),
+            |call, (i, (offerer, recipient, offer, consideration))| {
+                call.log(seaport_order_fulfilled_log(&[i as u8; 32], offerer, &[0; 20], recipient, &offer, &consideration))
+            },
+        );
+
+        let block = BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&BOB).call(call))
+            .build();
+
+        transform_block_to_sales(&block)
+    }
+
+    fn payouts(sale: &transfers::Sale) -> Vec<(String, Vec<u8>, String)> {
+        sale.payouts.iter()
+            .map(|payout| (payout.role.clone(), payout.recipient.clone(), payout.amount.clone()))
+            .collect()
+    }
+
+    fn payout(role: Role, recipient: &[u8], amount: &str) -> (String, Vec<u8>, String) {
+        (role_to_string(role), recipient.to_vec(), amount.to_string())
+    }
+
+    #[test]
+    fn it_transform_listings_to_sales() {
+        let sales = sales(vec![(
+            &ALICE,
+            &BOB,
+            vec![(ITEM_TYPE_ERC721, NFT.to_vec(), 7, 1)],
+            vec![
+                (ITEM_TYPE_NATIVE, vec![0; 20], 0, 925, ALICE.to_vec()),
+                (ITEM_TYPE_NATIVE, vec![0; 20], 0, 25, OPENSEA.to_vec()),
+                (ITEM_TYPE_NATIVE, vec![0; 20], 0, 50, CREATOR.to_vec()),
+            ],
+        )]);
+
+        assert_eq!(sales.len(), 1);
+        assert_eq!(sales[0].seller, ALICE.to_vec());
+        assert_eq!(sales[0].buyer, BOB.to_vec());
+        assert_eq!(sales[0].contract_address, NFT.to_vec());
+        assert_eq!(sales[0].token_id, vec![7]);
+        assert_eq!(sales[0].payment_token, vec![0; 20]);
+        assert_eq!(sales[0].price, "1000");
+        assert_eq!(sales[0].order_hash, vec![0; 32]);
+        assert_eq!(
+            payouts(&sales[0]),
+            vec![
+                payout(Role::Seller, &ALICE, "925"),
+                payout(Role::MarketplaceFee, &OPENSEA, "25"),
+                payout(Role::Other, &CREATOR, "50"),
+            ],
+        );
+    }
+
+    // The offerer of a bid is the buyer, the NFT is in its consideration and the seller is the
+    // fulfiller. The seller leg isn't in the event, it's what's left once fees and royalties are paid.
+    #[test]
+    fn it_transform_accepted_bids_to_sales() {
+        let sales = sales(vec![(
+            &BOB,
+            &ALICE,
+            vec![(ITEM_TYPE_ERC20, WETH.to_vec(), 0, 1000)],
+            vec![
+                (ITEM_TYPE_ERC721, NFT.to_vec(), 7, 1, BOB.to_vec()),
+                (ITEM_TYPE_ERC20, WETH.to_vec(), 0, 25, OPENSEA.to_vec()),
+                (ITEM_TYPE_ERC20, WETH.to_vec(), 0, 50, CREATOR.to_vec()),
+            ],
+        )]);
+
+        assert_eq!(sales.len(), 1);
+        assert_eq!(sales[0].seller, ALICE.to_vec());
+        assert_eq!(sales[0].buyer, BOB.to_vec());
+        assert_eq!(sales[0].token_id, vec![7]);
+        assert_eq!(sales[0].payment_token, WETH.to_vec());
+        assert_eq!(sales[0].price, "1000");
+        assert_eq!(
+            payouts(&sales[0]),
+            vec![
+                payout(Role::MarketplaceFee, &OPENSEA, "25"),
+                payout(Role::Other, &CREATOR, "50"),
+                payout(Role::Seller, &ALICE, "925"),
+            ],
+        );
+    }
+
+    #[test]
+    fn it_merges_matched_orders_into_a_single_sale() {
+        let sales = sales(vec![
+            (
+                &ALICE,
+                &[0; 20],
+                vec![(ITEM_TYPE_ERC721, NFT.to_vec(), 7, 1)],
+                vec![
+                    (ITEM_TYPE_NATIVE, vec![0; 20], 0, 975, ALICE.to_vec()),
+                    (ITEM_TYPE_NATIVE, vec![0; 20], 0, 25, OPENSEA.to_vec()),
+                ],
+            ),
+            (
+                &BOB,
+                &[0; 20],
+                vec![(ITEM_TYPE_NATIVE, vec![0; 20], 0, 1000)],
+                vec![(ITEM_TYPE_ERC721, NFT.to_vec(), 7, 1, BOB.to_vec())],
+            ),
+        ]);
+
+        assert_eq!(sales.len(), 1);
+        assert_eq!(sales[0].seller, ALICE.to_vec());
+        assert_eq!(sales[0].buyer, BOB.to_vec());
+        assert_eq!(sales[0].price, "1000");
+        assert_eq!(sales[0].ordinal, 0);
+        assert_eq!(
+            payouts(&sales[0]),
+            vec![payout(Role::Seller, &ALICE, "975"), payout(Role::MarketplaceFee, &OPENSEA, "25")],
+        );
+    }
+
+    #[test]
+    fn it_keeps_every_recipient_of_split_fees() {
+        let sales = sales(vec![(
+            &BOB,
+            &ALICE,
+            vec![(ITEM_TYPE_ERC20, WETH.to_vec(), 0, 1000)],
+            vec![
+                (ITEM_TYPE_ERC721, NFT.to_vec(), 7, 1, BOB.to_vec()),
+                (ITEM_TYPE_ERC20, WETH.to_vec(), 0, 25, OPENSEA.to_vec()),
+                (ITEM_TYPE_ERC20, WETH.to_vec(), 0, 30, CREATOR.to_vec()),
+                (ITEM_TYPE_ERC20, WETH.to_vec(), 0, 20, CO_CREATOR.to_vec()),
+            ],
+        )]);
+
+        assert_eq!(
+            payouts(&sales[0]),
+            vec![
+                payout(Role::MarketplaceFee, &OPENSEA, "25"),
+                payout(Role::Other, &CREATOR, "30"),
+                payout(Role::Other, &CO_CREATOR, "20"),
+                payout(Role::Seller, &ALICE, "925"),
+            ],
+        );
+    }
+
+    #[test]
+    fn it_labels_third_party_fee_recipients_as_other() {
+        let sales = sales(vec![(
+            &ALICE,
+            &BOB,
+            vec![(ITEM_TYPE_ERC721, NFT.to_vec(), 7, 1)],
+            vec![
+                (ITEM_TYPE_NATIVE, vec![0; 20], 0, 950, ALICE.to_vec()),
+                (ITEM_TYPE_NATIVE, vec![0; 20], 0, 50, THIRD_PARTY.to_vec()),
+            ],
+        )]);
+
+        assert_eq!(
+            payouts(&sales[0]),
+            vec![payout(Role::Seller, &ALICE, "950"), payout(Role::Other, &THIRD_PARTY, "50")],
+        );
+    }
+
+    #[test]
+    fn it_does_not_compute_the_seller_leg_when_payouts_exceed_the_price() {
+        let sales = sales(vec![(
+            &BOB,
+            &ALICE,
+            vec![(ITEM_TYPE_ERC20, WETH.to_vec(), 0, 10)],
+            vec![
+                (ITEM_TYPE_ERC721, NFT.to_vec(), 7, 1, BOB.to_vec()),
+                (ITEM_TYPE_ERC20, WETH.to_vec(), 0, 25, OPENSEA.to_vec()),
+            ],
+        )]);
+
+        assert_eq!(payouts(&sales[0]), vec![payout(Role::MarketplaceFee, &OPENSEA, "25")]);
+    }
+
+    #[test]
+    fn it_ignores_fills_of_other_contracts() {
+        let mut log = seaport_order_fulfilled_log(
+            &[0; 32],
+            &ALICE,
+            &[0; 20],
+            &BOB,
+            &[(ITEM_TYPE_ERC721, NFT.to_vec(), 7, 1)],
+            &[(ITEM_TYPE_NATIVE, vec![0; 20], 0, 1000, ALICE.to_vec())],
+        );
+        log.address = vec![0x5e; 20];
+
+        let block = BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&BOB).call(CallBuilder::new(&[0x5e; 20]).log(log)))
+            .build();
+
+        assert!(transform_block_to_sales(&block).is_empty());
+    }
+}
@@ -0,0 +1,401 @@
+// The logic of the store modules, generic over the stores so that it can run outside of the
+// substreams runtime. The handlers only pass their stores through.
+
+use substreams::scalar::BigInt;
+use substreams::store::{StoreAdd, StoreGet, StoreSet, StoreSetIfNotExists};
+use substreams::Hex;
+use substreams_ethereum::pb as ethpb;
+
+use crate::params::Params;
+use crate::pb::transfers;
+use crate::pb::transfers::contract_classification::Standard;
+use crate::{
+    balance_key, classification, cryptopunks, erc4907, erc5192, is_nft_contract, is_nft_schema,
+    is_single_owner_schema, is_transfer_from_expected_standard, nft_key, ownable, standard_to_string,
+    transform_block_to_nft_transfers, transform_block_to_transfers_with_legacy_contracts, ZERO_ADDRESS,
+};
+
+pub fn store_token_users(blk: &ethpb::eth::v2::Block, store: &impl StoreSet<transfers::TokenUser>) {
+    for token_user in erc4907::transform_block_to_token_users(blk) {
+        store.set(
+            token_user.ordinal,
+            nft_key(&token_user.contract_address, &token_user.token_id),
+            &token_user,
+        );
+    }
+}
+
+pub fn store_token_locks(blk: &ethpb::eth::v2::Block, store: &impl StoreSet<transfers::TokenLock>) {
+    for token_lock in erc5192::transform_block_to_token_locks(blk) {
+        store.set(
+            token_lock.ordinal,
+            nft_key(&token_lock.contract_address, &token_lock.token_id),
+            &token_lock,
+        );
+    }
+}
+
+pub fn store_contract_standards(blk: &ethpb::eth::v2::Block, store: &impl StoreSet<String>) {
+    for classification in classification::transform_block_to_contract_classifications(blk) {
+        store.set(
+            classification.ordinal,
+            Hex(&classification.contract_address).to_string(),
+            &classification.standard,
+        );
+    }
+}
+
+// Contracts that emitted at least one NFT transfer, with the schema of the first one. The store
+// can't read itself, only the classification tells which contracts emit legacy ERC721 transfers.
+pub fn store_nft_contracts(
+    blk: &ethpb::eth::v2::Block,
+    params: &Params,
+    contract_standards: &impl StoreGet<String>,
+    store: &impl StoreSetIfNotExists<String>,
+) {
+    let erc721 = standard_to_string(Standard::Erc721);
+    let (_timestamp, transfers) = transform_block_to_transfers_with_legacy_contracts(blk, params, &|address| {
+        contract_standards.get_last(Hex(address).to_string()).as_ref() == Some(&erc721)
+    });
+
+    for transfer in transfers {
+        if !is_transfer_from_expected_standard(contract_standards, &transfer) {
+            continue;
+        }
+
+        store.set_if_not_exists(
+            transfer.ordinal,
+            Hex(&transfer.contract_address).to_string(),
+            &transfer.schema,
+        );
+    }
+}
+
+pub fn store_contract_owners(
+    blk: &ethpb::eth::v2::Block,
+    contract_standards: &impl StoreGet<String>,
+    nft_contracts: &impl StoreGet<String>,
+    store: &impl StoreSet<transfers::OwnershipTransfer>,
+) {
+    for ownership_transfer in ownable::transform_block_to_ownership_transfers(blk) {
+        let key = Hex(&ownership_transfer.contract_address).to_string();
+
+        if !is_nft_contract(contract_standards, nft_contracts, &key) {
+            continue;
+        }
+
+        store.set(ownership_transfer.ordinal, key, &ownership_transfer);
+    }
+}
+
+// Owner of every token that can only have one, keyed like the `nfts` table. Burnt tokens are
+// owned by the zero address.
+pub fn store_nft_owners(
+    blk: &ethpb::eth::v2::Block,
+    params: &Params,
+    contract_standards: &impl StoreGet<String>,
+    nft_contracts: &impl StoreGet<String>,
+    store: &impl StoreSet<String>,
+) {
+    let (_timestamp, transfers) = transform_block_to_nft_transfers(blk, params, contract_standards, nft_contracts);
+
+    for transfer in transfers {
+        if !is_transfer_from_expected_standard(contract_standards, &transfer) || !is_single_owner_schema(&transfer.schema) {
+            continue;
+        }
+
+        store.set(
+            transfer.ordinal,
+            nft_key(&transfer.contract_address, &transfer.token_id),
+            &Hex(&transfer.to_address).to_string(),
+        );
+    }
+}
+
+// Quantity of each token held by each holder, keyed like the `balances` table. Tokens received
+// before the start block of the module are not accounted for, their holders can have a negative
+// balance once they send them.
+pub fn store_balances(
+    blk: &ethpb::eth::v2::Block,
+    params: &Params,
+    contract_standards: &impl StoreGet<String>,
+    nft_contracts: &impl StoreGet<String>,
+    store: &impl StoreAdd<BigInt>,
+) {
+    let (_timestamp, transfers) = transform_block_to_nft_transfers(blk, params, contract_standards, nft_contracts);
+
+    for transfer in transfers {
+        if !is_transfer_from_expected_standard(contract_standards, &transfer) || !is_nft_schema(&transfer.schema) {
+            continue;
+        }
+
+        let quantity = BigInt::try_from(&transfer.quantity).unwrap_or_else(|_| BigInt::zero());
+
+        if transfer.from_address != ZERO_ADDRESS {
+            store.add(
+                transfer.ordinal,
+                balance_key(&transfer.contract_address, &transfer.token_id, &transfer.from_address),
+                quantity.neg(),
+            );
+        }
+
+        if transfer.to_address != ZERO_ADDRESS {
+            store.add(
+                transfer.ordinal,
+                balance_key(&transfer.contract_address, &transfer.token_id, &transfer.to_address),
+                quantity,
+            );
+        }
+    }
+}
+
+// Number of transfers of every NFT contract, keyed like the `collections` table.
+pub fn store_collections(
+    blk: &ethpb::eth::v2::Block,
+    params: &Params,
+    contract_standards: &impl StoreGet<String>,
+    nft_contracts: &impl StoreGet<String>,
+    store: &impl StoreAdd<i64>,
+) {
+    let (_timestamp, transfers) = transform_block_to_nft_transfers(blk, params, contract_standards, nft_contracts);
+
+    for transfer in transfers {
+        if !is_transfer_from_expected_standard(contract_standards, &transfer) || !is_nft_schema(&transfer.schema) {
+            continue;
+        }
+
+        store.add(transfer.ordinal, Hex(&transfer.contract_address).to_string(), 1);
+    }
+}
+
+// Tokens transferred at least once, keyed like the `Token` entities, with the schema of their
+// first transfer. `graph_out` creates the entity of a token from the delta of its first transfer.
+pub fn store_tokens(
+    blk: &ethpb::eth::v2::Block,
+    params: &Params,
+    contract_standards: &impl StoreGet<String>,
+    nft_contracts: &impl StoreGet<String>,
+    store: &impl StoreSetIfNotExists<String>,
+) {
+    let (_timestamp, transfers) = transform_block_to_nft_transfers(blk, params, contract_standards, nft_contracts);
+
+    for transfer in transfers {
+        if !is_transfer_from_expected_standard(contract_standards, &transfer) || !is_nft_schema(&transfer.schema) {
+            continue;
+        }
+
+        store.set_if_not_exists(
+            transfer.ordinal,
+            nft_key(&transfer.contract_address, &transfer.token_id),
+            &transfer.schema,
+        );
+    }
+}
+
+// Addresses that sent or received a token, keyed like the `Account` entities, with the number
+// of the block they were first seen in. Same as `store_tokens` for the accounts.
+pub fn store_accounts(
+    blk: &ethpb::eth::v2::Block,
+    params: &Params,
+    contract_standards: &impl StoreGet<String>,
+    nft_contracts: &impl StoreGet<String>,
+    store: &impl StoreSetIfNotExists<String>,
+) {
+    let (_timestamp, transfers) = transform_block_to_nft_transfers(blk, params, contract_standards, nft_contracts);
+
+    for transfer in transfers {
+        if !is_transfer_from_expected_standard(contract_standards, &transfer) || !is_nft_schema(&transfer.schema) {
+            continue;
+        }
+
+        for account in [&transfer.from_address, &transfer.to_address] {
+            store.set_if_not_exists(transfer.ordinal, Hex(account).to_string(), &blk.number.to_string());
+        }
+    }
+}
+
+// Amount of the last bid entered on each punk, keyed like the `nfts` table. Bids are never
+// removed: a bid can only be accepted while it's live and every live bid entered after the start
+// block of the module has its `PunkBidEntered` here, so the value read when one is accepted is
+// always the one of the accepted bid.
+pub fn store_punk_bids(blk: &ethpb::eth::v2::Block, store: &impl StoreSet<String>) {
+    for bid in cryptopunks::transform_block_to_bids(blk) {
+        store.set(bid.ordinal, nft_key(&bid.contract_address, &bid.token_id), &bid.value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::memory_store::MemoryStore;
+    use crate::testing::{
+        erc1155_transfer_single_log, erc721_transfer_log, punk_bid_entered_log, BlockBuilder, CallBuilder,
+        TransactionBuilder,
+    };
+
+    const CONTRACT_721: [u8; 20] = [0x72; 20];
+    const CONTRACT_1155: [u8; 20] = [0x11; 20];
+    const ALICE: [u8; 20] = [0xa1; 20];
+    const BOB: [u8; 20] = [0xb0; 20];
+
+    // A block with a transaction per log, each log emitted by a call to its contract.
+    fn block(number: u64, logs: Vec<ethpb::eth::v2::Log>) -> ethpb::eth::v2::Block {
+        logs.into_iter()
+            .enumerate()
+            .fold(BlockBuilder::new(number), |builder, (i, log)| {
+                builder.transaction(TransactionBuilder::new(i as u8 + 1).from(&ALICE).call(CallBuilder::new(&log.address).log(log)))
+            })
+            .build()
+    }
+
+    fn delta_keys<V: Clone>(store: &MemoryStore<V>) -> Vec<String> {
+        store.deltas().into_iter().map(|delta| delta.key).collect()
+    }
+
+    #[test]
+    fn it_stores_the_schema_of_the_first_transfer_of_nft_contracts() {
+        let store: MemoryStore<String> = MemoryStore::new();
+        let contract_standards: MemoryStore<String> = MemoryStore::new();
+
+        store_nft_contracts(
+            &block(12287507, vec![
+                erc721_transfer_log(&CONTRACT_721, &ZERO_ADDRESS, &ALICE, 1),
+                erc1155_transfer_single_log(&CONTRACT_1155, &ALICE, &ZERO_ADDRESS, &ALICE, 7, 10),
+            ]),
+            &Params::default(),
+            &contract_standards,
+            &store,
+        );
+        store.commit();
+
+        assert_eq!(store.get_last(Hex(CONTRACT_721).to_string()), Some("erc721".to_string()));
+        assert_eq!(store.get_last(Hex(CONTRACT_1155).to_string()), Some("erc1155".to_string()));
+
+        store_nft_contracts(
+            &block(12287508, vec![erc721_transfer_log(&CONTRACT_721, &ALICE, &BOB, 1)]),
+            &Params::default(),
+            &contract_standards,
+            &store,
+        );
+
+        assert!(store.deltas().is_empty());
+    }
+
+    #[test]
+    fn it_stores_the_owner_of_single_owner_tokens() {
+        let store: MemoryStore<String> = MemoryStore::new();
+        let empty: MemoryStore<String> = MemoryStore::new();
+        let key = format!("{}-01", Hex(CONTRACT_721));
+
+        store_nft_owners(
+            &block(12287507, vec![
+                erc721_transfer_log(&CONTRACT_721, &ZERO_ADDRESS, &ALICE, 1),
+                erc721_transfer_log(&CONTRACT_721, &ALICE, &BOB, 1),
+                erc1155_transfer_single_log(&CONTRACT_1155, &ALICE, &ZERO_ADDRESS, &ALICE, 7, 10),
+            ]),
+            &Params::default(),
+            &empty,
+            &empty,
+            &store,
+        );
+
+        assert_eq!(delta_keys(&store), vec![key.clone(), key.clone()]);
+        assert_eq!(store.get_last(&key), Some(Hex(BOB).to_string()));
+
+        store.commit();
+        store_nft_owners(
+            &block(12287508, vec![erc721_transfer_log(&CONTRACT_721, &BOB, &ZERO_ADDRESS, 1)]),
+            &Params::default(),
+            &empty,
+            &empty,
+            &store,
+        );
+
+        assert_eq!(store.get_last(&key), Some(Hex(ZERO_ADDRESS).to_string()));
+    }
+
+    #[test]
+    fn it_stores_the_balances_of_holders() {
+        let store: MemoryStore<BigInt> = MemoryStore::new();
+        let empty: MemoryStore<String> = MemoryStore::new();
+
+        store_balances(
+            &block(12287507, vec![
+                erc1155_transfer_single_log(&CONTRACT_1155, &ALICE, &ZERO_ADDRESS, &ALICE, 7, 10),
+                erc1155_transfer_single_log(&CONTRACT_1155, &ALICE, &ALICE, &BOB, 7, 4),
+            ]),
+            &Params::default(),
+            &empty,
+            &empty,
+            &store,
+        );
+
+        let alice = format!("{}-07-{}", Hex(CONTRACT_1155), Hex(ALICE));
+        let bob = format!("{}-07-{}", Hex(CONTRACT_1155), Hex(BOB));
+
+        assert_eq!(delta_keys(&store), vec![alice.clone(), alice.clone(), bob.clone()]);
+        assert_eq!(store.get_last(&alice), Some(BigInt::from(6)));
+        assert_eq!(store.get_last(&bob), Some(BigInt::from(4)));
+    }
+
+    #[test]
+    fn it_counts_the_transfers_of_collections() {
+        let store: MemoryStore<i64> = MemoryStore::new();
+        let empty: MemoryStore<String> = MemoryStore::new();
+
+        store_collections(
+            &block(12287507, vec![
+                erc721_transfer_log(&CONTRACT_721, &ZERO_ADDRESS, &ALICE, 1),
+                erc721_transfer_log(&CONTRACT_721, &ALICE, &BOB, 1),
+                erc1155_transfer_single_log(&CONTRACT_1155, &ALICE, &ZERO_ADDRESS, &ALICE, 7, 10),
+            ]),
+            &Params::default(),
+            &empty,
+            &empty,
+            &store,
+        );
+
+        assert_eq!(store.get_last(Hex(CONTRACT_721).to_string()), Some(2));
+        assert_eq!(store.get_last(Hex(CONTRACT_1155).to_string()), Some(1));
+    }
+
+    #[test]
+    fn it_stores_tokens_and_accounts_the_first_time_they_are_seen() {
+        let tokens: MemoryStore<String> = MemoryStore::new();
+        let accounts: MemoryStore<String> = MemoryStore::new();
+        let empty: MemoryStore<String> = MemoryStore::new();
+
+        let mint = block(12287507, vec![erc721_transfer_log(&CONTRACT_721, &ZERO_ADDRESS, &ALICE, 1)]);
+        store_tokens(&mint, &Params::default(), &empty, &empty, &tokens);
+        store_accounts(&mint, &Params::default(), &empty, &empty, &accounts);
+
+        assert_eq!(delta_keys(&tokens), vec![format!("{}-01", Hex(CONTRACT_721))]);
+        assert_eq!(tokens.get_last(format!("{}-01", Hex(CONTRACT_721))), Some("erc721".to_string()));
+        assert_eq!(delta_keys(&accounts), vec![Hex(ZERO_ADDRESS).to_string(), Hex(ALICE).to_string()]);
+        assert_eq!(accounts.get_last(Hex(ALICE).to_string()), Some("12287507".to_string()));
+
+        tokens.commit();
+        accounts.commit();
+
+        let transfer = block(12287508, vec![erc721_transfer_log(&CONTRACT_721, &ALICE, &BOB, 1)]);
+        store_tokens(&transfer, &Params::default(), &empty, &empty, &tokens);
+        store_accounts(&transfer, &Params::default(), &empty, &empty, &accounts);
+
+        assert!(delta_keys(&tokens).is_empty());
+        assert_eq!(delta_keys(&accounts), vec![Hex(BOB).to_string()]);
+    }
+
+    #[test]
+    fn it_stores_the_last_bid_entered_on_punks() {
+        let store: MemoryStore<String> = MemoryStore::new();
+
+        store_punk_bids(
+            &block(12287507, vec![punk_bid_entered_log(42, 1000, &ALICE), punk_bid_entered_log(42, 2000, &BOB)]),
+            &store,
+        );
+
+        let key = nft_key(&crate::testing::CRYPTOPUNKS_ADDRESS, &[42]);
+        assert_eq!(store.get_last(key), Some("2000".to_string()));
+    }
+}
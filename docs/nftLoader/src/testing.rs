@@ -0,0 +1,775 @@
+// Builders of synthetic blocks for tests, with helpers producing the logs of the events decoded
+// by the modules. Indexes and ordinals are assigned when the block is built, in the order the
+// transactions, calls and logs were added, so tests only give what they assert on.
+//
+//     let block = BlockBuilder::new(12287507)
+//         .transaction(TransactionBuilder::new(0x01).call(
+//             CallBuilder::new(&contract).log(erc721_transfer_log(&contract, &from, &to, 1)),
+//         ))
+//         .build();
+
+use ethabi::{Address, Token, Uint};
+use hex_literal::hex;
+use prost_types::Timestamp;
+use substreams_ethereum::pb as ethpb;
+use substreams_ethereum::pb::eth::v2::{CallType, TransactionTraceStatus};
+
+const TRANSFER_TOPIC: [u8; 32] = hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+const TRANSFER_SINGLE_TOPIC: [u8; 32] = hex!("c3d58168c5ae7397731d063d5bbf3d657854427343f4c083240f7aacaa2d0f62");
+const CONSECUTIVE_TRANSFER_TOPIC: [u8; 32] = hex!("deaa91b6123d068f5821d0fb0678463d1a8a6079fe8af5de3ce5e896dcf9133d");
+const TRANSFER_BATCH_TOPIC: [u8; 32] = hex!("4a39dc06d4c0dbc64b70af90fd698a233a518aa5d07e595d983b8c0526c8f7fb");
+const ORDER_FULFILLED_TOPIC: [u8; 32] = hex!("9d9af8e38d66c62e2c12f0225249fd9d721c54b83f48d9352c97c6cacdcb6f31");
+const PUNK_BOUGHT_TOPIC: [u8; 32] = hex!("58e5d5a525e3b40bc15abaa38b5882678db1ee68befd2f60bafe3a7fd06db9e3");
+const PUNK_BID_ENTERED_TOPIC: [u8; 32] = hex!("5b859394fabae0c1ba88baffe67e751ab5248d2e879028b8c8d6897b0519f56a");
+const UPGRADED_TOPIC: [u8; 32] = hex!("bc7cd75a20ee27fd9adebab32041f755214dbc6bffa90cc0225b39da2e5c2d3b");
+const ADMIN_CHANGED_TOPIC: [u8; 32] = hex!("7e644d79422f17c01e4894b5f4f588d331ebfa28653d42ae832dc59e38c9798f");
+const APPROVAL_TOPIC: [u8; 32] = hex!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925");
+const APPROVAL_FOR_ALL_TOPIC: [u8; 32] = hex!("17307eab39ab6107e8899845ad3d59bd9653f200f220920489ca2b5937696c31");
+const ORDER_CANCELLED_TOPIC: [u8; 32] = hex!("6bacc01dbe442496068f7d234edd811f1a5f833243e0aec824f86ab861f3c90d");
+const COUNTER_INCREMENTED_TOPIC: [u8; 32] = hex!("721c20121297512b72821b97f5326877ea8ecf4bb9948fea5bfcb6453074d37f");
+const PUNK_OFFERED_TOPIC: [u8; 32] = hex!("3c7b682d5da98001a9b8cbda6c647d2c63d698a4184fd1d55e2ce7b66f5d21eb");
+const METADATA_UPDATE_TOPIC: [u8; 32] = hex!("f8e1a15aba9398e019f0b49df1a4fde98ee17ae345cb5f6b5e2c27f5033e8ce7");
+const UPDATE_USER_TOPIC: [u8; 32] = hex!("4e06b4e7000e659094299b3533b47b6aa8ad048e95e872d23d1f4ee55af89cfe");
+const LOCKED_TOPIC: [u8; 32] = hex!("032bc66be43dbccb7487781d168eb7bda224628a3b2c3388bdf69b532a3a1611");
+const ACCOUNT_CREATED_TOPIC: [u8; 32] = hex!("79f19b3655ee38b1ce526556b7731a20c8f218fbda4a3990b6cc4172fdf88722");
+const OWNERSHIP_TRANSFERRED_TOPIC: [u8; 32] = hex!("8be0079c531659141344cd1fd0a4f28419497f9722a3daafe3b4186f6b6457e0");
+
+// Seaport v1.5, any of the addresses known to `seaport.rs` is decoded.
+pub const SEAPORT_ADDRESS: [u8; 20] = hex!("00000000000000adc04c56bf30ac9d3c0aaf14dc");
+
+// The CryptoPunks market, its balance `Transfer` shares the signature of the ERC20 one.
+pub const CRYPTOPUNKS_ADDRESS: [u8; 20] = hex!("b47e3cd837ddf8e4c57f05d70ab865de6e193bbb");
+
+// The canonical ERC6551 registry, accounts created by other registries are ignored.
+pub const ERC6551_REGISTRY_ADDRESS: [u8; 20] = hex!("000000006551c19487814612e58fe06813775758");
+
+// The timestamp of the start block of the modules, blocks built without one are 12 seconds apart
+// from it.
+const START_BLOCK: u64 = 12287507;
+const START_TIMESTAMP: i64 = 1618481223;
+
+pub struct BlockBuilder {
+    number: u64,
+    timestamp: Option<i64>,
+    transactions: Vec<TransactionBuilder>,
+}
+
+pub struct TransactionBuilder {
+    hash: Vec<u8>,
+    from: Vec<u8>,
+    to: Vec<u8>,
+    status: TransactionTraceStatus,
+    calls: Vec<CallBuilder>,
+}
+
+pub struct CallBuilder {
+    address: Vec<u8>,
+    caller: Vec<u8>,
+    call_type: CallType,
+    depth: u32,
+    reverted: bool,
+    input: Vec<u8>,
+    code: Vec<u8>,
+    storage_changes: Vec<(Vec<u8>, Vec<u8>)>,
+    logs: Vec<ethpb::eth::v2::Log>,
+}
+
+// (itemType, token, identifier, amount) of the `offer` of an `OrderFulfilled`.
+pub type SpentItem = (u64, Vec<u8>, u64, u64);
+// (itemType, token, identifier, amount, recipient) of the `consideration` of an `OrderFulfilled`.
+pub type ReceivedItem = (u64, Vec<u8>, u64, u64, Vec<u8>);
+
+impl BlockBuilder {
+    pub fn new(number: u64) -> Self {
+        BlockBuilder {
+            number: number,
+            timestamp: None,
+            transactions: Vec::new(),
+        }
+    }
+
+    pub fn timestamp(mut self, seconds: i64) -> Self {
+        self.timestamp = Some(seconds);
+        self
+    }
+
+    pub fn transaction(mut self, transaction: TransactionBuilder) -> Self {
+        self.transactions.push(transaction);
+        self
+    }
+
+    pub fn build(self) -> ethpb::eth::v2::Block {
+        let timestamp = self.timestamp.unwrap_or_else(|| {
+            START_TIMESTAMP + (self.number as i64 - START_BLOCK as i64) * 12
+        });
+
+        let mut ordinal = 0;
+        let mut block_index = 0;
+
+        let transaction_traces = self.transactions.into_iter().enumerate().map(|(i, transaction)| {
+            transaction.build(i as u32, &mut ordinal, &mut block_index)
+        }).collect();
+
+        ethpb::eth::v2::Block {
+            ver: 2,
+            hash: word(&self.number.to_be_bytes()),
+            number: self.number,
+            header: Some(ethpb::eth::v2::BlockHeader {
+                number: self.number,
+                hash: word(&self.number.to_be_bytes()),
+                parent_hash: word(&self.number.saturating_sub(1).to_be_bytes()),
+                timestamp: Some(Timestamp { seconds: timestamp, nanos: 0 }),
+                ..Default::default()
+            }),
+            transaction_traces: transaction_traces,
+            ..Default::default()
+        }
+    }
+}
+
+impl TransactionBuilder {
+    // The hash of the transaction is `byte` repeated.
+    pub fn new(byte: u8) -> Self {
+        TransactionBuilder {
+            hash: vec![byte; 32],
+            from: Vec::new(),
+            to: Vec::new(),
+            status: TransactionTraceStatus::Succeeded,
+            calls: Vec::new(),
+        }
+    }
+
+    pub fn from(mut self, from: &[u8]) -> Self {
+        self.from = from.to_vec();
+        self
+    }
+
+    pub fn to(mut self, to: &[u8]) -> Self {
+        self.to = to.to_vec();
+        self
+    }
+
+    pub fn status(mut self, status: TransactionTraceStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    // The first call is the root call of the transaction, the next ones are its subcalls unless
+    // given a depth.
+    pub fn call(mut self, call: CallBuilder) -> Self {
+        self.calls.push(call);
+        self
+    }
+
+    fn build(self, index: u32, ordinal: &mut u64, block_index: &mut u32) -> ethpb::eth::v2::TransactionTrace {
+        let begin_ordinal = next(ordinal);
+        let succeeded = self.status == TransactionTraceStatus::Succeeded;
+        let to = match (self.to.is_empty(), self.calls.first()) {
+            (true, Some(call)) => call.address.clone(),
+            _ => self.to,
+        };
+
+        let mut receipt_logs = Vec::new();
+        // (index, address) of the calls enclosing the current one.
+        let mut parents: Vec<(u32, Vec<u8>)> = Vec::new();
+
+        let calls: Vec<ethpb::eth::v2::Call> = self.calls.into_iter().enumerate().map(|(i, call)| {
+            let call_index = i as u32 + 1;
+            let depth = if i == 0 { 0 } else { call.depth.max(1) };
+            parents.truncate(depth as usize);
+
+            let (parent_index, parent_address) = parents.last().cloned().unwrap_or_else(|| (0, self.from.clone()));
+            let caller = if call.caller.is_empty() { parent_address } else { call.caller.clone() };
+            parents.push((call_index, call.address.clone()));
+
+            let call = call.build(call_index, parent_index, depth, caller, succeeded, ordinal, block_index);
+            if succeeded && !call.state_reverted {
+                receipt_logs.extend(call.logs.iter().cloned());
+            }
+
+            call
+        }).collect();
+
+        for (i, log) in receipt_logs.iter_mut().enumerate() {
+            log.index = i as u32;
+        }
+
+        ethpb::eth::v2::TransactionTrace {
+            to: to,
+            index: index,
+            hash: self.hash,
+            from: self.from,
+            begin_ordinal: begin_ordinal,
+            end_ordinal: next(ordinal),
+            status: self.status as i32,
+            receipt: Some(ethpb::eth::v2::TransactionReceipt {
+                logs: receipt_logs,
+                ..Default::default()
+            }),
+            calls: calls,
+            ..Default::default()
+        }
+    }
+}
+
+impl CallBuilder {
+    pub fn new(address: &[u8]) -> Self {
+        CallBuilder {
+            address: address.to_vec(),
+            caller: Vec::new(),
+            call_type: CallType::Call,
+            depth: 0,
+            reverted: false,
+            input: Vec::new(),
+            code: Vec::new(),
+            storage_changes: Vec::new(),
+            logs: Vec::new(),
+        }
+    }
+
+    // Defaults to the sender of the transaction for the root call and to the address of the
+    // parent call for the others.
+    pub fn caller(mut self, caller: &[u8]) -> Self {
+        self.caller = caller.to_vec();
+        self
+    }
+
+    pub fn call_type(mut self, call_type: CallType) -> Self {
+        self.call_type = call_type;
+        self
+    }
+
+    pub fn depth(mut self, depth: u32) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    // The logs of a reverted call are kept on the call but not in the receipt.
+    pub fn reverted(mut self) -> Self {
+        self.reverted = true;
+        self
+    }
+
+    // The calldata, the function selector followed by the arguments.
+    pub fn input(mut self, input: &[u8]) -> Self {
+        self.input = input.to_vec();
+        self
+    }
+
+    // The code deployed at the address of the call, when the constructor returns so after its logs.
+    pub fn code(mut self, code: &[u8]) -> Self {
+        self.code = code.to_vec();
+        self
+    }
+
+    // A write of `value` to the storage slot `key` of the address of the call, before its logs.
+    pub fn storage_change(mut self, key: &[u8], value: &[u8]) -> Self {
+        self.storage_changes.push((key.to_vec(), value.to_vec()));
+        self
+    }
+
+    pub fn log(mut self, log: ethpb::eth::v2::Log) -> Self {
+        self.logs.push(log);
+        self
+    }
+
+    fn build(
+        self,
+        index: u32,
+        parent_index: u32,
+        depth: u32,
+        caller: Vec<u8>,
+        succeeded: bool,
+        ordinal: &mut u64,
+        block_index: &mut u32,
+    ) -> ethpb::eth::v2::Call {
+        let begin_ordinal = next(ordinal);
+        let state_reverted = self.reverted || !succeeded;
+
+        let address = self.address.clone();
+        let storage_changes = self.storage_changes.into_iter().map(|(key, value)| ethpb::eth::v2::StorageChange {
+            address: address.clone(),
+            key: key,
+            new_value: value,
+            ordinal: next(ordinal),
+            ..Default::default()
+        }).collect();
+
+        let logs = self.logs.into_iter().map(|log| {
+            let mut log = log;
+            log.ordinal = next(ordinal);
+
+            if !state_reverted {
+                log.block_index = *block_index;
+                *block_index += 1;
+            }
+
+            log
+        }).collect();
+
+        let code_changes = match self.code.is_empty() {
+            true => Vec::new(),
+            false => vec![ethpb::eth::v2::CodeChange {
+                address: self.address.clone(),
+                new_code: self.code,
+                ordinal: next(ordinal),
+                ..Default::default()
+            }],
+        };
+
+        ethpb::eth::v2::Call {
+            index: index,
+            parent_index: parent_index,
+            depth: depth,
+            call_type: self.call_type as i32,
+            caller: caller,
+            address: self.address,
+            input: self.input,
+            logs: logs,
+            storage_changes: storage_changes,
+            code_changes: code_changes,
+            status_reverted: self.reverted,
+            state_reverted: state_reverted,
+            begin_ordinal: begin_ordinal,
+            end_ordinal: next(ordinal),
+            ..Default::default()
+        }
+    }
+}
+
+pub fn erc721_transfer_log(contract: &[u8], from: &[u8], to: &[u8], token_id: u64) -> ethpb::eth::v2::Log {
+    new_log(
+        contract,
+        vec![TRANSFER_TOPIC.to_vec(), word(from), word(to), uint_word(token_id)],
+        Vec::new(),
+    )
+}
+
+// `Transfer` of the NFT contracts that predate ERC721, every parameter is in the data.
+pub fn erc721_legacy_transfer_log(contract: &[u8], from: &[u8], to: &[u8], token_id: u64) -> ethpb::eth::v2::Log {
+    new_log(
+        contract,
+        vec![TRANSFER_TOPIC.to_vec()],
+        ethabi::encode(&[address(from), address(to), uint(token_id)]),
+    )
+}
+
+pub fn erc721_approval_log(contract: &[u8], owner: &[u8], approved: &[u8], token_id: u64) -> ethpb::eth::v2::Log {
+    new_log(
+        contract,
+        vec![APPROVAL_TOPIC.to_vec(), word(owner), word(approved), uint_word(token_id)],
+        Vec::new(),
+    )
+}
+
+pub fn approval_for_all_log(contract: &[u8], owner: &[u8], operator: &[u8], approved: bool) -> ethpb::eth::v2::Log {
+    new_log(
+        contract,
+        vec![APPROVAL_FOR_ALL_TOPIC.to_vec(), word(owner), word(operator)],
+        ethabi::encode(&[Token::Bool(approved)]),
+    )
+}
+
+// ERC20 `Transfer` shares the signature of the ERC721 one, the amount is in the data.
+pub fn erc20_transfer_log(contract: &[u8], from: &[u8], to: &[u8], amount: u64) -> ethpb::eth::v2::Log {
+    new_log(
+        contract,
+        vec![TRANSFER_TOPIC.to_vec(), word(from), word(to)],
+        ethabi::encode(&[uint(amount)]),
+    )
+}
+
+// ERC2309 `ConsecutiveTransfer` of the tokens `from_token_id` to `to_token_id`, both included.
+pub fn erc2309_consecutive_transfer_log(
+    contract: &[u8],
+    from_token_id: u64,
+    to_token_id: u64,
+    from: &[u8],
+    to: &[u8],
+) -> ethpb::eth::v2::Log {
+    new_log(
+        contract,
+        vec![CONSECUTIVE_TRANSFER_TOPIC.to_vec(), uint_word(from_token_id), word(from), word(to)],
+        ethabi::encode(&[uint(to_token_id)]),
+    )
+}
+
+pub fn erc1155_transfer_single_log(
+    contract: &[u8],
+    operator: &[u8],
+    from: &[u8],
+    to: &[u8],
+    id: u64,
+    value: u64,
+) -> ethpb::eth::v2::Log {
+    new_log(
+        contract,
+        vec![TRANSFER_SINGLE_TOPIC.to_vec(), word(operator), word(from), word(to)],
+        ethabi::encode(&[uint(id), uint(value)]),
+    )
+}
+
+pub fn erc1155_transfer_batch_log(
+    contract: &[u8],
+    operator: &[u8],
+    from: &[u8],
+    to: &[u8],
+    ids: &[u64],
+    values: &[u64],
+) -> ethpb::eth::v2::Log {
+    new_log(
+        contract,
+        vec![TRANSFER_BATCH_TOPIC.to_vec(), word(operator), word(from), word(to)],
+        ethabi::encode(&[
+            Token::Array(ids.iter().map(|id| uint(*id)).collect()),
+            Token::Array(values.iter().map(|value| uint(*value)).collect()),
+        ]),
+    )
+}
+
+// An `OrderFulfilled` emitted by `SEAPORT_ADDRESS`.
+pub fn seaport_order_fulfilled_log(
+    order_hash: &[u8],
+    offerer: &[u8],
+    zone: &[u8],
+    recipient: &[u8],
+    offer: &[SpentItem],
+    consideration: &[ReceivedItem],
+) -> ethpb::eth::v2::Log {
+    let offer = offer.iter().map(|(item_type, token, identifier, amount)| Token::Tuple(vec![
+        uint(*item_type),
+        address(token),
+        uint(*identifier),
+        uint(*amount),
+    ])).collect();
+
+    let consideration = consideration.iter().map(|(item_type, token, identifier, amount, recipient)| Token::Tuple(vec![
+        uint(*item_type),
+        address(token),
+        uint(*identifier),
+        uint(*amount),
+        address(recipient),
+    ])).collect();
+
+    new_log(
+        &SEAPORT_ADDRESS,
+        vec![ORDER_FULFILLED_TOPIC.to_vec(), word(offerer), word(zone)],
+        ethabi::encode(&[
+            Token::FixedBytes(order_hash.to_vec()),
+            address(recipient),
+            Token::Array(offer),
+            Token::Array(consideration),
+        ]),
+    )
+}
+
+pub fn seaport_order_cancelled_log(order_hash: &[u8], offerer: &[u8], zone: &[u8]) -> ethpb::eth::v2::Log {
+    new_log(
+        &SEAPORT_ADDRESS,
+        vec![ORDER_CANCELLED_TOPIC.to_vec(), word(offerer), word(zone)],
+        ethabi::encode(&[Token::FixedBytes(order_hash.to_vec())]),
+    )
+}
+
+pub fn seaport_counter_incremented_log(new_counter: u64, offerer: &[u8]) -> ethpb::eth::v2::Log {
+    new_log(
+        &SEAPORT_ADDRESS,
+        vec![COUNTER_INCREMENTED_TOPIC.to_vec(), word(offerer)],
+        ethabi::encode(&[uint(new_counter)]),
+    )
+}
+
+// A `PunkBought` emitted by `CRYPTOPUNKS_ADDRESS`, an accepted bid has a zero `value` and `to`.
+pub fn punk_bought_log(punk_index: u64, value: u64, from: &[u8], to: &[u8]) -> ethpb::eth::v2::Log {
+    new_log(
+        &CRYPTOPUNKS_ADDRESS,
+        vec![PUNK_BOUGHT_TOPIC.to_vec(), uint_word(punk_index), word(from), word(to)],
+        ethabi::encode(&[uint(value)]),
+    )
+}
+
+pub fn punk_bid_entered_log(punk_index: u64, value: u64, from: &[u8]) -> ethpb::eth::v2::Log {
+    new_log(
+        &CRYPTOPUNKS_ADDRESS,
+        vec![PUNK_BID_ENTERED_TOPIC.to_vec(), uint_word(punk_index), word(from)],
+        ethabi::encode(&[uint(value)]),
+    )
+}
+
+// `to` is the zero address for offers open to anyone.
+pub fn punk_offered_log(punk_index: u64, min_value: u64, to: &[u8]) -> ethpb::eth::v2::Log {
+    new_log(
+        &CRYPTOPUNKS_ADDRESS,
+        vec![PUNK_OFFERED_TOPIC.to_vec(), uint_word(punk_index), word(to)],
+        ethabi::encode(&[uint(min_value)]),
+    )
+}
+
+pub fn erc4906_metadata_update_log(contract: &[u8], token_id: u64) -> ethpb::eth::v2::Log {
+    new_log(contract, vec![METADATA_UPDATE_TOPIC.to_vec()], ethabi::encode(&[uint(token_id)]))
+}
+
+pub fn erc4907_update_user_log(contract: &[u8], token_id: u64, user: &[u8], expires: u64) -> ethpb::eth::v2::Log {
+    new_log(
+        contract,
+        vec![UPDATE_USER_TOPIC.to_vec(), uint_word(token_id), word(user)],
+        ethabi::encode(&[uint(expires)]),
+    )
+}
+
+pub fn erc5192_locked_log(contract: &[u8], token_id: u64) -> ethpb::eth::v2::Log {
+    new_log(contract, vec![LOCKED_TOPIC.to_vec()], ethabi::encode(&[uint(token_id)]))
+}
+
+// An `ERC6551AccountCreated` emitted by `ERC6551_REGISTRY_ADDRESS` for a mainnet token, with a
+// zero salt.
+pub fn erc6551_account_created_log(
+    account: &[u8],
+    implementation: &[u8],
+    token_contract: &[u8],
+    token_id: u64,
+) -> ethpb::eth::v2::Log {
+    new_log(
+        &ERC6551_REGISTRY_ADDRESS,
+        vec![ACCOUNT_CREATED_TOPIC.to_vec(), word(implementation), word(token_contract), uint_word(token_id)],
+        ethabi::encode(&[address(account), Token::FixedBytes(vec![0; 32]), uint(1)]),
+    )
+}
+
+// ERC1967 `Upgraded` of the proxy at `contract`.
+pub fn erc1967_upgraded_log(contract: &[u8], implementation: &[u8]) -> ethpb::eth::v2::Log {
+    new_log(contract, vec![UPGRADED_TOPIC.to_vec(), word(implementation)], Vec::new())
+}
+
+// ERC1967 `AdminChanged` of the proxy at `contract`, both admins are in the data.
+pub fn erc1967_admin_changed_log(contract: &[u8], previous_admin: &[u8], new_admin: &[u8]) -> ethpb::eth::v2::Log {
+    new_log(
+        contract,
+        vec![ADMIN_CHANGED_TOPIC.to_vec()],
+        ethabi::encode(&[address(previous_admin), address(new_admin)]),
+    )
+}
+
+// Ownable `OwnershipTransferred` of `contract`.
+pub fn ownership_transferred_log(contract: &[u8], previous_owner: &[u8], new_owner: &[u8]) -> ethpb::eth::v2::Log {
+    new_log(
+        contract,
+        vec![OWNERSHIP_TRANSFERRED_TOPIC.to_vec(), word(previous_owner), word(new_owner)],
+        Vec::new(),
+    )
+}
+
+fn new_log(address: &[u8], topics: Vec<Vec<u8>>, data: Vec<u8>) -> ethpb::eth::v2::Log {
+    ethpb::eth::v2::Log {
+        address: address.to_vec(),
+        topics: topics,
+        data: data,
+        ..Default::default()
+    }
+}
+
+fn next(ordinal: &mut u64) -> u64 {
+    let current = *ordinal;
+    *ordinal += 1;
+    current
+}
+
+// `bytes` left padded to 32 bytes, like an address or a number in a topic.
+fn word(bytes: &[u8]) -> Vec<u8> {
+    assert!(bytes.len() <= 32, "{} bytes don't fit in a 32 bytes word", bytes.len());
+
+    let mut word = vec![0u8; 32 - bytes.len()];
+    word.extend_from_slice(bytes);
+    word
+}
+
+fn uint_word(value: u64) -> Vec<u8> {
+    word(&value.to_be_bytes())
+}
+
+fn uint(value: u64) -> Token {
+    Token::Uint(Uint::from(value))
+}
+
+fn address(bytes: &[u8]) -> Token {
+    Token::Address(Address::from_slice(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTRACT: [u8; 20] = [0x11; 20];
+    const ALICE: [u8; 20] = [0xa1; 20];
+    const BOB: [u8; 20] = [0xb0; 20];
+
+    #[test]
+    fn it_builds_block_zero() {
+        let block = BlockBuilder::new(0).timestamp(1438269973).build();
+        let header = block.header.unwrap();
+
+        assert_eq!(header.parent_hash, vec![0; 32]);
+        assert_eq!(header.timestamp.unwrap().seconds, 1438269973);
+    }
+
+    #[test]
+    fn it_spaces_blocks_by_12_seconds_from_the_start_block() {
+        let timestamp = |number| BlockBuilder::new(number).build().header.unwrap().timestamp.unwrap().seconds;
+
+        assert_eq!(timestamp(START_BLOCK), START_TIMESTAMP);
+        assert_eq!(timestamp(START_BLOCK + 2), START_TIMESTAMP + 24);
+        assert_eq!(timestamp(START_BLOCK - 1), START_TIMESTAMP - 12);
+    }
+
+    #[test]
+    fn it_assigns_indexes_and_ordinals_in_order() {
+        let block = BlockBuilder::new(START_BLOCK)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE).call(
+                CallBuilder::new(&CONTRACT)
+                    .log(erc721_transfer_log(&CONTRACT, &ALICE, &BOB, 1))
+                    .log(erc721_transfer_log(&CONTRACT, &ALICE, &BOB, 2)),
+            ))
+            .transaction(TransactionBuilder::new(0x02).from(&BOB).call(
+                CallBuilder::new(&CONTRACT).log(erc721_transfer_log(&CONTRACT, &BOB, &ALICE, 1)),
+            ))
+            .build();
+
+        let transactions = &block.transaction_traces;
+        assert_eq!(transactions[1].index, 1);
+        assert_eq!(transactions[1].hash, vec![0x02; 32]);
+        assert_eq!(transactions[1].to, CONTRACT.to_vec());
+
+        let logs: Vec<&ethpb::eth::v2::Log> = transactions.iter()
+            .flat_map(|transaction| transaction.receipt.as_ref().unwrap().logs.iter())
+            .collect();
+        let block_indexes: Vec<u32> = logs.iter().map(|log| log.block_index).collect();
+        let indexes: Vec<u32> = logs.iter().map(|log| log.index).collect();
+
+        assert_eq!(block_indexes, vec![0, 1, 2]);
+        assert_eq!(indexes, vec![0, 1, 0]);
+        assert!(logs.windows(2).all(|pair| pair[0].ordinal < pair[1].ordinal));
+        assert!(transactions[0].end_ordinal < transactions[1].begin_ordinal);
+    }
+
+    #[test]
+    fn it_nests_calls_by_depth() {
+        let block = BlockBuilder::new(START_BLOCK)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE)
+                .call(CallBuilder::new(&[0x01; 20]))
+                .call(CallBuilder::new(&[0x02; 20]))
+                .call(CallBuilder::new(&[0x03; 20]).depth(2).call_type(CallType::Delegate))
+                .call(CallBuilder::new(&[0x04; 20]).caller(&BOB)))
+            .build();
+
+        let calls = &block.transaction_traces[0].calls;
+        let tree: Vec<(u32, u32, u32)> = calls.iter().map(|call| (call.index, call.parent_index, call.depth)).collect();
+
+        assert_eq!(tree, vec![(1, 0, 0), (2, 1, 1), (3, 2, 2), (4, 1, 1)]);
+        assert_eq!(calls[0].caller, ALICE.to_vec());
+        assert_eq!(calls[2].caller, vec![0x02; 20]);
+        assert_eq!(calls[2].call_type, CallType::Delegate as i32);
+        assert_eq!(calls[3].caller, BOB.to_vec());
+    }
+
+    #[test]
+    fn it_reverts_every_call_of_a_failed_transaction() {
+        let block = BlockBuilder::new(START_BLOCK)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE).status(TransactionTraceStatus::Failed).call(
+                CallBuilder::new(&CONTRACT).log(erc721_transfer_log(&CONTRACT, &ALICE, &BOB, 1)),
+            ))
+            .transaction(TransactionBuilder::new(0x02).from(&ALICE).call(
+                CallBuilder::new(&CONTRACT).log(erc721_transfer_log(&CONTRACT, &ALICE, &BOB, 1)),
+            ))
+            .build();
+
+        let failed = &block.transaction_traces[0];
+
+        assert!(failed.calls[0].state_reverted);
+        assert!(failed.receipt.as_ref().unwrap().logs.is_empty());
+        assert_eq!(block.transaction_traces[1].receipt.as_ref().unwrap().logs[0].block_index, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "33 bytes don't fit in a 32 bytes word")]
+    fn it_rejects_words_over_32_bytes() {
+        word(&[0x01; 33]);
+    }
+
+    #[test]
+    fn it_builds_erc1155_batch_transfers() {
+        let block = BlockBuilder::new(START_BLOCK)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE).call(
+                CallBuilder::new(&CONTRACT)
+                    .log(erc1155_transfer_batch_log(&CONTRACT, &ALICE, &ALICE, &BOB, &[1, 2, 1], &[5, 6, 5])),
+            ))
+            .build();
+
+        let (_timestamp, transfers) = crate::transform_block_to_transfers(&block);
+
+        assert_eq!(transfers.len(), 2);
+        assert_eq!((transfers[0].token_id.clone(), transfers[0].quantity.as_str()), (vec![1], "5"));
+        assert_eq!((transfers[1].token_id.clone(), transfers[1].quantity.as_str()), (vec![2], "6"));
+        assert_eq!(transfers[0].operator, ALICE.to_vec());
+        assert_eq!(transfers[0].to_address, BOB.to_vec());
+    }
+
+    #[test]
+    fn it_builds_seaport_listings() {
+        let block = BlockBuilder::new(START_BLOCK)
+            .transaction(TransactionBuilder::new(0x01).from(&BOB).call(
+                CallBuilder::new(&SEAPORT_ADDRESS)
+                    .log(seaport_order_fulfilled_log(
+                        &[0x0f; 32],
+                        &ALICE,
+                        &[0; 20],
+                        &BOB,
+                        &[(2, CONTRACT.to_vec(), 7, 1)],
+                        &[(0, vec![0; 20], 0, 975, ALICE.to_vec()), (0, vec![0; 20], 0, 25, vec![0x22; 20])],
+                    ))
+                    .log(erc721_transfer_log(&CONTRACT, &ALICE, &BOB, 7)),
+            ))
+            .build();
+
+        let sales = crate::seaport::transform_block_to_sales(&block);
+
+        assert_eq!(sales.len(), 1);
+        assert_eq!(sales[0].seller, ALICE.to_vec());
+        assert_eq!(sales[0].buyer, BOB.to_vec());
+        assert_eq!(sales[0].token_id, vec![7]);
+        assert_eq!(sales[0].price, "1000");
+    }
+
+    #[test]
+    fn it_builds_approvals() {
+        let block = BlockBuilder::new(START_BLOCK)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE).call(
+                CallBuilder::new(&CONTRACT)
+                    .log(erc721_approval_log(&CONTRACT, &ALICE, &BOB, 7))
+                    .log(approval_for_all_log(&CONTRACT, &ALICE, &BOB, false)),
+            ))
+            .build();
+
+        let approvals = crate::approvals::transform_block_to_approvals(&block);
+
+        assert_eq!(approvals.len(), 2);
+        assert_eq!((approvals[0].token_id.clone(), approvals[0].approved), (vec![7], true));
+        assert_eq!((approvals[1].token_id.clone(), approvals[1].approved), (Vec::new(), false));
+        assert_eq!(approvals[1].spender, BOB.to_vec());
+    }
+
+    #[test]
+    fn it_drops_the_logs_of_reverted_calls_from_the_receipt() {
+        let block = BlockBuilder::new(START_BLOCK)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE)
+                .call(CallBuilder::new(&CONTRACT).log(erc20_transfer_log(&CONTRACT, &ALICE, &BOB, 1)))
+                .call(CallBuilder::new(&CONTRACT).reverted().log(erc721_transfer_log(&CONTRACT, &ALICE, &BOB, 1))))
+            .build();
+
+        let transaction = &block.transaction_traces[0];
+
+        assert_eq!(transaction.calls[1].parent_index, 1);
+        assert_eq!(transaction.calls[1].caller, CONTRACT.to_vec());
+        assert_eq!(transaction.receipt.as_ref().unwrap().logs.len(), 1);
+        assert_eq!(transaction.receipt.as_ref().unwrap().logs[0].topics.len(), 3);
+    }
+}
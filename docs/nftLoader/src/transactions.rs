@@ -0,0 +1,50 @@
+use substreams::scalar::BigInt;
+use substreams_ethereum::pb as ethpb;
+
+use crate::pb::transfers;
+
+pub fn transform_block_to_transactions(blk: &ethpb::eth::v2::Block) -> Vec<transfers::Transaction> {
+    let header = blk.header.as_ref().unwrap();
+    let base_fee_per_gas = header.base_fee_per_gas.as_ref().map(to_big_int);
+
+    blk.transactions().map(|transaction| transfers::Transaction {
+        hash: transaction.hash.to_vec(),
+        from_address: transaction.from.to_vec(),
+        to_address: transaction.to.to_vec(),
+        value: transaction.value.as_ref().map(to_big_int).unwrap_or_else(BigInt::zero).to_string(),
+        gas_used: transaction.gas_used,
+        effective_gas_price: effective_gas_price(transaction, base_fee_per_gas.as_ref()).to_string(),
+        block_number: blk.number,
+        index: transaction.index,
+        timestamp: Some(header.timestamp.as_ref().unwrap().clone()),
+    }).collect()
+}
+
+// Before London, and for legacy transactions after it, the price is the one set on the
+// transaction. Fee market transactions pay the base fee plus their priority fee, capped by their
+// max fee.
+fn effective_gas_price(transaction: &ethpb::eth::v2::TransactionTrace, base_fee_per_gas: Option<&BigInt>) -> BigInt {
+    let gas_price = transaction.gas_price.as_ref().map(to_big_int).unwrap_or_else(BigInt::zero);
+
+    let (max_fee, max_priority_fee, base_fee) = match (
+        transaction.max_fee_per_gas.as_ref(),
+        transaction.max_priority_fee_per_gas.as_ref(),
+        base_fee_per_gas,
+    ) {
+        (Some(max_fee), Some(max_priority_fee), Some(base_fee)) if !max_fee.bytes.is_empty() => {
+            (to_big_int(max_fee), to_big_int(max_priority_fee), base_fee.clone())
+        }
+        _ => return gas_price,
+    };
+
+    let price = base_fee + max_priority_fee;
+    if price > max_fee {
+        return max_fee;
+    }
+
+    price
+}
+
+fn to_big_int(value: &ethpb::eth::v2::BigInt) -> BigInt {
+    BigInt::from_unsigned_bytes_be(&value.bytes)
+}
@@ -0,0 +1,113 @@
+// Runs the modules over the blocks of every fixture in `tests/fixtures` and compares the output
+// of `db_out` and `jsonl_out` with the snapshot of the same name in `tests/snapshots`. A fixture
+// holds one or more blocks, processed in order by the same pipeline: a `.json` array of blocks
+// written from `support/fixtures.rs` or length-delimited `sf.ethereum.type.v2.Block` messages,
+// like the blocks recorded with `rpc_to_fixture`.
+//
+// Run with `UPDATE_SNAPSHOTS=1` to write the JSON fixtures and the snapshots from the current
+// code instead, and review their diff.
+#[path = "support/fixtures.rs"]
+mod fixtures;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Value};
+
+use substreams_postgresql_sink_tutorial::block_json::block_to_json;
+use substreams_postgresql_sink_tutorial::blocks::read_blocks;
+use substreams_postgresql_sink_tutorial::output::database_changes_to_json;
+use substreams_postgresql_sink_tutorial::pipeline::Pipeline;
+
+#[test]
+fn it_outputs_match_snapshots() {
+    let update = env::var("UPDATE_SNAPSHOTS").is_ok();
+    let mut mismatches = Vec::new();
+
+    // The fixtures are written first, the snapshots are computed from them.
+    for (name, blocks) in fixtures::fixtures() {
+        let fixture = fixtures_dir().join(name).with_extension("json");
+        let values: Vec<Value> = blocks.iter().map(block_to_json).collect();
+        let actual = serde_json::to_string_pretty(&values).unwrap() + "\n";
+
+        compare(update, &fixture, actual, "support/fixtures.rs", &mut mismatches);
+    }
+
+    for fixture in fixture_paths() {
+        compare(update, &snapshot_path(&fixture), run_fixture(&fixture), &fixture.display().to_string(), &mut mismatches);
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "{}\n\nrun with UPDATE_SNAPSHOTS=1 to update the fixtures and the snapshots",
+        mismatches.join("\n\n"),
+    );
+}
+
+// Writes `actual` to `path` when updating, otherwise records a mismatch when it differs from
+// the content of `path`, which is generated from `source`.
+fn compare(update: bool, path: &Path, actual: String, source: &str, mismatches: &mut Vec<String>) {
+    if update {
+        fs::write(path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(path).unwrap_or_else(|_| {
+        panic!("missing {}, run with UPDATE_SNAPSHOTS=1 to create it", path.display())
+    });
+
+    if expected != actual {
+        mismatches.push(format!(
+            "{} doesn't match {}\n--- expected\n{}\n--- actual\n{}",
+            path.display(),
+            source,
+            expected,
+            actual,
+        ));
+    }
+}
+
+// A pipeline runs a single output module, `jsonl_out` gets its own.
+fn run_fixture(fixture: &Path) -> String {
+    let mut db_out = Pipeline::new();
+    let mut jsonl_out = Pipeline::new();
+
+    let blocks = read_blocks(fixture).unwrap();
+
+    let outputs: Vec<Value> = blocks.iter().map(|blk| {
+        let lines: Vec<Value> = jsonl_out.jsonl_out(blk).lines.iter()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        json!({
+            "block_number": blk.number,
+            "db_out": database_changes_to_json(&db_out.db_out(blk)),
+            "jsonl_out": lines,
+        })
+    }).collect();
+
+    serde_json::to_string_pretty(&outputs).unwrap() + "\n"
+}
+
+fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+fn fixture_paths() -> Vec<PathBuf> {
+    let mut fixtures: Vec<PathBuf> = fs::read_dir(fixtures_dir())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().map_or(false, |extension| extension == "json" || extension == "binpb"))
+        .collect();
+    fixtures.sort();
+
+    fixtures
+}
+
+fn snapshot_path(fixture: &Path) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/snapshots")
+        .join(fixture.file_stem().unwrap())
+        .with_extension("json")
+}
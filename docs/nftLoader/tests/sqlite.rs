@@ -0,0 +1,299 @@
+// Applies the output of `db_out` over the fixtures to SQLite and asserts on the resulting rows,
+// which also checks that every table and column written exists in `schema.sql`. Together the
+// fixtures write every table of the schema.
+mod support;
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+
+use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges};
+
+use substreams_postgresql_sink_tutorial::blocks::read_blocks;
+use substreams_postgresql_sink_tutorial::pipeline::Pipeline;
+use substreams_postgresql_sink_tutorial::testing::{erc1967_upgraded_log, erc721_transfer_log, BlockBuilder, CallBuilder, TransactionBuilder};
+
+use support::sqlite::SqliteApplier;
+
+const CONTRACT_721: &str = "7272727272727272727272727272727272727272";
+const CONTRACT_1155: &str = "1111111111111111111111111111111111111111";
+const ALICE: &str = "a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1a1";
+const BOB: &str = "b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0";
+const CAROL: &str = "c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0c0";
+const CRYPTOPUNKS: &str = "b47e3cd837ddf8e4c57f05d70ab865de6e193bbb";
+const IMPLEMENTATION_SLOT: [u8; 32] = hex_literal::hex!("360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+
+fn apply_fixture(name: &str) -> SqliteApplier {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name);
+    let mut pipeline = Pipeline::new();
+    let mut applier = SqliteApplier::new().unwrap();
+
+    for blk in read_blocks(&path).unwrap() {
+        applier.apply(&pipeline.db_out(&blk)).unwrap();
+    }
+
+    applier
+}
+
+fn fixture_names() -> Vec<String> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .collect();
+    names.sort();
+
+    names
+}
+
+fn value(applier: &SqliteApplier, table: &str, id: &str, column: &str) -> Option<String> {
+    applier.row(table, id).unwrap()
+        .unwrap_or_else(|| panic!("no row {} in {}", id, table))
+        .remove(column)
+        .unwrap_or_else(|| panic!("no column {} in {}", column, table))
+}
+
+#[test]
+fn it_writes_every_table_from_the_fixtures() {
+    let mut written = BTreeSet::new();
+    let mut tables = BTreeSet::new();
+
+    for name in fixture_names() {
+        let applier = apply_fixture(&name);
+
+        for table in applier.tables().unwrap() {
+            if !applier.rows(&table).unwrap().is_empty() {
+                written.insert(table.clone());
+            }
+            tables.insert(table);
+        }
+    }
+
+    // Written by the sink itself.
+    tables.remove("cursors");
+
+    assert_eq!(written, tables);
+}
+
+#[test]
+fn it_applies_transfers_to_owners_balances_and_collections() {
+    let applier = apply_fixture("erc721_erc1155_transfers.json");
+
+    assert_eq!(applier.rows("transfers").unwrap().len(), 4);
+    assert_eq!(applier.rows("transactions").unwrap().len(), 4);
+
+    let nft = format!("{}-01", CONTRACT_721);
+    assert_eq!(value(&applier, "nfts", &nft, "owner"), Some(BOB.to_string()));
+    assert_eq!(value(&applier, "nfts", &nft, "contract"), Some(CONTRACT_721.to_string()));
+
+    assert_eq!(value(&applier, "balances", &format!("{}-01-{}", CONTRACT_721, ALICE), "balance"), Some("0".to_string()));
+    assert_eq!(value(&applier, "balances", &format!("{}-01-{}", CONTRACT_721, BOB), "balance"), Some("1".to_string()));
+    assert_eq!(value(&applier, "balances", &format!("{}-07-{}", CONTRACT_1155, ALICE), "balance"), Some("6".to_string()));
+    assert_eq!(value(&applier, "balances", &format!("{}-07-{}", CONTRACT_1155, BOB), "balance"), Some("4".to_string()));
+
+    assert_eq!(value(&applier, "collections", CONTRACT_721, "transfer_count"), Some("2".to_string()));
+    assert_eq!(value(&applier, "collections", CONTRACT_1155, "transfer_count"), Some("2".to_string()));
+}
+
+#[test]
+fn it_applies_seaport_sales_payouts_and_cancellations() {
+    let applier = apply_fixture("seaport_sales.json");
+
+    let listing = format!("{}-0", "02".repeat(32));
+    assert_eq!(value(&applier, "sales", &listing, "seller"), Some(ALICE.to_string()));
+    assert_eq!(value(&applier, "sales", &listing, "buyer"), Some(BOB.to_string()));
+    assert_eq!(value(&applier, "sales", &listing, "price"), Some("1000".to_string()));
+
+    let bid = format!("{}-2", "03".repeat(32));
+    assert_eq!(value(&applier, "sales", &bid, "seller"), Some(ALICE.to_string()));
+    assert_eq!(value(&applier, "sales", &bid, "payment_token"), Some("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2".to_string()));
+
+    let payouts: Vec<(Option<String>, Option<String>)> = applier.rows("sale_payouts").unwrap().into_iter()
+        .filter(|payout| payout["sale_id"].as_deref() == Some(bid.as_str()))
+        .map(|mut payout| (payout.remove("role").unwrap(), payout.remove("amount").unwrap()))
+        .collect();
+    assert_eq!(payouts, vec![
+        (Some("marketplace_fee".to_string()), Some("50".to_string())),
+        (Some("other".to_string()), Some("100".to_string())),
+        (Some("seller".to_string()), Some("1850".to_string())),
+    ]);
+    assert_eq!(applier.rows("sale_payouts").unwrap().len(), 6);
+
+    assert_eq!(value(&applier, "nfts", &format!("{}-08", CONTRACT_721), "owner"), Some(BOB.to_string()));
+    assert_eq!(applier.rows("transactions").unwrap().len(), 3);
+
+    let kinds: Vec<Option<String>> = applier.rows("order_cancellations").unwrap().into_iter()
+        .map(|mut cancellation| cancellation.remove("kind").unwrap())
+        .collect();
+    assert_eq!(kinds, vec![Some("order_cancelled".to_string()), Some("counter_incremented".to_string())]);
+}
+
+#[test]
+fn it_applies_cryptopunks_sales_and_orders() {
+    let applier = apply_fixture("cryptopunks.json");
+
+    let bought = format!("{}-2", "02".repeat(32));
+    assert_eq!(value(&applier, "sales", &bought, "marketplace"), Some("cryptopunks".to_string()));
+    assert_eq!(value(&applier, "sales", &bought, "price"), Some("1000".to_string()));
+
+    // Priced from the bid of Carol, the `PunkBought` of an accepted bid has a zero value.
+    let accepted_bid = format!("{}-2", "04".repeat(32));
+    assert_eq!(value(&applier, "sales", &accepted_bid, "buyer"), Some(CAROL.to_string()));
+    assert_eq!(value(&applier, "sales", &accepted_bid, "price"), Some("700".to_string()));
+    assert_eq!(value(&applier, "sale_payouts", &format!("{}-0", accepted_bid), "amount"), Some("700".to_string()));
+
+    assert_eq!(value(&applier, "nfts", &format!("{}-2a", CRYPTOPUNKS), "owner"), Some(BOB.to_string()));
+    assert_eq!(value(&applier, "nfts", &format!("{}-2b", CRYPTOPUNKS), "owner"), Some(CAROL.to_string()));
+
+    let kinds: Vec<Option<String>> = applier.rows("punk_orders").unwrap().into_iter()
+        .map(|mut order| order.remove("kind").unwrap())
+        .collect();
+    assert_eq!(kinds, vec![Some("offered".to_string()), Some("bid_entered".to_string())]);
+}
+
+#[test]
+fn it_applies_contracts_and_their_ownership() {
+    let applier = apply_fixture("contracts.json");
+
+    let standards: Vec<(Option<String>, Option<String>)> = applier.rows("contracts").unwrap().into_iter()
+        .map(|mut contract| (contract.remove("id").unwrap(), contract.remove("standard").unwrap()))
+        .collect();
+    assert_eq!(standards, vec![
+        (Some("12".repeat(20)), Some("erc1155".to_string())),
+        (Some("20".repeat(20)), Some("erc20".to_string())),
+        (Some(CONTRACT_721.to_string()), Some("erc721".to_string())),
+    ]);
+
+    assert_eq!(value(&applier, "contracts", CONTRACT_721, "deployer"), Some(ALICE.to_string()));
+    assert_eq!(value(&applier, "contracts", CONTRACT_721, "owner"), Some(BOB.to_string()));
+    assert_eq!(applier.rows("contract_ownership_transfers").unwrap().len(), 2);
+}
+
+#[test]
+fn it_applies_erc2309_transfers_and_ranges() {
+    let applier = apply_fixture("erc2309_transfers.json");
+
+    // 5 expanded transfers and the transfer of token 3.
+    assert_eq!(applier.rows("transfers").unwrap().len(), 6);
+    assert_eq!(value(&applier, "nfts", &format!("{}-03", CONTRACT_721), "owner"), Some(BOB.to_string()));
+    assert_eq!(value(&applier, "balances", &format!("{}-03-{}", CONTRACT_721, ALICE), "balance"), Some("0".to_string()));
+    assert_eq!(value(&applier, "collections", CONTRACT_721, "transfer_count"), Some("6".to_string()));
+
+    let ranges = applier.rows("transfer_ranges").unwrap();
+    assert_eq!(ranges.len(), 1);
+    assert_eq!(ranges[0]["from_token_id"], Some("06".to_string()));
+    assert_eq!(ranges[0]["to_token_id"], Some("4e25".to_string()));
+    assert_eq!(ranges[0]["to_address"], Some(BOB.to_string()));
+}
+
+#[test]
+fn it_applies_proxy_upgrades_from_events_and_the_implementation_slot() {
+    let applier = apply_fixture("proxies.json");
+
+    let nft_proxy = "50".repeat(20);
+    assert_eq!(value(&applier, "contracts", &nft_proxy, "standard"), Some("proxy".to_string()));
+    assert_eq!(value(&applier, "contracts", &nft_proxy, "implementation"), Some("1b".repeat(20)));
+
+    let upgrades: Vec<(Option<String>, Option<String>)> = applier.rows("contract_upgrades").unwrap().into_iter()
+        .map(|mut upgrade| (upgrade.remove("kind").unwrap(), upgrade.remove("source").unwrap()))
+        .collect();
+    assert_eq!(upgrades, vec![
+        (Some("upgraded".to_string()), Some("event".to_string())),
+        (Some("admin_changed".to_string()), Some("event".to_string())),
+        (Some("upgraded".to_string()), Some("storage".to_string())),
+    ]);
+}
+
+#[test]
+fn it_applies_token_users_locks_metadata_refreshes_and_accounts() {
+    let applier = apply_fixture("token_extensions.json");
+
+    let token_1 = format!("{}-01", CONTRACT_721);
+    let token_2 = format!("{}-02", CONTRACT_721);
+
+    assert_eq!(value(&applier, "nfts", &token_1, "metadata_stale"), Some("true".to_string()));
+    assert_eq!(value(&applier, "nfts", &token_2, "locked"), Some("true".to_string()));
+    assert_eq!(value(&applier, "token_users", &token_1, "user_address"), Some(BOB.to_string()));
+    assert_eq!(value(&applier, "token_users", &token_1, "expires"), Some("1618500000".to_string()));
+    assert_eq!(value(&applier, "token_bound_accounts", &"6a".repeat(20), "token_id"), Some("01".to_string()));
+    assert_eq!(applier.rows("metadata_refresh").unwrap().len(), 1);
+
+    let anomalies: Vec<Option<String>> = applier.rows("transfers").unwrap().into_iter()
+        .filter(|transfer| transfer["token_id"].as_deref() == Some("02") && transfer["to_address"].as_deref() == Some(BOB))
+        .map(|mut transfer| transfer.remove("anomaly").unwrap())
+        .collect();
+    assert_eq!(anomalies, vec![Some("locked_token".to_string())]);
+}
+
+#[test]
+fn it_applies_the_upgrades_of_nft_proxies_deployed_before_the_start_block() {
+    let nft_proxy = [0x50; 20];
+    let other_proxy = [0x51; 20];
+    let implementation = [0x1a; 20];
+    let alice = [0xa1; 20];
+
+    let blk = BlockBuilder::new(12287507)
+        .transaction(TransactionBuilder::new(0x01).from(&alice).call(
+            CallBuilder::new(&nft_proxy)
+                .log(erc721_transfer_log(&nft_proxy, &[0; 20], &alice, 1))
+                .log(erc1967_upgraded_log(&nft_proxy, &implementation)),
+        ))
+        .transaction(TransactionBuilder::new(0x02).from(&alice).call(
+            CallBuilder::new(&other_proxy).log(erc1967_upgraded_log(&other_proxy, &implementation)),
+        ))
+        .build();
+
+    let mut applier = SqliteApplier::new().unwrap();
+    applier.apply(&Pipeline::new().db_out(&blk)).unwrap();
+
+    let nft_proxy = substreams::Hex(nft_proxy).to_string();
+    assert_eq!(value(&applier, "contracts", &nft_proxy, "implementation"), Some(substreams::Hex(implementation).to_string()));
+    assert_eq!(value(&applier, "contracts", &nft_proxy, "contract"), Some(nft_proxy.clone()));
+
+    assert_eq!(applier.rows("contracts").unwrap().len(), 1);
+    assert_eq!(applier.rows("contract_upgrades").unwrap().len(), 1);
+}
+
+#[test]
+fn it_applies_the_slot_writes_of_two_proxies_in_one_transaction() {
+    let proxies = [[0x50; 20], [0x51; 20]];
+    let implementation = [0x1a; 20];
+    let alice = [0xa1; 20];
+
+    let mut slot_value = [0u8; 32];
+    slot_value[12..].copy_from_slice(&implementation);
+
+    let blk = BlockBuilder::new(12287507)
+        .transaction(TransactionBuilder::new(0x01).from(&alice)
+            .call(CallBuilder::new(&proxies[0]).log(erc721_transfer_log(&proxies[0], &[0; 20], &alice, 1)))
+            .call(CallBuilder::new(&proxies[1]).log(erc721_transfer_log(&proxies[1], &[0; 20], &alice, 1))))
+        .transaction(TransactionBuilder::new(0x02).from(&alice)
+            .call(CallBuilder::new(&proxies[0]).storage_change(&IMPLEMENTATION_SLOT, &slot_value))
+            .call(CallBuilder::new(&proxies[1]).storage_change(&IMPLEMENTATION_SLOT, &slot_value)))
+        .build();
+
+    let mut applier = SqliteApplier::new().unwrap();
+    applier.apply(&Pipeline::new().db_out(&blk)).unwrap();
+
+    assert_eq!(applier.rows("contract_upgrades").unwrap().len(), 2);
+    for proxy in proxies {
+        let proxy = substreams::Hex(proxy).to_string();
+        assert_eq!(value(&applier, "contracts", &proxy, "implementation"), Some(substreams::Hex(implementation).to_string()));
+    }
+}
+
+#[test]
+fn it_rejects_columns_missing_from_the_schema() {
+    let mut applier = SqliteApplier::new().unwrap();
+
+    let mut changes = DatabaseChanges::default();
+    changes
+        .push_change("collections", "01", 0, Operation::Create)
+        .change("transfers_count", ("", "1"));
+
+    let err = applier.apply(&changes).unwrap_err();
+
+    assert!(format!("{:#}", err).contains("transfers_count"), "{:#}", err);
+    assert!(applier.rows("collections").unwrap().is_empty());
+}
@@ -0,0 +1,289 @@
+// The blocks of the fixtures of `tests/fixtures`, built with the builders of `testing.rs`. The
+// JSON fixtures are written from here by `make update-snapshots` and `tests/golden.rs` fails when
+// they drift apart, so change the blocks here rather than the JSON.
+use hex_literal::hex;
+use substreams_ethereum::pb as ethpb;
+use substreams_ethereum::pb::eth::v2::CallType;
+
+use substreams_postgresql_sink_tutorial::testing::*;
+
+const NFT: [u8; 20] = [0x72; 20];
+const MULTI_TOKEN: [u8; 20] = [0x11; 20];
+const WETH: [u8; 20] = hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2");
+const ZERO: [u8; 20] = [0; 20];
+const ALICE: [u8; 20] = [0xa1; 20];
+const BOB: [u8; 20] = [0xb0; 20];
+const CAROL: [u8; 20] = [0xc0; 20];
+const CREATOR: [u8; 20] = [0xc1; 20];
+const OPENSEA: [u8; 20] = hex!("0000a26b00c1f0df003000390027140000faa719");
+
+// Seaport item types.
+const NATIVE: u64 = 0;
+const ERC20: u64 = 1;
+const ERC721: u64 = 2;
+
+// Deployed code holding the selectors or interface ids the classification looks for.
+const ERC721_CODE: [u8; 10] = hex!("6380ac58cd6301ffc9a7");
+const ERC1155_CODE: [u8; 10] = hex!("63f242432a632eb2c2d6");
+const ERC20_CODE: [u8; 15] = hex!("63a9059cbb6318160ddd63dd62ed3e");
+// EIP-1167 minimal proxy
+const PROXY_CODE: [u8; 45] = hex!("363d3d373d3d3d363d73bebebebebebebebebebebebebebebebebebebebe5af43d82803e903d91602b57fd5bf3");
+
+const IMPLEMENTATION_SLOT: [u8; 32] = hex!("360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc");
+
+// The name of every fixture, the file stem in `tests/fixtures`, with its blocks.
+pub fn fixtures() -> Vec<(&'static str, Vec<ethpb::eth::v2::Block>)> {
+    vec![
+        ("approvals", approvals()),
+        ("contracts", contracts()),
+        ("cryptopunks", cryptopunks()),
+        ("erc2309_transfers", erc2309_transfers()),
+        ("erc721_erc1155_transfers", erc721_erc1155_transfers()),
+        ("proxies", proxies()),
+        ("seaport_sales", seaport_sales()),
+        ("token_extensions", token_extensions()),
+    ]
+}
+
+// An ERC721 token minted to Alice then transferred to Bob, and an ERC1155 token of which Alice
+// mints 10 and sends 4 to Bob.
+fn erc721_erc1155_transfers() -> Vec<ethpb::eth::v2::Block> {
+    vec![
+        BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE).call(
+                CallBuilder::new(&NFT).log(erc721_transfer_log(&NFT, &ZERO, &ALICE, 1)),
+            ))
+            .transaction(TransactionBuilder::new(0x02).from(&ALICE).call(
+                CallBuilder::new(&MULTI_TOKEN).log(erc1155_transfer_single_log(&MULTI_TOKEN, &ALICE, &ZERO, &ALICE, 7, 10)),
+            ))
+            .build(),
+        BlockBuilder::new(12287508)
+            .transaction(TransactionBuilder::new(0x03).from(&ALICE).call(
+                CallBuilder::new(&NFT).log(erc721_transfer_log(&NFT, &ALICE, &BOB, 1)),
+            ))
+            .transaction(TransactionBuilder::new(0x04).from(&ALICE).call(
+                CallBuilder::new(&MULTI_TOKEN).log(erc1155_transfer_single_log(&MULTI_TOKEN, &ALICE, &ALICE, &BOB, 7, 4)),
+            ))
+            .build(),
+    ]
+}
+
+// A listing of Alice bought by Bob in ETH, a WETH bid of Bob accepted by Alice, both with a
+// marketplace fee and a creator fee, then a cancelled order and a counter increment.
+fn seaport_sales() -> Vec<ethpb::eth::v2::Block> {
+    vec![
+        BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE).call(
+                CallBuilder::new(&NFT)
+                    .log(erc721_transfer_log(&NFT, &ZERO, &ALICE, 7))
+                    .log(erc721_transfer_log(&NFT, &ZERO, &ALICE, 8)),
+            ))
+            .build(),
+        BlockBuilder::new(12287508)
+            .transaction(TransactionBuilder::new(0x02).from(&BOB)
+                .call(CallBuilder::new(&SEAPORT_ADDRESS).log(seaport_order_fulfilled_log(
+                    &[0x0f; 32],
+                    &ALICE,
+                    &ZERO,
+                    &BOB,
+                    &[(ERC721, NFT.to_vec(), 7, 1)],
+                    &[
+                        (NATIVE, ZERO.to_vec(), 0, 925, ALICE.to_vec()),
+                        (NATIVE, ZERO.to_vec(), 0, 25, OPENSEA.to_vec()),
+                        (NATIVE, ZERO.to_vec(), 0, 50, CREATOR.to_vec()),
+                    ],
+                )))
+                .call(CallBuilder::new(&NFT).log(erc721_transfer_log(&NFT, &ALICE, &BOB, 7))))
+            .transaction(TransactionBuilder::new(0x03).from(&ALICE)
+                .call(CallBuilder::new(&SEAPORT_ADDRESS).log(seaport_order_fulfilled_log(
+                    &[0x1f; 32],
+                    &BOB,
+                    &ZERO,
+                    &ALICE,
+                    &[(ERC20, WETH.to_vec(), 0, 2000)],
+                    &[
+                        (ERC721, NFT.to_vec(), 8, 1, BOB.to_vec()),
+                        (ERC20, WETH.to_vec(), 0, 50, OPENSEA.to_vec()),
+                        (ERC20, WETH.to_vec(), 0, 100, CREATOR.to_vec()),
+                    ],
+                )))
+                .call(CallBuilder::new(&NFT).log(erc721_transfer_log(&NFT, &ALICE, &BOB, 8))))
+            .build(),
+        BlockBuilder::new(12287509)
+            .transaction(TransactionBuilder::new(0x04).from(&BOB).call(
+                CallBuilder::new(&SEAPORT_ADDRESS).log(seaport_order_cancelled_log(&[0x2f; 32], &BOB, &ZERO)),
+            ))
+            .transaction(TransactionBuilder::new(0x05).from(&ALICE).call(
+                CallBuilder::new(&SEAPORT_ADDRESS).log(seaport_counter_incremented_log(1, &ALICE)),
+            ))
+            .build(),
+    ]
+}
+
+// Approvals have no table, they're only in the output of `jsonl_out`.
+fn approvals() -> Vec<ethpb::eth::v2::Block> {
+    vec![
+        BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE).call(
+                CallBuilder::new(&NFT).log(erc721_transfer_log(&NFT, &ZERO, &ALICE, 1)),
+            ))
+            .transaction(TransactionBuilder::new(0x02).from(&ALICE).call(
+                CallBuilder::new(&NFT).log(erc721_approval_log(&NFT, &ALICE, &BOB, 1)),
+            ))
+            .transaction(TransactionBuilder::new(0x03).from(&ALICE).call(
+                CallBuilder::new(&NFT).log(approval_for_all_log(&NFT, &ALICE, &CAROL, true)),
+            ))
+            .build(),
+        BlockBuilder::new(12287508)
+            .transaction(TransactionBuilder::new(0x04).from(&ALICE).call(
+                CallBuilder::new(&NFT).log(approval_for_all_log(&NFT, &ALICE, &CAROL, false)),
+            ))
+            .build(),
+    ]
+}
+
+// Alice deploys an ERC721 contract minting in its constructor, an ERC1155 and an ERC20 one,
+// then hands the ownership of the ERC721 one over to Bob.
+fn contracts() -> Vec<ethpb::eth::v2::Block> {
+    let multi_token = [0x12; 20];
+    let token = [0x20; 20];
+
+    vec![
+        BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE).call(
+                CallBuilder::new(&NFT)
+                    .call_type(CallType::Create)
+                    .code(&ERC721_CODE)
+                    .log(ownership_transferred_log(&NFT, &ZERO, &ALICE))
+                    .log(erc721_transfer_log(&NFT, &ZERO, &ALICE, 1)),
+            ))
+            .transaction(TransactionBuilder::new(0x02).from(&ALICE).call(
+                CallBuilder::new(&multi_token).call_type(CallType::Create).code(&ERC1155_CODE),
+            ))
+            .transaction(TransactionBuilder::new(0x03).from(&ALICE).call(
+                CallBuilder::new(&token)
+                    .call_type(CallType::Create)
+                    .code(&ERC20_CODE)
+                    .log(erc20_transfer_log(&token, &ZERO, &ALICE, 1000)),
+            ))
+            .build(),
+        BlockBuilder::new(12287508)
+            .transaction(TransactionBuilder::new(0x04).from(&ALICE).call(
+                CallBuilder::new(&NFT).log(ownership_transferred_log(&NFT, &ALICE, &BOB)),
+            ))
+            .build(),
+    ]
+}
+
+// A batch mint of 5 tokens expanded into transfers and one of 20000 tokens, over the default
+// `max_consecutive_transfer_range`, written as a range.
+fn erc2309_transfers() -> Vec<ethpb::eth::v2::Block> {
+    vec![
+        BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE).call(
+                CallBuilder::new(&NFT)
+                    .call_type(CallType::Create)
+                    .code(&ERC721_CODE)
+                    .log(erc2309_consecutive_transfer_log(&NFT, 1, 5, &ZERO, &ALICE))
+                    .log(erc2309_consecutive_transfer_log(&NFT, 6, 20005, &ZERO, &BOB)),
+            ))
+            .build(),
+        BlockBuilder::new(12287508)
+            .transaction(TransactionBuilder::new(0x02).from(&ALICE).call(
+                CallBuilder::new(&NFT).log(erc721_transfer_log(&NFT, &ALICE, &BOB, 3)),
+            ))
+            .build(),
+    ]
+}
+
+// Punk 42 offered by Alice and bought by Bob, then punk 43 of Bob sold to Carol by accepting her
+// bid.
+fn cryptopunks() -> Vec<ethpb::eth::v2::Block> {
+    vec![
+        BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE).call(
+                CallBuilder::new(&CRYPTOPUNKS_ADDRESS).log(punk_offered_log(42, 1000, &ZERO)),
+            ))
+            .transaction(TransactionBuilder::new(0x02).from(&BOB).call(
+                CallBuilder::new(&CRYPTOPUNKS_ADDRESS)
+                    .log(erc20_transfer_log(&CRYPTOPUNKS_ADDRESS, &ALICE, &BOB, 1))
+                    .log(punk_bought_log(42, 1000, &ALICE, &BOB)),
+            ))
+            .build(),
+        BlockBuilder::new(12287508)
+            .transaction(TransactionBuilder::new(0x03).from(&CAROL).call(
+                CallBuilder::new(&CRYPTOPUNKS_ADDRESS).log(punk_bid_entered_log(43, 700, &CAROL)),
+            ))
+            .transaction(TransactionBuilder::new(0x04).from(&BOB).call(
+                CallBuilder::new(&CRYPTOPUNKS_ADDRESS)
+                    .log(erc20_transfer_log(&CRYPTOPUNKS_ADDRESS, &BOB, &CAROL, 1))
+                    .log(punk_bought_log(43, 0, &BOB, &ZERO)),
+            ))
+            .build(),
+    ]
+}
+
+// An NFT proxy upgraded with `Upgraded` and then only through its implementation slot, a change
+// of its admin, and the upgrade of a proxy that isn't an NFT contract, which is dropped.
+fn proxies() -> Vec<ethpb::eth::v2::Block> {
+    let nft_proxy = [0x50; 20];
+    let other_proxy = [0x51; 20];
+    let implementation = [0x1a; 20];
+    let next_implementation = [0x1b; 20];
+
+    let mut slot_value = [0u8; 32];
+    slot_value[12..].copy_from_slice(&next_implementation);
+
+    vec![
+        BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE).call(
+                CallBuilder::new(&nft_proxy)
+                    .call_type(CallType::Create)
+                    .code(&PROXY_CODE)
+                    .log(erc1967_upgraded_log(&nft_proxy, &implementation))
+                    .log(erc1967_admin_changed_log(&nft_proxy, &ZERO, &ALICE))
+                    .log(erc721_transfer_log(&nft_proxy, &ZERO, &ALICE, 1)),
+            ))
+            .transaction(TransactionBuilder::new(0x02).from(&ALICE).call(
+                CallBuilder::new(&other_proxy).log(erc1967_upgraded_log(&other_proxy, &implementation)),
+            ))
+            .build(),
+        BlockBuilder::new(12287508)
+            .transaction(TransactionBuilder::new(0x03).from(&ALICE).call(
+                CallBuilder::new(&nft_proxy).storage_change(&IMPLEMENTATION_SLOT, &slot_value),
+            ))
+            .build(),
+    ]
+}
+
+// ERC4906 metadata updates, an ERC4907 user, an ERC5192 locked token transferred anyway and the
+// ERC6551 account of a token.
+fn token_extensions() -> Vec<ethpb::eth::v2::Block> {
+    let account = [0x6a; 20];
+    let account_implementation = [0x6b; 20];
+
+    vec![
+        BlockBuilder::new(12287507)
+            .transaction(TransactionBuilder::new(0x01).from(&ALICE).call(
+                CallBuilder::new(&NFT)
+                    .log(erc721_transfer_log(&NFT, &ZERO, &ALICE, 1))
+                    .log(erc721_transfer_log(&NFT, &ZERO, &ALICE, 2))
+                    .log(erc5192_locked_log(&NFT, 2)),
+            ))
+            .transaction(TransactionBuilder::new(0x02).from(&ALICE).call(
+                CallBuilder::new(&ERC6551_REGISTRY_ADDRESS)
+                    .log(erc6551_account_created_log(&account, &account_implementation, &NFT, 1)),
+            ))
+            .build(),
+        BlockBuilder::new(12287508)
+            .transaction(TransactionBuilder::new(0x03).from(&ALICE).call(
+                CallBuilder::new(&NFT)
+                    .log(erc4907_update_user_log(&NFT, 1, &BOB, 1618500000))
+                    .log(erc4906_metadata_update_log(&NFT, 1)),
+            ))
+            .transaction(TransactionBuilder::new(0x04).from(&ALICE).call(
+                CallBuilder::new(&NFT).log(erc721_transfer_log(&NFT, &ALICE, &BOB, 2)),
+            ))
+            .build(),
+    ]
+}
@@ -0,0 +1,147 @@
+// Applies the output of `db_out` to an in-memory SQLite database created from `schema.sql`, the
+// way the Postgres sink applies it: creates are upserts on `id`, updates of a missing row change
+// nothing and unset changes are skipped. Every value is bound as a parameter, a change for a table
+// or a column missing from the schema is an error.
+use std::collections::BTreeMap;
+
+use anyhow::Context;
+use rusqlite::types::ValueRef;
+use rusqlite::{params_from_iter, Connection};
+use substreams_database_change::pb::database::{table_change::Operation, DatabaseChanges, TableChange};
+
+const SCHEMA: &str = include_str!("../../schema.sql");
+
+pub type Row = BTreeMap<String, Option<String>>;
+
+pub struct SqliteApplier {
+    connection: Connection,
+}
+
+impl SqliteApplier {
+    pub fn new() -> Result<Self, anyhow::Error> {
+        let connection = Connection::open_in_memory()?;
+        connection.execute_batch(SCHEMA).context("creating the tables of schema.sql")?;
+
+        Ok(SqliteApplier { connection })
+    }
+
+    // All the changes of a block are applied in a single transaction, like a flush of the sink.
+    pub fn apply(&mut self, changes: &DatabaseChanges) -> Result<(), anyhow::Error> {
+        let transaction = self.connection.transaction()?;
+
+        for change in changes.table_changes.iter() {
+            apply_change(&transaction, change)
+                .with_context(|| format!("applying {} {} to {}", operation_name(change), change.pk, change.table))?;
+        }
+
+        transaction.commit()?;
+        Ok(())
+    }
+
+    pub fn row(&self, table: &str, id: &str) -> Result<Option<Row>, anyhow::Error> {
+        let rows = self.query(&format!("SELECT * FROM {} WHERE id = ?1", table), &[id])?;
+        Ok(rows.into_iter().next())
+    }
+
+    // The tables created from `schema.sql`, ordered by name.
+    pub fn tables(&self) -> Result<Vec<String>, anyhow::Error> {
+        let rows = self.query("SELECT name FROM sqlite_master WHERE type = 'table' ORDER BY name", &[])?;
+        Ok(rows.into_iter().filter_map(|mut row| row.remove("name").flatten()).collect())
+    }
+
+    // Ordered by `id`.
+    pub fn rows(&self, table: &str) -> Result<Vec<Row>, anyhow::Error> {
+        self.query(&format!("SELECT * FROM {} ORDER BY id", table), &[])
+    }
+
+    fn query(&self, sql: &str, params: &[&str]) -> Result<Vec<Row>, anyhow::Error> {
+        let mut statement = self.connection.prepare(sql)?;
+        let columns: Vec<String> = statement.column_names().iter().map(|name| name.to_string()).collect();
+
+        let mut rows = statement.query(params_from_iter(params.iter()))?;
+        let mut result = Vec::new();
+
+        while let Some(row) = rows.next()? {
+            let mut values = Row::new();
+            for (i, column) in columns.iter().enumerate() {
+                values.insert(column.clone(), value_to_string(row.get_ref(i)?));
+            }
+            result.push(values);
+        }
+
+        Ok(result)
+    }
+}
+
+fn apply_change(connection: &Connection, change: &TableChange) -> Result<(), anyhow::Error> {
+    let fields: Vec<_> = change.fields.iter().filter(|field| field.name != "id").collect();
+
+    let (sql, mut params) = match Operation::from_i32(change.operation) {
+        Some(Operation::Create) => {
+            let columns: Vec<&str> = fields.iter().map(|field| field.name.as_str()).collect();
+            let placeholders: Vec<String> = (1..=columns.len() + 1).map(|i| format!("?{}", i)).collect();
+            let on_conflict = match columns.is_empty() {
+                true => "DO NOTHING".to_string(),
+                false => format!(
+                    "DO UPDATE SET {}",
+                    columns.iter().map(|column| format!("{}=excluded.{}", column, column)).collect::<Vec<_>>().join(", "),
+                ),
+            };
+
+            let sql = format!(
+                "INSERT INTO {} (id{}) VALUES ({}) ON CONFLICT (id) {}",
+                change.table,
+                columns.iter().map(|column| format!(", {}", column)).collect::<String>(),
+                placeholders.join(", "),
+                on_conflict,
+            );
+
+            (sql, vec![change.pk.as_str()])
+        }
+        Some(Operation::Update) => {
+            if fields.is_empty() {
+                return Ok(());
+            }
+
+            let updates: Vec<String> = fields.iter().enumerate()
+                .map(|(i, field)| format!("{}=?{}", field.name, i + 1))
+                .collect();
+            let sql = format!("UPDATE {} SET {} WHERE id = ?{}", change.table, updates.join(", "), fields.len() + 1);
+
+            let mut params: Vec<&str> = fields.iter().map(|field| field.new_value.as_str()).collect();
+            params.push(change.pk.as_str());
+
+            connection.execute(&sql, params_from_iter(params.iter()))?;
+            return Ok(());
+        }
+        Some(Operation::Delete) => {
+            connection.execute(&format!("DELETE FROM {} WHERE id = ?1", change.table), [change.pk.as_str()])?;
+            return Ok(());
+        }
+        _ => return Ok(()),
+    };
+
+    params.extend(fields.iter().map(|field| field.new_value.as_str()));
+    connection.execute(&sql, params_from_iter(params.iter()))?;
+    Ok(())
+}
+
+fn operation_name(change: &TableChange) -> &'static str {
+    match Operation::from_i32(change.operation) {
+        Some(Operation::Create) => "create",
+        Some(Operation::Update) => "update",
+        Some(Operation::Delete) => "delete",
+        _ => "unset",
+    }
+}
+
+// Columns keep the values as given, except the integer and boolean ones SQLite converts.
+fn value_to_string(value: ValueRef) -> Option<String> {
+    match value {
+        ValueRef::Null => None,
+        ValueRef::Integer(value) => Some(value.to_string()),
+        ValueRef::Real(value) => Some(value.to_string()),
+        ValueRef::Text(value) => Some(String::from_utf8_lossy(value).to_string()),
+        ValueRef::Blob(value) => Some(substreams::Hex(value).to_string()),
+    }
+}